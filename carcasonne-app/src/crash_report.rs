@@ -0,0 +1,184 @@
+use carcasonne_core::model::game::Game;
+use carcasonne_core::model::game::moves::Move;
+use std::backtrace::Backtrace;
+use std::fmt::Write as _;
+use std::fs;
+use std::panic::{self, Location, PanicHookInfo};
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// What is needed to play the current game again: the seed its tiles are drawn with,
+/// and the moves applied so far.
+#[derive(Debug, Clone, Default)]
+struct Session {
+    seed: Option<u64>,
+    moves: Vec<Move>,
+}
+
+/// The session of the game being played, written in the crash report.
+static SESSION: Mutex<Session> = Mutex::new(Session {
+    seed: None,
+    moves: Vec::new(),
+});
+
+/// Records the seed and the moves of `game`, to be written in the crash report
+/// should the application crash.
+pub fn record(game: &Game) {
+    let session = Session {
+        seed: game.tiles().seed,
+        moves: game
+            .history()
+            .done()
+            .iter()
+            .map(|command| command.player_move())
+            .collect(),
+    };
+    *SESSION.lock().unwrap_or_else(|e| e.into_inner()) = session;
+}
+
+/// Installs a panic hook writing a crash report to a file.
+///
+/// The report contains the application version, the command-line arguments the
+/// game was started with, the seed and the moves of the game last `record`ed, the
+/// panic message and location, and a backtrace.
+/// The path of the report is printed on stderr so it can be attached to a bug report.
+///
+/// # Arguments
+///
+/// * `args` - The command-line arguments of the session, recorded as its configuration.
+pub fn install(args: Vec<String>) {
+    panic::set_hook(Box::new(move |info| {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+        let session = SESSION.lock().unwrap_or_else(|e| e.into_inner()).clone();
+        let report = build_report(
+            &args,
+            &session,
+            &panic_message(info),
+            info.location(),
+            &Backtrace::force_capture(),
+        );
+        let path = report_path(timestamp);
+
+        // The terminal may still be in raw mode, so lines end with an explicit carriage return.
+        match fs::write(&path, report) {
+            Ok(()) => eprint!(
+                "\r\nThe game crashed: {}\r\nA crash report was written to {}\r\n",
+                panic_message(info),
+                path.display()
+            ),
+            Err(e) => eprint!(
+                "\r\nThe game crashed: {}\r\nThe crash report could not be written: {e}\r\n",
+                panic_message(info)
+            ),
+        }
+    }));
+}
+
+/// Returns the path of the crash report file for a crash happening at `timestamp`.
+fn report_path(timestamp: u64) -> PathBuf {
+    std::env::temp_dir().join(format!("carcasonne-crash-{timestamp}.txt"))
+}
+
+/// Returns the message the panic was raised with.
+fn panic_message(info: &PanicHookInfo) -> String {
+    info.payload()
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| info.payload().downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown error".to_string())
+}
+
+/// Builds the content of a crash report.
+fn build_report(
+    args: &[String],
+    session: &Session,
+    message: &str,
+    location: Option<&Location>,
+    backtrace: &Backtrace,
+) -> String {
+    let mut report = String::new();
+    writeln!(report, "Carcasonne crash report").ok();
+    writeln!(report, "Version: {}", env!("CARGO_PKG_VERSION")).ok();
+    writeln!(report, "Arguments: {args:?}").ok();
+    let seed = session
+        .seed
+        .map_or("none".to_string(), |seed| seed.to_string());
+    writeln!(report, "Seed: {seed}").ok();
+    writeln!(report, "Message: {message}").ok();
+    if let Some(location) = location {
+        writeln!(report, "Location: {location}").ok();
+    }
+    writeln!(report).ok();
+    writeln!(report, "Moves:").ok();
+    for (index, player_move) in session.moves.iter().enumerate() {
+        writeln!(report, "{:>4}. {player_move:?}", index + 1).ok();
+    }
+    writeln!(report).ok();
+    writeln!(report, "Backtrace:").ok();
+    writeln!(report, "{backtrace}").ok();
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use carcasonne_core::model::board::Coord;
+    use carcasonne_core::model::tile::{Rotation, TileId};
+
+    #[test]
+    fn test_report_path_is_in_temp_dir() {
+        let path = report_path(42);
+        assert_eq!(path.parent(), Some(std::env::temp_dir().as_path()));
+        assert!(path.ends_with("carcasonne-crash-42.txt"));
+    }
+
+    #[test]
+    fn test_build_report_contains_session_context() {
+        let args = vec!["--keys".to_string(), "vim".to_string()];
+        let session = Session {
+            seed: Some(7),
+            moves: vec![
+                Move::PlaceTile {
+                    tile: TileId('D'),
+                    position: Coord::new(1, 0),
+                    rotation: Rotation::Deg90,
+                },
+                Move::SkipMeeple,
+            ],
+        };
+        let report = build_report(
+            &args,
+            &session,
+            "boom 7",
+            Some(Location::caller()),
+            &Backtrace::disabled(),
+        );
+
+        assert!(report.contains("Arguments: [\"--keys\", \"vim\"]"));
+        assert!(report.contains("Seed: 7"));
+        assert!(report.contains("Message: boom 7"));
+        assert!(report.contains("Location: "));
+        assert!(report.contains("   1. PlaceTile"));
+        assert!(report.contains("   2. SkipMeeple"));
+        assert!(report.contains("Backtrace:"));
+    }
+
+    #[test]
+    fn test_build_report_without_session() {
+        let report = build_report(
+            &[],
+            &Session::default(),
+            "boom",
+            None,
+            &Backtrace::disabled(),
+        );
+
+        assert!(report.contains("Seed: none"));
+        assert!(!report.contains("Location: "));
+        assert!(report.contains("Moves:\n\nBacktrace:"));
+    }
+}
@@ -1,9 +1,12 @@
+use crate::crash_report;
 use carcasonne_console_input::input_handler::read_input_event;
+use carcasonne_console_input::keymap::Keymap;
 use carcasonne_core::action::Action;
+use carcasonne_core::layout::node::Node;
 use carcasonne_core::renderer::Renderer;
-use carcasonne_core::state::game_state::menu_state::MenuState;
 use carcasonne_core::state::State;
 use carcasonne_core::state::StateResult::{Continue, ExitToStop, Skip};
+use carcasonne_core::state::game_state::menu_state::MenuState;
 use std::cell::RefCell;
 
 /// Main game engine struct managing the game state and rendering.
@@ -16,6 +19,10 @@ pub struct Game<T: Renderer> {
     game_state: Option<Box<dyn State>>,
     /// Renderer used to draw the current state.
     renderer: RefCell<T>,
+    /// Key bindings used to translate key presses into input events.
+    keymap: Keymap,
+    /// The status line listing the key bindings, shown below every state.
+    key_help: String,
 }
 
 impl<T: Renderer> Game<T> {
@@ -26,14 +33,17 @@ impl<T: Renderer> Game<T> {
     /// # Arguments
     ///
     /// * `renderer` - A `RefCell` wrapping the renderer implementation.
+    /// * `keymap` - The key bindings used to read player input.
     ///
     /// # Returns
     ///
     /// A new `Game` instance is ready to run.
-    pub fn new(renderer: RefCell<T>) -> Self {
+    pub fn new(renderer: RefCell<T>, keymap: Keymap) -> Self {
         Self {
-            game_state: Some(Box::new(MenuState {})),
+            game_state: Some(Box::new(MenuState::new())),
             renderer,
+            key_help: keymap.help(),
+            keymap,
         }
     }
     /// Returns a reference to the current game state.
@@ -60,9 +70,16 @@ impl<T: Renderer> Game<T> {
             .expect("Game state should always be set")
     }
 
-    /// Renders the current game state using the associated renderer.
+    /// Renders the current game state using the associated renderer, followed by the
+    /// status line naming the active key preset.
     fn rerender(&mut self) {
-        self.renderer.borrow_mut().render(self.game_state().draw());
+        let node = match self.game_state().draw() {
+            Node::None => Node::None,
+            node => {
+                Node::VerticalContainer(vec![Box::new(node), Box::new(Node::Text(&self.key_help))])
+            }
+        };
+        self.renderer.borrow_mut().render(node);
     }
 
     /// Runs the main game loop.
@@ -79,7 +96,8 @@ impl<T: Renderer> Game<T> {
         'main_loop: loop {
             // TODO find a solution to allow quit without blocking the input
             let action = if self.game_state().need_input() {
-                self.game_state().handle_input(read_input_event())
+                self.game_state()
+                    .handle_input(read_input_event(&self.keymap))
             } else {
                 Action::None
             };
@@ -100,10 +118,15 @@ impl<T: Renderer> Game<T> {
 
     /// Changes the current game state and triggers re-rendering.
     ///
+    /// The game being played, if any, is recorded for the crash report.
+    ///
     /// # Arguments
     ///
     /// * `new_state` - The new game state to replace the current one.
     fn change_state(&mut self, new_state: Box<dyn State>) {
+        if let Some(game) = new_state.game() {
+            crash_report::record(game);
+        }
         self.game_state = Some(new_state);
         self.rerender();
     }
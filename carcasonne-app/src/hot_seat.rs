@@ -0,0 +1,241 @@
+use crate::crash_report;
+use carcasonne_core::factory::game_factory::GameTilesFactory;
+use carcasonne_core::model::game::Game;
+use carcasonne_core::model::game::event::GameEvent;
+use carcasonne_core::model::game::moves::Move;
+use carcasonne_core::model::game::notation::slot_name;
+use carcasonne_core::model::game::turn_engine::TurnPhase;
+use carcasonne_core::model::player::{MAX_PLAYERS, MIN_PLAYERS, Player};
+use std::io::{self, BufRead, Write};
+
+/// Plays a game of the base tiles in the terminal, the players sharing one keyboard.
+///
+/// Each turn the board is printed, then the active player is told which tile they
+/// drew and picks, by number, where to place it and where to put a meeple. The
+/// features scored are printed after each turn, and the final scores once the bag is
+/// empty. The game stops early when `input` runs out. Each move is recorded for the
+/// crash report.
+///
+/// # Arguments
+///
+/// * `players` - The number of players, taking turns in order.
+/// * `seed` - The seed the tiles are drawn with, to replay the same game.
+/// * `input` - Where the choices of the players are read from.
+/// * `output` - Where the board and the prompts are written to.
+///
+/// # Errors
+///
+/// Returns an `InvalidInput` error, before anything is written, unless there are
+/// `MIN_PLAYERS` to `MAX_PLAYERS` players, and the errors of `input` and `output`.
+pub fn run(
+    players: usize,
+    seed: Option<u64>,
+    mut input: impl BufRead,
+    mut output: impl Write,
+) -> io::Result<()> {
+    if !(MIN_PLAYERS..=MAX_PLAYERS).contains(&players) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("a game takes {MIN_PLAYERS} to {MAX_PLAYERS} players, not {players}"),
+        ));
+    }
+    let mut tiles = GameTilesFactory::build_base_game();
+    tiles.seed = seed;
+    let mut game = Game::new(tiles, Player::local_players(players));
+    crash_report::record(&game);
+    let events = game.advance();
+    print_events(&game, &events, &mut output)?;
+
+    while game.phase() != TurnPhase::GameOver {
+        let player = game.active_player();
+        let tile = game.drawn_tile().expect("a tile is drawn").clone();
+        write!(output, "\n{}", game.board().to_ascii_snapshot())?;
+        writeln!(
+            output,
+            "{} drew tile {} ({} meeples left)",
+            game.players()[player].name,
+            tile.id,
+            game.meeples_left(player)
+        )?;
+
+        let placements = game.board().legal_placements(&tile);
+        for (index, (position, rotation)) in placements.iter().enumerate() {
+            writeln!(
+                output,
+                "{:>3}. ({}, {}) r{}",
+                index + 1,
+                position.x,
+                position.y,
+                rotation.quarter_turns() * 90
+            )?;
+        }
+        let Some(choice) = choose(
+            1,
+            placements.len(),
+            "Place the tile",
+            &mut input,
+            &mut output,
+        )?
+        else {
+            return Ok(());
+        };
+        let (position, rotation) = placements[choice - 1];
+        let events = game
+            .apply(Move::PlaceTile {
+                tile: tile.id,
+                position,
+                rotation,
+            })
+            .expect("the placement is legal");
+        crash_report::record(&game);
+        print_events(&game, &events, &mut output)?;
+
+        let slots = if game.meeples_left(player) > 0 {
+            game.legal_meeple_placements(position)
+        } else {
+            Vec::new()
+        };
+        let meeple = if slots.is_empty() {
+            Move::SkipMeeple
+        } else {
+            writeln!(output, "  0. no meeple")?;
+            for (index, slot) in slots.iter().enumerate() {
+                let name = slot_name(&tile, *slot).unwrap_or_default();
+                writeln!(output, "{:>3}. {name}", index + 1)?;
+            }
+            match choose(0, slots.len(), "Place a meeple", &mut input, &mut output)? {
+                None => return Ok(()),
+                Some(0) => Move::SkipMeeple,
+                Some(choice) => Move::PlaceMeeple(slots[choice - 1]),
+            }
+        };
+        let events = game.apply(meeple).expect("the meeple move is legal");
+        crash_report::record(&game);
+        print_events(&game, &events, &mut output)?;
+    }
+    write!(output, "\n{}", game.board().to_ascii_snapshot())?;
+    Ok(())
+}
+
+/// Asks for a number from `min` to `max` until one is given, and returns it, or
+/// `None` once `input` runs out.
+fn choose(
+    min: usize,
+    max: usize,
+    prompt: &str,
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+) -> io::Result<Option<usize>> {
+    loop {
+        write!(output, "{prompt} [{min}-{max}]: ")?;
+        output.flush()?;
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+        match line.trim().parse::<usize>() {
+            Ok(choice) if (min..=max).contains(&choice) => return Ok(Some(choice)),
+            _ => writeln!(output, "Please enter a number from {min} to {max}.")?,
+        }
+    }
+}
+
+/// Writes the scores and the end of the game among `events`.
+fn print_events(game: &Game, events: &[GameEvent], output: &mut impl Write) -> io::Result<()> {
+    let name = |player: usize| game.players()[player].name.as_str();
+    for event in events {
+        match event {
+            GameEvent::FeatureScored(score) if !score.players.is_empty() => {
+                let players: Vec<&str> = score.players.iter().map(|p| name(*p)).collect();
+                writeln!(
+                    output,
+                    "{:?} scored {} points for {}",
+                    score.category,
+                    score.points,
+                    players.join(", ")
+                )?;
+            }
+            GameEvent::TileDiscarded { tile } => {
+                writeln!(output, "Tile {tile} fits nowhere and was discarded")?;
+            }
+            GameEvent::GameOver(result) => {
+                writeln!(output, "\nGame over after {} turns", result.turns)?;
+                writeln!(
+                    output,
+                    "{:<10} {:>5} {:>5} {:>6} {:>6} {:>8} {:>5}",
+                    "", "towns", "roads", "abbeys", "fields", "game end", "total"
+                )?;
+                for (player, breakdown) in result.breakdowns.iter().enumerate() {
+                    writeln!(
+                        output,
+                        "{:<10} {:>5} {:>5} {:>6} {:>6} {:>8} {:>5}",
+                        name(player),
+                        breakdown.towns,
+                        breakdown.roads,
+                        breakdown.abbeys,
+                        breakdown.fields,
+                        breakdown.end_game,
+                        breakdown.total()
+                    )?;
+                }
+                let winners: Vec<&str> = result.winners.iter().map(|p| name(*p)).collect();
+                writeln!(output, "Winner: {}", winners.join(", "))?;
+            }
+            _ => {}
+        }
+    }
+    if events
+        .iter()
+        .any(|event| matches!(event, GameEvent::FeatureScored(_)))
+    {
+        let scores: Vec<String> = (0..game.players().len())
+            .map(|player| format!("{} {}", name(player), game.score_of(player)))
+            .collect();
+        writeln!(output, "Scores: {}", scores.join(" | "))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn play(players: usize, input: &str) -> String {
+        let mut output = Vec::new();
+        run(players, Some(3), input.as_bytes(), &mut output).unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn test_turns_rotate_between_players() {
+        let output = play(2, "1\n0\n1\n0\n1\n0\n");
+        let first = output.find("Player 1 drew").unwrap();
+        let second = output.find("Player 2 drew").unwrap();
+        assert!(first < second);
+        assert!(output[second..].contains("Player 1 drew"));
+    }
+
+    #[test]
+    fn test_invalid_choices_are_asked_again() {
+        let output = play(2, "x\n999\n1\n");
+        assert_eq!(output.matches("Please enter a number").count(), 2);
+        assert!(output.contains("Place a meeple") || output.contains("Player 2 drew"));
+    }
+
+    #[test]
+    fn test_player_count_out_of_range_is_refused() {
+        for players in [MIN_PLAYERS - 1, MAX_PLAYERS + 1] {
+            let mut output = Vec::new();
+            let error = run(players, Some(3), "1\n".as_bytes(), &mut output).unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+            assert!(output.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_game_plays_to_the_end() {
+        let output = play(2, &"1\n0\n".repeat(200));
+        assert!(output.contains("Game over after"));
+        assert!(output.contains("Winner: "));
+    }
+}
@@ -1,14 +1,71 @@
 use crate::game::Game;
+use carcasonne_console_input::keymap::{KeyPreset, Keymap};
+use carcasonne_core::model::player::MIN_PLAYERS;
+use carcasonne_text_ui::color_map::DisplayMode;
 use carcasonne_text_ui::renderer::TextRenderer;
 use std::cell::RefCell;
-use std::io::stdout;
+use std::fmt::Display;
+use std::io::{stdin, stdout};
+use std::str::FromStr;
 
+mod crash_report;
 mod game;
+mod hot_seat;
+
+/// Returns the value of the command-line option `flag` (e.g. `--keys vim`), or the default value.
+///
+/// Unparsable values are reported on stderr and replaced by the default.
+fn option<T>(args: &[String], flag: &str) -> T
+where
+    T: FromStr + Default,
+    T::Err: Display,
+{
+    let Some(position) = args.iter().position(|arg| arg == flag) else {
+        return T::default();
+    };
+    match args.get(position + 1).map(|value| value.parse()) {
+        Some(Ok(value)) => value,
+        Some(Err(e)) => {
+            eprintln!("{e}, using default value for {flag}");
+            T::default()
+        }
+        None => T::default(),
+    }
+}
 
 /// Entry point of the application.
 ///
 /// Creates a new game instance with a `TextRenderer` wrapped in a `RefCell`,
+/// using the key bindings (`--keys arrows|vim|wasd`) and display mode
+/// (`--display standard|high-contrast|symbols`) selected on the command line,
 /// then starts the game loop by calling `run`.
+///
+/// With `--hot-seat`, plays a game on the plain terminal instead, for
+/// `--players` players (2 by default) drawing tiles with the optional `--seed`.
+/// A player count outside 2 to 5 is refused, and the process exits with an error.
+///
+/// Any crash writes a report to a file whose path is printed on exit.
 fn main() {
-    Game::new(RefCell::new(TextRenderer::new(stdout()))).run();
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    crash_report::install(args.clone());
+    if args.iter().any(|arg| arg == "--hot-seat") {
+        let players = if args.iter().any(|arg| arg == "--players") {
+            option::<usize>(&args, "--players")
+        } else {
+            MIN_PLAYERS
+        };
+        let seed = args
+            .iter()
+            .any(|arg| arg == "--seed")
+            .then(|| option::<u64>(&args, "--seed"));
+        if let Err(e) = hot_seat::run(players, seed, stdin().lock(), stdout()) {
+            eprintln!("{e}");
+            std::process::exit(1);
+        }
+        return;
+    }
+    let keymap = Keymap::new(option::<KeyPreset>(&args, "--keys"));
+    let display_mode = option::<DisplayMode>(&args, "--display");
+    let renderer = TextRenderer::new(stdout()).with_color_map(display_mode.color_map());
+    Game::new(RefCell::new(renderer), keymap).run();
 }
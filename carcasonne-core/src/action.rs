@@ -19,6 +19,8 @@ pub enum Action {
     Right,
     /// Confirm the current selection or input.
     Validate,
+    /// Decline to put a meeple on the tile just placed.
+    SkipMeeple,
     /// Exit the game or current screen.
     Quit,
     /// No action (e.g., idle state or ignored input).
@@ -1,18 +1,30 @@
 use crate::model::game::GameTiles;
-use crate::model::tile::Tile;
+use crate::model::rules::RulesConfig;
+use crate::model::tile::{Tile, TileId};
 
 /// A builder for constructing a collection of game tiles.
 ///
 /// `GameBuilder` provides a fluent interface to add multiple tiles and then
 /// create a `GameTiles` instance representing the full tile set.
+#[derive(Default)]
 pub struct GameBuilder {
     tiles: Vec<Tile>,
+    start_tile: Option<TileId>,
+    seed: Option<u64>,
+    deck_size: Option<usize>,
+    rules: RulesConfig,
 }
 
 impl GameBuilder {
     /// Creates a new, empty `GameBuilder`.
     pub fn new() -> Self {
-        Self { tiles: Vec::new() }
+        Self {
+            tiles: Vec::new(),
+            start_tile: None,
+            seed: None,
+            deck_size: None,
+            rules: RulesConfig::default(),
+        }
     }
 
     /// Adds a quantity of identical tiles to the builder.
@@ -24,13 +36,87 @@ impl GameBuilder {
         self
     }
 
+    /// Adds every printed copy of a tile, as recorded in its metadata.
+    ///
+    /// A tile without metadata is added once.
+    pub fn add_print_run(self, tile: Tile) -> Self {
+        let copies = tile.meta.map_or(1, |meta| meta.copies);
+        self.add_tiles(tile, copies)
+    }
+
+    /// Sets aside a copy of the tile with the given id, to be laid at the origin when
+    /// the game begins.
+    ///
+    /// In the base game, the start tile is one of the four `D` tiles. The copy is taken
+    /// from the added tiles when the builder is built; no start tile is set if none
+    /// of them has the id.
+    pub fn start_tile(mut self, id: TileId) -> Self {
+        self.start_tile = Some(id);
+        self
+    }
+
+    /// Seeds the random number generator drawing the tiles, so that games built with
+    /// the same seed draw the same tiles in the same order.
+    ///
+    /// Without a seed, the generator is seeded from the operating system.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Plays a short game: only `size` tiles taken at random from the added ones are put
+    /// in the bag, and the game ends once they are all drawn.
+    ///
+    /// The start tile is set aside first, and the tiles are picked with the seed of the
+    /// builder, if any. Every added tile is kept if there are no more than `size`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use carcasonne_core::builder::game_builder::GameBuilder;
+    /// use carcasonne_core::builder::tile_builder::TileBuilder;
+    /// use carcasonne_core::model::tile::TileId;
+    /// use carcasonne_core::model::tile_feature::Edge;
+    ///
+    /// let road = TileBuilder::new(TileId('U')).add_road(vec![Edge::West, Edge::East]).build();
+    /// let tiles = GameBuilder::new()
+    ///     .add_tiles(road, 30)
+    ///     .start_tile(TileId('U'))
+    ///     .deck_size(20)
+    ///     .build();
+    /// assert_eq!(tiles.available_tiles.len(), 20);
+    /// assert!(tiles.start_tile.is_some());
+    /// ```
+    pub fn deck_size(mut self, size: usize) -> Self {
+        self.deck_size = Some(size);
+        self
+    }
+
+    /// Sets the rules the game is played with, in place of the base game ones.
+    pub fn rules(mut self, rules: RulesConfig) -> Self {
+        self.rules = rules;
+        self
+    }
+
     /// Finalizes the builder and returns a `GameTiles` instance containing all added tiles.
     ///
     /// After calling `build`, the builder is consumed.
-    pub fn build(self) -> GameTiles {
-        GameTiles {
+    pub fn build(mut self) -> GameTiles {
+        let start_tile = self
+            .start_tile
+            .and_then(|id| self.tiles.iter().position(|tile| tile.id == id))
+            .map(|index| self.tiles.remove(index));
+        let mut tiles = GameTiles {
             available_tiles: self.tiles,
+            start_tile,
+            seed: self.seed,
+            rules: self.rules,
+        };
+        if let Some(size) = self.deck_size {
+            let mut rng = tiles.rng();
+            tiles.keep_random(size, &mut rng);
         }
+        tiles
     }
 }
 
@@ -38,8 +124,10 @@ impl GameBuilder {
 mod tests {
     use super::*;
     use crate::builder::tile_builder::TileBuilder;
+    use crate::model::tile::TileId;
     use crate::model::tile_feature::Edge::North;
-    use crate::model::tile_feature::{Road, Town};
+    use crate::model::tile_feature::{EdgeSet, Road, Town};
+    use crate::model::tile_meta::ExpansionId;
     use std::any::TypeId;
 
     #[test]
@@ -50,7 +138,7 @@ mod tests {
 
     #[test]
     fn test_game_builder_add_tiles_once() {
-        let tile = TileBuilder::new().add_town(vec![North]).build();
+        let tile = TileBuilder::new(TileId('Z')).add_town(vec![North]).build();
         let game = GameBuilder::new().add_tiles(tile.clone(), 3).build();
 
         assert_eq!(game.available_tiles.len(), 3);
@@ -61,14 +149,14 @@ mod tests {
                     && t.tile_features.len() == 1
                     && t.tile_features[0].feature_type.as_ref().type_id() == TypeId::of::<Town>()
                     && t.tile_features[0].edges.len() == 1
-                    && t.tile_features[0].edges[0] == North)
+                    && t.tile_features[0].edges.contains(North))
         );
     }
 
     #[test]
     fn test_game_builder_add_tiles_multiple() {
-        let tile1 = TileBuilder::new().add_town(vec![North]).build();
-        let tile2 = TileBuilder::new().add_road(vec![North]).build();
+        let tile1 = TileBuilder::new(TileId('Z')).add_town(vec![North]).build();
+        let tile2 = TileBuilder::new(TileId('Z')).add_road(vec![North]).build();
 
         let game = GameBuilder::new()
             .add_tiles(tile1.clone(), 2)
@@ -83,11 +171,88 @@ mod tests {
         assert!(compare_tile_extension::<Road>(&game.available_tiles[4]));
     }
 
+    #[test]
+    fn test_game_builder_add_print_run() {
+        let tile = TileBuilder::new(TileId('D'))
+            .add_town(vec![North])
+            .print_run(ExpansionId::Base, 4)
+            .build();
+        let untracked = TileBuilder::new(TileId('Z')).add_road(vec![North]).build();
+
+        let game = GameBuilder::new()
+            .add_print_run(tile)
+            .add_print_run(untracked)
+            .build();
+
+        assert_eq!(game.available_tiles.len(), 5);
+        assert!(compare_tile_extension::<Road>(&game.available_tiles[4]));
+    }
+
+    #[test]
+    fn test_game_builder_sets_start_tile_aside() {
+        let tile = TileBuilder::new(TileId('D')).add_town(vec![North]).build();
+        let other = TileBuilder::new(TileId('Z')).add_road(vec![North]).build();
+
+        let game = GameBuilder::new()
+            .add_tiles(other.clone(), 2)
+            .add_tiles(tile, 4)
+            .start_tile(TileId('D'))
+            .build();
+        assert_eq!(game.start_tile.map(|tile| tile.id), Some(TileId('D')));
+        assert_eq!(game.available_tiles.len(), 5);
+
+        let game = GameBuilder::new()
+            .add_tiles(other, 2)
+            .start_tile(TileId('D'))
+            .build();
+        assert!(game.start_tile.is_none());
+        assert_eq!(game.available_tiles.len(), 2);
+    }
+
+    #[test]
+    fn test_game_builder_seed() {
+        assert_eq!(GameBuilder::new().build().seed, None);
+        assert_eq!(GameBuilder::new().seed(42).build().seed, Some(42));
+    }
+
+    #[test]
+    fn test_game_builder_rules() {
+        let rules = RulesConfig {
+            farmers: false,
+            ..RulesConfig::default()
+        };
+        assert_eq!(GameBuilder::new().build().rules, RulesConfig::default());
+        assert_eq!(GameBuilder::new().rules(rules).build().rules, rules);
+    }
+
+    #[test]
+    fn test_game_builder_deck_size() {
+        let tiles: Vec<Tile> = "ABCDEFGHIJ"
+            .chars()
+            .map(|id| TileBuilder::new(TileId(id)).add_road(vec![North]).build())
+            .collect();
+        let short_game = |seed: u64, size: usize| {
+            let builder = tiles.iter().fold(GameBuilder::new(), |builder, tile| {
+                builder.add_tiles(tile.clone(), 1)
+            });
+            builder
+                .seed(seed)
+                .deck_size(size)
+                .build()
+                .available_tiles
+                .iter()
+                .map(|tile| tile.id)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(short_game(4, 3).len(), 3);
+        assert_eq!(short_game(4, 3), short_game(4, 3));
+        assert_eq!(short_game(4, 20).len(), 10);
+    }
+
     fn compare_tile_extension<T: 'static>(tile: &Tile) -> bool {
         tile.tile_extension.is_none()
             && tile.tile_features.len() == 1
             && tile.tile_features[0].feature_type.as_ref().type_id() == TypeId::of::<T>()
-            && tile.tile_features[0].edges.len() == 1
-            && tile.tile_features[0].edges[0] == North
+            && tile.tile_features[0].edges == EdgeSet::from([North])
     }
 }
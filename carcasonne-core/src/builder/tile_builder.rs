@@ -1,7 +1,47 @@
 use crate::builder::tile_feature_builder::TileFeatureBuilder;
-use crate::model::tile::Tile;
-use crate::model::tile_extension::{Abbey, TileExtension};
-use crate::model::tile_feature::{Edge, Road, Shield, TileFeature, Town};
+use crate::model::tile::{Tile, TileId};
+use crate::model::tile_extension::{MonasteryKind, TileExtension};
+use crate::model::tile_feature::{
+    EdgeSegment, EdgeSet, Field, Road, SegmentSet, TileFeature, TileFeatureEnhancement, Town,
+};
+use crate::model::tile_meta::{ExpansionId, TileMeta};
+use std::fmt::{Display, Formatter};
+
+/// The reasons a tile definition can be rejected by `TileBuilder::try_build`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TileBuildError {
+    /// Two features, given by their index in the tile, claim the same edge segment.
+    ///
+    /// Roads only claim the center of their edges, leaving the sides to fields.
+    OverlappingFeatures {
+        segment: EdgeSegment,
+        first: usize,
+        second: usize,
+    },
+    /// The feature at the given index covers no edge at all.
+    FeatureWithoutEdges { feature: usize },
+}
+
+impl Display for TileBuildError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TileBuildError::OverlappingFeatures {
+                segment,
+                first,
+                second,
+            } => write!(
+                f,
+                "features {first} and {second} both claim the {:?} part of the {:?} edge",
+                segment.side, segment.edge
+            ),
+            TileBuildError::FeatureWithoutEdges { feature } => {
+                write!(f, "feature {feature} has no edges")
+            }
+        }
+    }
+}
+
+impl std::error::Error for TileBuildError {}
 
 /// A builder for constructing complex `Tile` instances.
 ///
@@ -10,16 +50,20 @@ use crate::model::tile_feature::{Edge, Road, Shield, TileFeature, Town};
 ///
 /// The builder methods consume and return `self` for ergonomic chaining.
 pub struct TileBuilder {
+    id: TileId,
     tile_features: Vec<TileFeature>,
-    tile_extension: Option<Box<dyn TileExtension>>,
+    tile_extension: Option<TileExtension>,
+    meta: Option<TileMeta>,
 }
 
 impl TileBuilder {
-    /// Creates a new empty `TileBuilder`.
-    pub fn new() -> Self {
+    /// Creates a new empty `TileBuilder` for the tile definition identified by `id`.
+    pub fn new(id: TileId) -> Self {
         Self {
+            id,
             tile_features: Vec::new(),
             tile_extension: None,
+            meta: None,
         }
     }
 
@@ -28,7 +72,7 @@ impl TileBuilder {
     /// # Arguments
     ///
     /// * `edges` - The edges of the tile that the town feature occupies.
-    pub fn add_town(mut self, edges: Vec<Edge>) -> Self {
+    pub fn add_town(mut self, edges: impl Into<EdgeSet>) -> Self {
         self.tile_features.push(
             TileFeatureBuilder::new(Box::new(Town {}))
                 .edges(edges)
@@ -44,11 +88,26 @@ impl TileBuilder {
     /// # Arguments
     ///
     /// * `edges` - The edges of the tile that the shielded town occupies.
-    pub fn add_shielded_town(mut self, edges: Vec<Edge>) -> Self {
+    pub fn add_shielded_town(mut self, edges: impl Into<EdgeSet>) -> Self {
+        self.tile_features.push(
+            TileFeatureBuilder::new(Box::new(Town {}))
+                .edges(edges)
+                .enhancement(TileFeatureEnhancement::Shield)
+                .build(),
+        );
+        self
+    }
+
+    /// Adds a town feature with a cathedral on the specified edges.
+    ///
+    /// # Arguments
+    ///
+    /// * `edges` - The edges of the tile that the town occupies.
+    pub fn add_cathedral_town(mut self, edges: impl Into<EdgeSet>) -> Self {
         self.tile_features.push(
             TileFeatureBuilder::new(Box::new(Town {}))
                 .edges(edges)
-                .enhancement(Box::new(Shield {}))
+                .enhancement(TileFeatureEnhancement::Cathedral)
                 .build(),
         );
         self
@@ -59,10 +118,40 @@ impl TileBuilder {
     /// # Arguments
     ///
     /// * `edges` - The edges of the tile that the road feature occupies.
-    pub fn add_road(mut self, edges: Vec<Edge>) -> Self {
+    pub fn add_road(mut self, edges: impl Into<EdgeSet>) -> Self {
+        self.tile_features.push(
+            TileFeatureBuilder::new(Box::new(Road {}))
+                .edges(edges)
+                .build(),
+        );
+        self
+    }
+
+    /// Adds a road feature with an inn on the specified edges.
+    ///
+    /// # Arguments
+    ///
+    /// * `edges` - The edges of the tile that the road occupies.
+    pub fn add_inn_road(mut self, edges: impl Into<EdgeSet>) -> Self {
         self.tile_features.push(
             TileFeatureBuilder::new(Box::new(Road {}))
                 .edges(edges)
+                .enhancement(TileFeatureEnhancement::Inn)
+                .build(),
+        );
+        self
+    }
+
+    /// Adds a field feature covering the specified edge segments.
+    ///
+    /// # Arguments
+    ///
+    /// * `segments` - The edge segments of the tile that the field covers. A list of
+    ///   edges covers those edges entirely.
+    pub fn add_field(mut self, segments: impl Into<SegmentSet>) -> Self {
+        self.tile_features.push(
+            TileFeatureBuilder::new(Box::new(Field {}))
+                .segments(segments)
                 .build(),
         );
         self
@@ -72,15 +161,85 @@ impl TileBuilder {
     ///
     /// Abbeys are special tile extensions that typically affect scoring or placement.
     pub fn add_abbey(mut self) -> Self {
-        self.tile_extension = Some(Box::new(Abbey {}));
+        self.tile_extension = Some(TileExtension::Abbey);
         self
     }
 
-    /// Finalizes the builder and returns the constructed `Tile`.
+    /// Adds a Garden tile extension, from the Abbot mini-expansion.
+    pub fn add_garden(mut self) -> Self {
+        self.tile_extension = Some(TileExtension::Garden);
+        self
+    }
+
+    /// Adds a Monastery tile extension of the given kind.
+    pub fn add_monastery(mut self, kind: MonasteryKind) -> Self {
+        self.tile_extension = Some(TileExtension::Monastery(kind));
+        self
+    }
+
+    /// Records the expansion the tile is printed in and its number of copies.
+    ///
+    /// The letter of the metadata is the one of the tile identifier.
+    pub fn print_run(mut self, expansion: ExpansionId, copies: usize) -> Self {
+        self.meta = Some(TileMeta {
+            expansion,
+            copies,
+            letter: self.id.0,
+        });
+        self
+    }
+
+    /// Finalizes the builder after checking the tile definition is consistent.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TileBuildError` when a feature has no edges, or when two features
+    /// claim the same edge segment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use carcasonne_core::builder::tile_builder::{TileBuildError, TileBuilder};
+    /// use carcasonne_core::model::tile::TileId;
+    /// use carcasonne_core::model::tile_feature::Edge;
+    ///
+    /// let result = TileBuilder::new(TileId('Z'))
+    ///     .add_town(vec![Edge::North])
+    ///     .add_road(vec![Edge::North, Edge::South])
+    ///     .try_build();
+    /// assert!(matches!(result, Err(TileBuildError::OverlappingFeatures { .. })));
+    /// ```
+    pub fn try_build(self) -> Result<Tile, TileBuildError> {
+        for (index, feature) in self.tile_features.iter().enumerate() {
+            if feature.edges.is_empty() {
+                return Err(TileBuildError::FeatureWithoutEdges { feature: index });
+            }
+        }
+
+        let mut claimed: Vec<(EdgeSegment, usize)> = Vec::new();
+        for (index, feature) in self.tile_features.iter().enumerate() {
+            for segment in feature.segments.iter() {
+                if let Some(&(_, first)) = claimed.iter().find(|(claimed, _)| *claimed == segment) {
+                    return Err(TileBuildError::OverlappingFeatures {
+                        segment,
+                        first,
+                        second: index,
+                    });
+                }
+                claimed.push((segment, index));
+            }
+        }
+
+        Ok(self.build())
+    }
+
+    /// Finalizes the builder and returns the constructed `Tile`, without any validation.
     pub fn build(self) -> Tile {
         Tile {
+            id: self.id,
             tile_features: self.tile_features,
             tile_extension: self.tile_extension,
+            meta: self.meta,
         }
     }
 }
@@ -88,12 +247,13 @@ impl TileBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::model::tile_feature::Edge;
+    use crate::model::tile_feature::{Edge, Side};
     use std::any::TypeId;
 
     #[test]
     fn test_new_tile_builder() {
-        let builder = TileBuilder::new();
+        let builder = TileBuilder::new(TileId('Z'));
+        assert_eq!(builder.id, TileId('Z'));
         assert!(builder.tile_features.is_empty());
         assert!(builder.tile_extension.is_none());
     }
@@ -101,12 +261,14 @@ mod tests {
     #[test]
     fn test_add_town() {
         let edges = vec![Edge::North, Edge::East];
-        let tile = TileBuilder::new().add_town(edges.clone()).build();
+        let tile = TileBuilder::new(TileId('Z'))
+            .add_town(edges.clone())
+            .build();
 
         assert_eq!(tile.tile_features.len(), 1);
         let feature = &tile.tile_features[0];
 
-        assert_eq!(feature.edges, edges);
+        assert_eq!(feature.edges, EdgeSet::from(edges));
         assert!(feature.enhancement.is_none());
         assert_eq!(
             feature.feature_type.as_ref().type_id(),
@@ -117,33 +279,32 @@ mod tests {
     #[test]
     fn test_add_shielded_town() {
         let edges = vec![Edge::South, Edge::West];
-        let tile = TileBuilder::new().add_shielded_town(edges.clone()).build();
+        let tile = TileBuilder::new(TileId('Z'))
+            .add_shielded_town(edges.clone())
+            .build();
 
         assert_eq!(tile.tile_features.len(), 1);
         let feature = &tile.tile_features[0];
 
-        assert_eq!(feature.edges, edges);
-        assert!(feature.enhancement.is_some());
+        assert_eq!(feature.edges, EdgeSet::from(edges));
         assert_eq!(
             feature.feature_type.as_ref().type_id(),
             TypeId::of::<Town>()
         );
-        assert_eq!(
-            feature.enhancement.clone().unwrap().as_ref().type_id(),
-            TypeId::of::<Shield>()
-        );
+        assert_eq!(feature.enhancement, Some(TileFeatureEnhancement::Shield));
     }
 
     #[test]
     fn test_add_road() {
         let edges = vec![Edge::North];
-        let tile = TileBuilder::new().add_road(edges.clone()).build();
+        let tile = TileBuilder::new(TileId('Z'))
+            .add_road(edges.clone())
+            .build();
 
         assert_eq!(tile.tile_features.len(), 1);
         let feature = &tile.tile_features[0];
-        println!("abbey {:?}", TypeId::of::<Abbey>());
 
-        assert_eq!(feature.edges, edges);
+        assert_eq!(feature.edges, EdgeSet::from(edges));
         assert!(feature.enhancement.is_none());
         assert_eq!(
             feature.feature_type.as_ref().type_id(),
@@ -151,22 +312,64 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_add_field() {
+        let edges = vec![Edge::North, Edge::East, Edge::South];
+        let tile = TileBuilder::new(TileId('Z'))
+            .add_field(edges.clone())
+            .build();
+
+        assert_eq!(tile.tile_features.len(), 1);
+        let feature = &tile.tile_features[0];
+        assert_eq!(feature.edges, EdgeSet::from(edges));
+        assert!(feature.is_field());
+    }
+
     #[test]
     fn test_add_abbey() {
-        let tile = TileBuilder::new().add_abbey().build();
-        assert!(tile.tile_extension.is_some());
+        let tile = TileBuilder::new(TileId('Z')).add_abbey().build();
+        assert_eq!(tile.tile_extension, Some(TileExtension::Abbey));
+    }
+
+    #[test]
+    fn test_add_garden() {
+        let tile = TileBuilder::new(TileId('Z')).add_garden().build();
+        assert_eq!(tile.tile_extension, Some(TileExtension::Garden));
+    }
+
+    #[test]
+    fn test_add_monastery() {
+        let tile = TileBuilder::new(TileId('Z'))
+            .add_monastery(MonasteryKind::Shrine)
+            .build();
         assert_eq!(
-            tile.tile_extension.unwrap().as_ref().type_id(),
-            TypeId::of::<Abbey>()
+            tile.tile_extension,
+            Some(TileExtension::Monastery(MonasteryKind::Shrine))
         );
     }
 
+    #[test]
+    fn test_print_run() {
+        let tile = TileBuilder::new(TileId('D'))
+            .print_run(ExpansionId::Base, 4)
+            .build();
+        assert_eq!(
+            tile.meta,
+            Some(TileMeta {
+                expansion: ExpansionId::Base,
+                copies: 4,
+                letter: 'D',
+            })
+        );
+        assert!(TileBuilder::new(TileId('Z')).build().meta.is_none());
+    }
+
     #[test]
     fn test_combined_tile() {
         let edges_town = vec![Edge::North, Edge::South];
         let edges_road = vec![Edge::East, Edge::West];
 
-        let tile = TileBuilder::new()
+        let tile = TileBuilder::new(TileId('Z'))
             .add_town(edges_town.clone())
             .add_road(edges_road.clone())
             .add_abbey()
@@ -183,4 +386,68 @@ mod tests {
         );
         assert!(tile.tile_extension.is_some());
     }
+
+    #[test]
+    fn test_try_build_accepts_valid_tile() {
+        let tile = TileBuilder::new(TileId('Z'))
+            .add_town(vec![Edge::North])
+            .add_road(vec![Edge::East, Edge::West])
+            .add_field(
+                SegmentSet::from([Edge::South])
+                    .with(Edge::East.segment(Side::Right))
+                    .with(Edge::West.segment(Side::Left)),
+            )
+            .add_field([
+                Edge::East.segment(Side::Left),
+                Edge::West.segment(Side::Right),
+            ])
+            .try_build()
+            .unwrap();
+        assert_eq!(tile.tile_features.len(), 4);
+    }
+
+    #[test]
+    fn test_try_build_rejects_overlapping_features() {
+        let result = TileBuilder::new(TileId('Z'))
+            .add_town(vec![Edge::North, Edge::East])
+            .add_road(vec![Edge::South])
+            .add_road(vec![Edge::East])
+            .try_build();
+        assert_eq!(
+            result.unwrap_err(),
+            TileBuildError::OverlappingFeatures {
+                segment: Edge::East.segment(Side::Center),
+                first: 0,
+                second: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn test_try_build_rejects_field_across_road() {
+        let result = TileBuilder::new(TileId('Z'))
+            .add_road(vec![Edge::North, Edge::South])
+            .add_field(vec![Edge::North, Edge::East, Edge::South])
+            .try_build();
+        assert_eq!(
+            result.unwrap_err(),
+            TileBuildError::OverlappingFeatures {
+                segment: Edge::North.segment(Side::Center),
+                first: 0,
+                second: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn test_try_build_rejects_feature_without_edges() {
+        let result = TileBuilder::new(TileId('Z'))
+            .add_road(vec![Edge::North, Edge::South])
+            .add_town(Vec::new())
+            .try_build();
+        assert_eq!(
+            result.unwrap_err(),
+            TileBuildError::FeatureWithoutEdges { feature: 1 }
+        );
+    }
 }
@@ -1,13 +1,17 @@
-use crate::model::tile_feature::{Edge, TileFeature, TileFeatureEnhancement, TileFeatureType};
+use crate::model::tile_feature::{
+    EdgeSet, SegmentSet, TileFeature, TileFeatureEnhancement, TileFeatureType,
+};
 
 /// Builder pattern for constructing `TileFeature` instances.
 ///
-/// Supports setting the feature type, the edges it spans, and an optional enhancement.
+/// Supports setting the feature type, the edges or edge segments it spans, and an
+/// optional enhancement.
 /// Methods consume and return `self` for chaining.
 pub struct TileFeatureBuilder {
     feature_type: Box<dyn TileFeatureType>,
-    edges: Vec<Edge>,
-    enhancement: Option<Box<dyn TileFeatureEnhancement>>,
+    edges: EdgeSet,
+    segments: Option<SegmentSet>,
+    enhancement: Option<TileFeatureEnhancement>,
 }
 
 impl TileFeatureBuilder {
@@ -19,7 +23,8 @@ impl TileFeatureBuilder {
     pub fn new(feature: Box<dyn TileFeatureType>) -> Self {
         Self {
             feature_type: feature,
-            edges: vec![],
+            edges: EdgeSet::empty(),
+            segments: None,
             enhancement: None,
         }
     }
@@ -28,9 +33,27 @@ impl TileFeatureBuilder {
     ///
     /// # Arguments
     ///
-    /// * `edge` - A vector of `Edge` values representing tile edges.
-    pub fn edges(mut self, edge: Vec<Edge>) -> Self {
-        self.edges = edge;
+    /// * `edge` - The tile edges, as an `EdgeSet` or anything convertible into one
+    ///   (e.g. a vector or an array of `Edge`).
+    pub fn edges(mut self, edge: impl Into<EdgeSet>) -> Self {
+        self.edges = edge.into();
+        self.segments = None;
+        self
+    }
+
+    /// Sets the edge segments that this feature covers on the tile.
+    ///
+    /// Only needed when the feature covers part of an edge other than the
+    /// default of its kind, such as a field lying on one side of a road.
+    /// The edges of the feature are the ones the segments belong to.
+    ///
+    /// # Arguments
+    ///
+    /// * `segments` - The edge segments, as a `SegmentSet` or anything convertible into one.
+    pub fn segments(mut self, segments: impl Into<SegmentSet>) -> Self {
+        let segments = segments.into();
+        self.edges = segments.edges();
+        self.segments = Some(segments);
         self
     }
 
@@ -38,16 +61,20 @@ impl TileFeatureBuilder {
     ///
     /// # Arguments
     ///
-    /// * `enhancement` - A boxed trait object implementing `TileFeatureEnhancement`.
-    pub fn enhancement(mut self, enhancement: Box<dyn TileFeatureEnhancement>) -> Self {
+    /// * `enhancement` - The enhancement applied to the feature (e.g. a shield).
+    pub fn enhancement(mut self, enhancement: TileFeatureEnhancement) -> Self {
         self.enhancement = Some(enhancement);
         self
     }
 
     /// Builds the final `TileFeature` instance.
     pub fn build(self) -> TileFeature {
+        let segments = self
+            .segments
+            .unwrap_or_else(|| self.feature_type.kind().segments_on(self.edges));
         TileFeature {
             edges: self.edges,
+            segments,
             feature_type: self.feature_type,
             enhancement: self.enhancement,
         }
@@ -57,7 +84,7 @@ impl TileFeatureBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::model::tile_feature::{Road, Shield, Town};
+    use crate::model::tile_feature::{Edge, Field, Road, Side, Town};
     use std::any::TypeId;
     #[test]
     fn test_tile_feature_builder_with_town() {
@@ -67,7 +94,7 @@ mod tests {
             .edges(edges.clone())
             .build();
 
-        assert_eq!(feature.edges, edges);
+        assert_eq!(feature.edges, EdgeSet::from(edges));
         assert!(feature.enhancement.is_none());
         assert_eq!(
             feature.feature_type.as_ref().type_id(),
@@ -83,7 +110,11 @@ mod tests {
             .edges(edges.clone())
             .build();
 
-        assert_eq!(feature.edges, edges);
+        assert_eq!(feature.edges, EdgeSet::from(edges));
+        assert_eq!(
+            feature.segments,
+            SegmentSet::from([Edge::South.segment(Side::Center)])
+        );
         assert!(feature.enhancement.is_none());
         assert_eq!(
             feature.feature_type.as_ref().type_id(),
@@ -91,24 +122,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_tile_feature_builder_with_segments() {
+        let segments = SegmentSet::from([Edge::West]).with(Edge::North.segment(Side::Left));
+
+        let feature = TileFeatureBuilder::new(Box::new(Field {}))
+            .segments(segments)
+            .build();
+
+        assert_eq!(feature.segments, segments);
+        assert_eq!(feature.edges, EdgeSet::from([Edge::North, Edge::West]));
+    }
+
     #[test]
     fn test_tile_feature_builder_with_enhancement() {
         let edges = vec![Edge::West];
 
         let feature = TileFeatureBuilder::new(Box::new(Town {}))
             .edges(edges.clone())
-            .enhancement(Box::new(Shield {}))
+            .enhancement(TileFeatureEnhancement::Shield)
             .build();
 
-        assert_eq!(feature.edges, edges);
-        assert!(feature.enhancement.is_some());
+        assert_eq!(feature.edges, EdgeSet::from(edges));
         assert_eq!(
             feature.feature_type.as_ref().type_id(),
             TypeId::of::<Town>()
         );
-        assert_eq!(
-            feature.enhancement.unwrap().as_ref().type_id(),
-            TypeId::of::<Shield>()
-        );
+        assert_eq!(feature.enhancement, Some(TileFeatureEnhancement::Shield));
     }
 }
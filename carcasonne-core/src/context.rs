@@ -1,13 +1,71 @@
 use crate::model::tile::Tile;
-use rand::rng;
+use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
+use rand::{RngCore, SeedableRng};
 
+/// The tiles left to draw, and the random number generator drawing them.
+///
+/// The board, the players and the turns are kept by `Game`. The generator can be
+/// injected to make draws deterministic.
 pub struct GameContext {
     /// The list of remaining tiles in the game.
     pub available_tiles: Vec<Tile>,
+    /// The source of randomness used to draw tiles.
+    rng: Box<dyn RngCore + Send + Sync>,
 }
 
 impl GameContext {
+    /// Creates a new context drawing from `available_tiles`.
+    ///
+    /// Tiles are drawn using a random number generator seeded from the operating system.
+    pub fn new(available_tiles: Vec<Tile>) -> Self {
+        Self::with_rng(available_tiles, Box::new(StdRng::from_os_rng()))
+    }
+
+    /// Creates a new context drawing tiles with the given random number generator.
+    ///
+    /// # Arguments
+    ///
+    /// * `available_tiles` - The tiles that can be drawn during the game.
+    /// * `rng` - The source of randomness used for every draw.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use carcasonne_core::context::GameContext;
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    ///
+    /// let context = GameContext::with_rng(vec![], Box::new(StdRng::seed_from_u64(7)));
+    /// ```
+    pub fn with_rng(available_tiles: Vec<Tile>, rng: Box<dyn RngCore + Send + Sync>) -> Self {
+        Self {
+            available_tiles,
+            rng,
+        }
+    }
+
+    /// Creates a new context drawing tiles with a random number generator seeded with
+    /// `seed`, so that two contexts with the same seed draw the same tiles.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use carcasonne_core::context::GameContext;
+    /// use carcasonne_core::factory::game_factory::GameTilesFactory;
+    ///
+    /// let tiles = GameTilesFactory::build_base_game().available_tiles;
+    /// let mut first = GameContext::with_seed(tiles.clone(), 7);
+    /// let mut second = GameContext::with_seed(tiles, 7);
+    /// assert_eq!(
+    ///     first.select_random_tile().map(|tile| tile.id),
+    ///     second.select_random_tile().map(|tile| tile.id)
+    /// );
+    /// ```
+    pub fn with_seed(available_tiles: Vec<Tile>, seed: u64) -> Self {
+        Self::with_rng(available_tiles, Box::new(StdRng::seed_from_u64(seed)))
+    }
+
     /// Randomly selects and removes a tile from the remaining pool.
     ///
     /// Internally, this method shuffles the remaining tiles and pops one
@@ -18,11 +76,11 @@ impl GameContext {
     /// ```
     /// use carcasonne_core::context::GameContext;
     ///
-    /// let mut game_tiles = GameContext { available_tiles: vec![] };
+    /// let mut game_tiles = GameContext::new(vec![]);
     /// let tile = game_tiles.select_random_tile();
     /// ```
     pub fn select_random_tile(&mut self) -> Option<Tile> {
-        self.available_tiles.shuffle(&mut rng());
+        self.available_tiles.shuffle(&mut self.rng);
         self.available_tiles.pop()
     }
 }
@@ -30,20 +88,21 @@ impl GameContext {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::model::tile::Tile;
+    use crate::factory::game_factory::GameTilesFactory;
+    use crate::model::tile::{Tile, TileId};
 
     fn dummy_tile() -> Tile {
         Tile {
+            id: TileId('Z'),
             tile_features: vec![],
             tile_extension: None,
+            meta: None,
         }
     }
 
     #[test]
     fn test_select_random_tile_returns_tile() {
-        let mut game_tiles = GameContext {
-            available_tiles: vec![dummy_tile()],
-        };
+        let mut game_tiles = GameContext::new(vec![dummy_tile()]);
 
         let tile = game_tiles.select_random_tile();
         assert!(tile.is_some(), "Expected to get a tile");
@@ -56,9 +115,7 @@ mod tests {
 
     #[test]
     fn test_select_random_tile_from_empty_deck_returns_none() {
-        let mut game_tiles = GameContext {
-            available_tiles: vec![],
-        };
+        let mut game_tiles = GameContext::new(vec![]);
         let tile = game_tiles.select_random_tile();
         assert!(
             tile.is_none(),
@@ -68,15 +125,13 @@ mod tests {
 
     #[test]
     fn test_random_selection_exhausts_all_tiles() {
-        let mut game_tiles = GameContext {
-            available_tiles: vec![
-                dummy_tile(),
-                dummy_tile(),
-                dummy_tile(),
-                dummy_tile(),
-                dummy_tile(),
-            ],
-        };
+        let mut game_tiles = GameContext::new(vec![
+            dummy_tile(),
+            dummy_tile(),
+            dummy_tile(),
+            dummy_tile(),
+            dummy_tile(),
+        ]);
 
         let mut drawn = vec![];
         while let Some(tile) = game_tiles.select_random_tile() {
@@ -93,12 +148,8 @@ mod tests {
     #[test]
     fn test_shuffling_changes_order() {
         let tiles: Vec<Tile> = vec![dummy_tile(), dummy_tile(), dummy_tile()];
-        let mut game_tiles_1 = GameContext {
-            available_tiles: tiles.clone(),
-        };
-        let mut game_tiles_2 = GameContext {
-            available_tiles: tiles.clone(),
-        };
+        let mut game_tiles_1 = GameContext::new(tiles.clone());
+        let mut game_tiles_2 = GameContext::new(tiles.clone());
 
         // Shuffle both
         game_tiles_1.select_random_tile(); // first shuffle (done implicitly)
@@ -109,4 +160,20 @@ mod tests {
         assert!(game_tiles_1.available_tiles.len() < 3);
         assert!(game_tiles_2.available_tiles.len() < 3);
     }
+
+    #[test]
+    fn test_injected_rng_makes_draws_reproducible() {
+        let tiles = GameTilesFactory::build_base_game().available_tiles;
+        let draw_all = |seed: u64| {
+            let mut context =
+                GameContext::with_rng(tiles.clone(), Box::new(StdRng::seed_from_u64(seed)));
+            let mut drawn = vec![];
+            while let Some(tile) = context.select_random_tile() {
+                drawn.push(format!("{tile:?}"));
+            }
+            drawn
+        };
+
+        assert_eq!(draw_all(42), draw_all(42));
+    }
 }
@@ -1,2 +1,3 @@
+pub mod deck_loader;
 pub mod game_factory;
-mod tile_factory;
+pub(crate) mod tile_factory;
@@ -0,0 +1,283 @@
+use crate::builder::game_builder::GameBuilder;
+use crate::builder::tile_builder::{TileBuildError, TileBuilder};
+use crate::model::game::GameTiles;
+use crate::model::tile::TileId;
+use crate::model::tile_extension::TileExtension;
+use crate::model::tile_feature::{EdgeSegment, EdgeSet, SegmentSet};
+use crate::model::tile_meta::ExpansionId;
+use serde::Deserialize;
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+use std::{fs, io};
+
+/// The reasons a deck file can fail to load.
+#[derive(Debug)]
+pub enum DeckLoadError {
+    /// The file could not be read.
+    Io(io::Error),
+    /// The file is not a well-formed deck description.
+    Parse(toml::de::Error),
+    /// A tile definition of the file is inconsistent.
+    InvalidTile {
+        /// The letter of the faulty tile definition.
+        letter: char,
+        /// What is wrong with the definition.
+        source: TileBuildError,
+    },
+}
+
+impl Display for DeckLoadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DeckLoadError::Io(e) => write!(f, "cannot read the deck file: {e}"),
+            DeckLoadError::Parse(e) => write!(f, "invalid deck file: {e}"),
+            DeckLoadError::InvalidTile { letter, source } => {
+                write!(f, "invalid definition of tile {letter}: {source}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DeckLoadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DeckLoadError::Io(e) => Some(e),
+            DeckLoadError::Parse(e) => Some(e),
+            DeckLoadError::InvalidTile { source, .. } => Some(source),
+        }
+    }
+}
+
+/// The content of a deck file.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DeckFile {
+    #[serde(default)]
+    start_tile: Option<char>,
+    tiles: Vec<TileDefinition>,
+}
+
+/// One tile definition of a deck file, with the number of copies to add.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TileDefinition {
+    letter: char,
+    copies: usize,
+    #[serde(default)]
+    expansion: ExpansionId,
+    #[serde(default)]
+    towns: Vec<EdgeSet>,
+    #[serde(default)]
+    shielded_towns: Vec<EdgeSet>,
+    #[serde(default)]
+    roads: Vec<EdgeSet>,
+    #[serde(default)]
+    fields: Vec<FieldDefinition>,
+    #[serde(default)]
+    extension: Option<TileExtension>,
+}
+
+/// A field of a deck file: the edges it covers entirely, plus single segments.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FieldDefinition {
+    #[serde(default)]
+    edges: EdgeSet,
+    #[serde(default)]
+    segments: Vec<EdgeSegment>,
+}
+
+impl TileDefinition {
+    /// Returns a builder holding the tile described by the definition.
+    fn to_builder(&self) -> TileBuilder {
+        let mut builder =
+            TileBuilder::new(TileId(self.letter)).print_run(self.expansion, self.copies);
+        for edges in &self.towns {
+            builder = builder.add_town(*edges);
+        }
+        for edges in &self.shielded_towns {
+            builder = builder.add_shielded_town(*edges);
+        }
+        for edges in &self.roads {
+            builder = builder.add_road(*edges);
+        }
+        for field in &self.fields {
+            let segments = field
+                .segments
+                .iter()
+                .fold(SegmentSet::from(field.edges), |set, segment| {
+                    set.with(*segment)
+                });
+            builder = builder.add_field(segments);
+        }
+        match self.extension {
+            Some(TileExtension::Abbey) => builder.add_abbey(),
+            Some(TileExtension::Garden) => builder.add_garden(),
+            Some(TileExtension::Monastery(kind)) => builder.add_monastery(kind),
+            None => builder,
+        }
+    }
+}
+
+/// Builds game tile sets from declarative deck files.
+///
+/// A deck file is written in TOML. It may name its start tile with a top-level
+/// `start_tile` letter, and lists tile definitions under `[[tiles]]`, each with:
+/// - `letter`: the letter code of the tile
+/// - `copies`: the number of copies in the deck
+/// - `expansion` (optional): the `ExpansionId` the tile belongs to, `Base` by default
+/// - `towns`, `shielded_towns`, `roads` (optional): one list of edges per feature
+/// - `fields` (optional): one table per field, with the `edges` it covers entirely and
+///   the `segments` it covers on edges split by a road
+/// - `extension` (optional): the `TileExtension` in the middle of the tile
+///
+/// Custom decks and fan expansions can thus be added without writing a new tile factory.
+/// `decks/base.toml` describes the base game in this format.
+pub struct DeckLoader;
+
+impl DeckLoader {
+    /// Builds the tile set described by a deck file in TOML.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DeckLoadError` if the text is not a valid deck, or if one of its
+    /// tile definitions is rejected by `TileBuilder::try_build`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use carcasonne_core::factory::deck_loader::DeckLoader;
+    ///
+    /// let deck = r#"
+    ///     [[tiles]]
+    ///     letter = "E"
+    ///     copies = 5
+    ///     towns = [["North"]]
+    ///     fields = [{ edges = ["East", "South", "West"] }]
+    /// "#;
+    /// let tiles = DeckLoader::from_toml_str(deck).unwrap();
+    /// assert_eq!(tiles.available_tiles.len(), 5);
+    /// ```
+    pub fn from_toml_str(text: &str) -> Result<GameTiles, DeckLoadError> {
+        let deck: DeckFile = toml::from_str(text).map_err(DeckLoadError::Parse)?;
+        let builder = match deck.start_tile {
+            Some(letter) => GameBuilder::new().start_tile(TileId(letter)),
+            None => GameBuilder::new(),
+        };
+        deck.tiles
+            .iter()
+            .try_fold(builder, |builder, definition| {
+                let tile = definition.to_builder().try_build().map_err(|source| {
+                    DeckLoadError::InvalidTile {
+                        letter: definition.letter,
+                        source,
+                    }
+                })?;
+                Ok(builder.add_print_run(tile))
+            })
+            .map(GameBuilder::build)
+    }
+
+    /// Reads a deck file from disk and builds the tile set it describes.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DeckLoadError` if the file cannot be read or does not describe a valid deck.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<GameTiles, DeckLoadError> {
+        let text = fs::read_to_string(path).map_err(DeckLoadError::Io)?;
+        Self::from_toml_str(&text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factory::game_factory::GameTilesFactory;
+    use crate::model::tile_extension::MonasteryKind;
+    use crate::model::tile_feature::{Edge, Side};
+
+    const BASE_DECK: &str = include_str!("../../decks/base.toml");
+
+    #[test]
+    fn test_base_deck_matches_base_game_factory() {
+        let loaded = DeckLoader::from_toml_str(BASE_DECK).unwrap();
+        let built = GameTilesFactory::build_base_game();
+        assert_eq!(
+            loaded.start_tile.map(|tile| tile.id),
+            built.start_tile.map(|tile| tile.id)
+        );
+
+        let mut loaded = loaded.available_tiles;
+        let mut built = built.available_tiles;
+        loaded.sort_by_key(|tile| tile.id);
+        built.sort_by_key(|tile| tile.id);
+
+        assert_eq!(loaded.len(), built.len());
+        for (loaded, built) in loaded.iter().zip(&built) {
+            assert_eq!(loaded.id, built.id);
+            assert_eq!(loaded.meta, built.meta);
+            assert!(loaded.equivalent_to(built), "Tile {} differs", loaded.id);
+        }
+    }
+
+    #[test]
+    fn test_load_expansion_tile() {
+        let deck = r#"
+            [[tiles]]
+            letter = "Z"
+            copies = 2
+            expansion = "Abbot"
+            roads = [["South"]]
+            fields = [{ edges = ["North", "East", "West"], segments = [
+                { edge = "South", side = "Left" },
+                { edge = "South", side = "Right" },
+            ] }]
+            extension = { Monastery = "Shrine" }
+        "#;
+        let tiles = DeckLoader::from_toml_str(deck).unwrap();
+
+        assert_eq!(tiles.remaining_from(ExpansionId::Abbot), 2);
+        let tile = &tiles.available_tiles[0];
+        assert_eq!(
+            tile.tile_extension,
+            Some(TileExtension::Monastery(MonasteryKind::Shrine))
+        );
+        assert_eq!(
+            tile.field_index_at(Edge::South.segment(Side::Left)),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn test_reject_invalid_tile() {
+        let deck = r#"
+            [[tiles]]
+            letter = "Z"
+            copies = 1
+            towns = [["North"]]
+            roads = [["North", "South"]]
+        "#;
+        assert!(matches!(
+            DeckLoader::from_toml_str(deck),
+            Err(DeckLoadError::InvalidTile { letter: 'Z', .. })
+        ));
+    }
+
+    #[test]
+    fn test_reject_malformed_file() {
+        let deck = r#"
+            [[tiles]]
+            letter = "Z"
+            copies = 1
+            castles = [["North"]]
+        "#;
+        assert!(matches!(
+            DeckLoader::from_toml_str(deck),
+            Err(DeckLoadError::Parse(_))
+        ));
+        assert!(matches!(
+            DeckLoader::from_file("missing-deck.toml"),
+            Err(DeckLoadError::Io(_))
+        ));
+    }
+}
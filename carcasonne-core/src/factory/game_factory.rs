@@ -4,6 +4,7 @@ use crate::factory::tile_factory::road_tiles_factory::RoadTileBuilder;
 use crate::factory::tile_factory::town_tiles_factory::TownTileBuilder;
 use crate::factory::tile_factory::TileFactory;
 use crate::model::game::GameTiles;
+use crate::model::tile::TileId;
 
 /// A factory responsible for creating predefined sets of game tiles.
 ///
@@ -15,8 +16,9 @@ impl GameTilesFactory {
     /// Builds the base game tile set.
     ///
     /// This method constructs the standard collection of tiles required for a
-    /// typical game session, including abbey, road, and town tiles with
-    /// predefined quantities.
+    /// typical game session, including abbey, road, and town tiles, each in the
+    /// quantity printed in the base game box. One of the `D` tiles is set aside as
+    /// the start tile.
     ///
     /// # Examples
     ///
@@ -28,32 +30,93 @@ impl GameTilesFactory {
     pub fn build_base_game() -> GameTiles {
         GameBuilder::new()
             // Add Abbey
-            .add_tiles(TileFactory::build_a_abbey(), 2)
-            .add_tiles(TileFactory::build_b_abbey(), 4)
+            .add_print_run(TileFactory::build_a_abbey())
+            .add_print_run(TileFactory::build_b_abbey())
             // Add Road
-            .add_tiles(TileFactory::build_u_road(), 8)
-            .add_tiles(TileFactory::build_v_road(), 9)
-            .add_tiles(TileFactory::build_x_road(), 1)
-            .add_tiles(TileFactory::build_w_road(), 4)
+            .add_print_run(TileFactory::build_u_road())
+            .add_print_run(TileFactory::build_v_road())
+            .add_print_run(TileFactory::build_x_road())
+            .add_print_run(TileFactory::build_w_road())
             // Add Town
-            .add_tiles(TileFactory::build_c_town(), 1)
-            .add_tiles(TileFactory::build_d_town(), 4)
-            .add_tiles(TileFactory::build_e_town(), 5)
-            .add_tiles(TileFactory::build_f_town(), 2)
-            .add_tiles(TileFactory::build_g_town(), 1)
-            .add_tiles(TileFactory::build_h_town(), 3)
-            .add_tiles(TileFactory::build_i_town(), 2)
-            .add_tiles(TileFactory::build_j_town(), 3)
-            .add_tiles(TileFactory::build_k_town(), 3)
-            .add_tiles(TileFactory::build_l_town(), 3)
-            .add_tiles(TileFactory::build_m_town(), 2)
-            .add_tiles(TileFactory::build_n_town(), 3)
-            .add_tiles(TileFactory::build_o_town(), 2)
-            .add_tiles(TileFactory::build_p_town(), 3)
-            .add_tiles(TileFactory::build_q_town(), 1)
-            .add_tiles(TileFactory::build_r_town(), 3)
-            .add_tiles(TileFactory::build_s_town(), 2)
-            .add_tiles(TileFactory::build_t_town(), 1)
+            .add_print_run(TileFactory::build_c_town())
+            .add_print_run(TileFactory::build_d_town())
+            .add_print_run(TileFactory::build_e_town())
+            .add_print_run(TileFactory::build_f_town())
+            .add_print_run(TileFactory::build_g_town())
+            .add_print_run(TileFactory::build_h_town())
+            .add_print_run(TileFactory::build_i_town())
+            .add_print_run(TileFactory::build_j_town())
+            .add_print_run(TileFactory::build_k_town())
+            .add_print_run(TileFactory::build_l_town())
+            .add_print_run(TileFactory::build_m_town())
+            .add_print_run(TileFactory::build_n_town())
+            .add_print_run(TileFactory::build_o_town())
+            .add_print_run(TileFactory::build_p_town())
+            .add_print_run(TileFactory::build_q_town())
+            .add_print_run(TileFactory::build_r_town())
+            .add_print_run(TileFactory::build_s_town())
+            .add_print_run(TileFactory::build_t_town())
+            .start_tile(TileId('D'))
             .build()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::tile::{Tile, TileId};
+    use crate::model::tile_meta::ExpansionId;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn test_base_game_uses_letters_a_to_x() {
+        let ids: BTreeSet<TileId> = GameTilesFactory::build_base_game()
+            .available_tiles
+            .iter()
+            .map(|tile| tile.id)
+            .collect();
+        let expected: BTreeSet<TileId> = ('A'..='X').map(TileId).collect();
+        assert_eq!(ids, expected);
+    }
+
+    #[test]
+    fn test_base_game_print_run() {
+        let tiles = GameTilesFactory::build_base_game();
+        let start_tile = tiles
+            .start_tile
+            .as_ref()
+            .expect("The base game has a start tile");
+        assert_eq!(start_tile.id, TileId('D'));
+        assert_eq!(tiles.available_tiles.len(), 71);
+        assert_eq!(tiles.remaining_from(ExpansionId::Base), 71);
+        for tile in &tiles.available_tiles {
+            let meta = tile.meta.expect("Base game tiles carry their metadata");
+            assert_eq!(meta.letter, tile.id.0);
+            let copies = tiles
+                .available_tiles
+                .iter()
+                .chain([start_tile])
+                .filter(|other| other.id == tile.id)
+                .count();
+            assert_eq!(meta.copies, copies, "Wrong print run for {}", tile.id);
+        }
+    }
+
+    #[test]
+    fn test_base_game_tile_definitions_are_distinct() {
+        let mut definitions: Vec<Tile> = vec![];
+        for tile in GameTilesFactory::build_base_game().available_tiles {
+            if definitions.iter().any(|known| known.id == tile.id) {
+                continue;
+            }
+            if let Some(duplicate) = definitions.iter().find(|known| known.equivalent_to(&tile)) {
+                panic!(
+                    "Tiles {} and {} have the same layout",
+                    duplicate.id, tile.id
+                );
+            }
+            definitions.push(tile);
+        }
+        assert_eq!(definitions.len(), 24);
+    }
+}
@@ -1,7 +1,10 @@
 use crate::builder::tile_builder::TileBuilder;
 use crate::factory::tile_factory::TileFactory;
-use crate::model::tile::Tile;
-use crate::model::tile_feature::Edge::South;
+use crate::model::tile::{Tile, TileId};
+use crate::model::tile_feature::Edge::{East, North, South, West};
+use crate::model::tile_feature::SegmentSet;
+use crate::model::tile_feature::Side::{Left, Right};
+use crate::model::tile_meta::ExpansionId::Base;
 
 /// Defines a builder interface for generating Abbey-style tiles.
 ///
@@ -18,50 +21,60 @@ pub trait AbbeyTileBuilder {
 
 impl AbbeyTileBuilder for TileFactory {
     fn build_a_abbey() -> Tile {
-        TileBuilder::new().add_road(vec![South]).add_abbey().build()
+        TileBuilder::new(TileId('A'))
+            .print_run(Base, 2)
+            .add_road(vec![South])
+            .add_abbey()
+            .add_field(
+                SegmentSet::from([North, East, West])
+                    .with(South.segment(Left))
+                    .with(South.segment(Right)),
+            )
+            .try_build()
+            .expect("invalid tile definition")
     }
     fn build_b_abbey() -> Tile {
-        TileBuilder::new().add_abbey().build()
+        TileBuilder::new(TileId('B'))
+            .print_run(Base, 4)
+            .add_abbey()
+            .add_field(vec![North, East, South, West])
+            .try_build()
+            .expect("invalid tile definition")
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::model::tile_extension::Abbey;
+    use crate::model::tile_extension::TileExtension;
     use crate::model::tile_feature::Edge::South;
-    use crate::model::tile_feature::Road;
+    use crate::model::tile_feature::{EdgeSet, Road};
     use std::any::TypeId;
 
     #[test]
     fn test_build_a_abbey() {
         let tile = TileFactory::build_a_abbey();
 
-        assert_eq!(tile.tile_features.len(), 1);
+        assert_eq!(tile.tile_features.len(), 2);
         let feature = &tile.tile_features[0];
-        assert_eq!(feature.edges, vec![South]);
+        assert_eq!(feature.edges, EdgeSet::from([South]));
         assert_eq!(
             feature.feature_type.as_ref().type_id(),
             TypeId::of::<Road>()
         );
+        assert!(tile.tile_features[1].is_field());
+        assert_eq!(tile.tile_features[1].edges.len(), 4);
 
-        assert!(tile.tile_extension.is_some());
-        assert_eq!(
-            tile.tile_extension.unwrap().as_ref().type_id(),
-            TypeId::of::<Abbey>()
-        );
+        assert_eq!(tile.tile_extension, Some(TileExtension::Abbey));
     }
 
     #[test]
     fn test_build_b_abbey() {
         let tile = TileFactory::build_b_abbey();
 
-        assert!(tile.tile_features.is_empty());
+        assert_eq!(tile.tile_features.len(), 1);
+        assert!(tile.tile_features[0].is_field());
 
-        assert!(tile.tile_extension.is_some());
-        assert_eq!(
-            tile.tile_extension.unwrap().as_ref().type_id(),
-            TypeId::of::<Abbey>()
-        );
+        assert_eq!(tile.tile_extension, Some(TileExtension::Abbey));
     }
 }
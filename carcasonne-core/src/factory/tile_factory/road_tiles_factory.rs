@@ -1,7 +1,10 @@
 use crate::builder::tile_builder::TileBuilder;
 use crate::factory::tile_factory::TileFactory;
-use crate::model::tile::Tile;
+use crate::model::tile::{Tile, TileId};
 use crate::model::tile_feature::Edge::{East, North, South, West};
+use crate::model::tile_feature::SegmentSet;
+use crate::model::tile_feature::Side::{Left, Right};
+use crate::model::tile_meta::ExpansionId::Base;
 
 /// A trait for constructing predefined road tile variants.
 ///
@@ -20,25 +23,64 @@ pub trait RoadTileBuilder {
 
 impl RoadTileBuilder for TileFactory {
     fn build_u_road() -> Tile {
-        TileBuilder::new().add_road(vec![North, South]).build()
+        TileBuilder::new(TileId('U'))
+            .print_run(Base, 8)
+            .add_road(vec![North, South])
+            .add_field(
+                SegmentSet::from([West])
+                    .with(North.segment(Left))
+                    .with(South.segment(Right)),
+            )
+            .add_field(
+                SegmentSet::from([East])
+                    .with(North.segment(Right))
+                    .with(South.segment(Left)),
+            )
+            .try_build()
+            .expect("invalid tile definition")
     }
     fn build_v_road() -> Tile {
-        TileBuilder::new().add_road(vec![North, West]).build()
+        TileBuilder::new(TileId('V'))
+            .print_run(Base, 9)
+            .add_road(vec![North, West])
+            .add_field([North.segment(Left), West.segment(Right)])
+            .add_field(
+                SegmentSet::from([East, South])
+                    .with(North.segment(Right))
+                    .with(West.segment(Left)),
+            )
+            .try_build()
+            .expect("invalid tile definition")
     }
     fn build_w_road() -> Tile {
-        TileBuilder::new()
+        TileBuilder::new(TileId('W'))
+            .print_run(Base, 4)
             .add_road(vec![North])
             .add_road(vec![West])
             .add_road(vec![South])
-            .build()
+            .add_field([North.segment(Left), West.segment(Right)])
+            .add_field([West.segment(Left), South.segment(Right)])
+            .add_field(
+                SegmentSet::from([East])
+                    .with(North.segment(Right))
+                    .with(South.segment(Left)),
+            )
+            .try_build()
+            .expect("invalid tile definition")
     }
     fn build_x_road() -> Tile {
-        TileBuilder::new()
+        TileBuilder::new(TileId('X'))
+            .print_run(Base, 1)
             .add_road(vec![North])
             .add_road(vec![West])
             .add_road(vec![South])
             .add_road(vec![East])
-            .build()
+            .add_field([North.segment(Right), East.segment(Left)])
+            .add_field([East.segment(Right), South.segment(Left)])
+            .add_field([South.segment(Right), West.segment(Left)])
+            .add_field([West.segment(Right), North.segment(Left)])
+            .try_build()
+            .expect("invalid tile definition")
     }
 }
 
@@ -46,30 +88,54 @@ impl RoadTileBuilder for TileFactory {
 mod tests {
     use super::*;
     use crate::model::tile_feature::Edge::{East, North, South, West};
-    use crate::model::tile_feature::{Edge, Road};
+    use crate::model::tile_feature::{Edge, EdgeSet, Road};
     use std::any::TypeId;
 
     fn assert_road_edges(tile: &Tile, expected_edges: Vec<Vec<Edge>>) {
-        assert_eq!(tile.tile_features.len(), expected_edges.len());
-        for (feature, expected) in tile.tile_features.iter().zip(expected_edges) {
+        let roads: Vec<_> = tile
+            .tile_features
+            .iter()
+            .filter(|feature| !feature.is_field())
+            .collect();
+        assert_eq!(roads.len(), expected_edges.len());
+        for (feature, expected) in roads.into_iter().zip(expected_edges) {
             assert_eq!(
                 feature.feature_type.as_ref().type_id(),
                 TypeId::of::<Road>()
             );
-            assert_eq!(feature.edges, expected);
+            assert_eq!(feature.edges, EdgeSet::from(expected));
         }
     }
 
+    fn assert_field_edges(tile: &Tile, expected_edges: Vec<Vec<Edge>>) {
+        let fields: Vec<EdgeSet> = tile
+            .tile_features
+            .iter()
+            .filter(|feature| feature.is_field())
+            .map(|feature| feature.edges)
+            .collect();
+        let expected: Vec<EdgeSet> = expected_edges.into_iter().map(EdgeSet::from).collect();
+        assert_eq!(fields, expected);
+    }
+
     #[test]
     fn test_build_u_road() {
         let tile = TileFactory::build_u_road();
         assert_road_edges(&tile, vec![vec![North, South]]);
+        assert_field_edges(
+            &tile,
+            vec![vec![North, West, South], vec![North, East, South]],
+        );
     }
 
     #[test]
     fn test_build_v_road() {
         let tile = TileFactory::build_v_road();
         assert_road_edges(&tile, vec![vec![North, West]]);
+        assert_field_edges(
+            &tile,
+            vec![vec![North, West], vec![North, East, South, West]],
+        );
     }
 
     #[test]
@@ -1,7 +1,10 @@
 use crate::builder::tile_builder::TileBuilder;
 use crate::factory::tile_factory::TileFactory;
-use crate::model::tile::Tile;
+use crate::model::tile::{Tile, TileId};
 use crate::model::tile_feature::Edge::{East, North, South, West};
+use crate::model::tile_feature::SegmentSet;
+use crate::model::tile_feature::Side::{Left, Right};
+use crate::model::tile_meta::ExpansionId::Base;
 
 /// A trait for constructing predefined town tile variants.
 ///
@@ -49,112 +52,196 @@ pub trait TownTileBuilder {
 
 impl TownTileBuilder for TileFactory {
     fn build_c_town() -> Tile {
-        TileBuilder::new()
+        TileBuilder::new(TileId('C'))
+            .print_run(Base, 1)
             .add_shielded_town(vec![North, West, South, East])
-            .build()
+            .try_build()
+            .expect("invalid tile definition")
     }
     fn build_d_town() -> Tile {
-        TileBuilder::new()
+        TileBuilder::new(TileId('D'))
+            .print_run(Base, 4)
             .add_town(vec![North])
             .add_road(vec![West, East])
-            .build()
+            .add_field([West.segment(Right), East.segment(Left)])
+            .add_field(
+                SegmentSet::from([South])
+                    .with(East.segment(Right))
+                    .with(West.segment(Left)),
+            )
+            .try_build()
+            .expect("invalid tile definition")
     }
     fn build_e_town() -> Tile {
-        TileBuilder::new().add_town(vec![North]).build()
+        TileBuilder::new(TileId('E'))
+            .print_run(Base, 5)
+            .add_town(vec![North])
+            .add_field(vec![East, South, West])
+            .try_build()
+            .expect("invalid tile definition")
     }
     fn build_f_town() -> Tile {
-        TileBuilder::new()
+        TileBuilder::new(TileId('F'))
+            .print_run(Base, 2)
             .add_shielded_town(vec![West, East])
-            .build()
+            .add_field(vec![North])
+            .add_field(vec![South])
+            .try_build()
+            .expect("invalid tile definition")
     }
 
     fn build_g_town() -> Tile {
-        TileBuilder::new().add_town(vec![West, East]).build()
+        TileBuilder::new(TileId('G'))
+            .print_run(Base, 1)
+            .add_town(vec![West, East])
+            .add_field(vec![North])
+            .add_field(vec![South])
+            .try_build()
+            .expect("invalid tile definition")
     }
 
     fn build_h_town() -> Tile {
-        TileBuilder::new()
+        TileBuilder::new(TileId('H'))
+            .print_run(Base, 3)
             .add_town(vec![West])
             .add_town(vec![East])
-            .build()
+            .add_field(vec![North, South])
+            .try_build()
+            .expect("invalid tile definition")
     }
 
     fn build_i_town() -> Tile {
-        TileBuilder::new()
+        TileBuilder::new(TileId('I'))
+            .print_run(Base, 2)
             .add_town(vec![North])
             .add_town(vec![West])
-            .build()
+            .add_field(vec![East, South])
+            .try_build()
+            .expect("invalid tile definition")
     }
 
     fn build_j_town() -> Tile {
-        TileBuilder::new()
+        TileBuilder::new(TileId('J'))
+            .print_run(Base, 3)
             .add_town(vec![North])
             .add_road(vec![South, East])
-            .build()
+            .add_field([East.segment(Right), South.segment(Left)])
+            .add_field(
+                SegmentSet::from([West])
+                    .with(East.segment(Left))
+                    .with(South.segment(Right)),
+            )
+            .try_build()
+            .expect("invalid tile definition")
     }
 
     fn build_k_town() -> Tile {
-        TileBuilder::new()
+        TileBuilder::new(TileId('K'))
+            .print_run(Base, 3)
             .add_town(vec![North])
-            .add_road(vec![West, East])
-            .build()
+            .add_road(vec![South, West])
+            .add_field([South.segment(Right), West.segment(Left)])
+            .add_field(
+                SegmentSet::from([East])
+                    .with(South.segment(Left))
+                    .with(West.segment(Right)),
+            )
+            .try_build()
+            .expect("invalid tile definition")
     }
 
     fn build_l_town() -> Tile {
-        TileBuilder::new()
+        TileBuilder::new(TileId('L'))
+            .print_run(Base, 3)
             .add_town(vec![North])
             .add_road(vec![West])
             .add_road(vec![South])
             .add_road(vec![East])
-            .build()
+            .add_field([West.segment(Right), East.segment(Left)])
+            .add_field([South.segment(Right), West.segment(Left)])
+            .add_field([East.segment(Right), South.segment(Left)])
+            .try_build()
+            .expect("invalid tile definition")
     }
 
     fn build_m_town() -> Tile {
-        TileBuilder::new()
+        TileBuilder::new(TileId('M'))
+            .print_run(Base, 2)
             .add_shielded_town(vec![North, West])
-            .build()
+            .add_field(vec![East, South])
+            .try_build()
+            .expect("invalid tile definition")
     }
 
     fn build_n_town() -> Tile {
-        TileBuilder::new().add_town(vec![North, West]).build()
+        TileBuilder::new(TileId('N'))
+            .print_run(Base, 3)
+            .add_town(vec![North, West])
+            .add_field(vec![East, South])
+            .try_build()
+            .expect("invalid tile definition")
     }
 
     fn build_o_town() -> Tile {
-        TileBuilder::new()
+        TileBuilder::new(TileId('O'))
+            .print_run(Base, 2)
             .add_shielded_town(vec![North, West])
             .add_road(vec![South, East])
-            .build()
+            .add_field([East.segment(Right), South.segment(Left)])
+            .add_field([East.segment(Left), South.segment(Right)])
+            .try_build()
+            .expect("invalid tile definition")
     }
 
     fn build_p_town() -> Tile {
-        TileBuilder::new()
+        TileBuilder::new(TileId('P'))
+            .print_run(Base, 3)
             .add_town(vec![North, West])
             .add_road(vec![South, East])
-            .build()
+            .add_field([East.segment(Right), South.segment(Left)])
+            .add_field([East.segment(Left), South.segment(Right)])
+            .try_build()
+            .expect("invalid tile definition")
     }
 
     fn build_q_town() -> Tile {
-        TileBuilder::new()
+        TileBuilder::new(TileId('Q'))
+            .print_run(Base, 1)
             .add_shielded_town(vec![North, West, East])
-            .build()
+            .add_field(vec![South])
+            .try_build()
+            .expect("invalid tile definition")
     }
 
     fn build_r_town() -> Tile {
-        TileBuilder::new().add_town(vec![North, West, East]).build()
+        TileBuilder::new(TileId('R'))
+            .print_run(Base, 3)
+            .add_town(vec![North, West, East])
+            .add_field(vec![South])
+            .try_build()
+            .expect("invalid tile definition")
     }
 
     fn build_s_town() -> Tile {
-        TileBuilder::new()
+        TileBuilder::new(TileId('S'))
+            .print_run(Base, 2)
             .add_shielded_town(vec![North, West, East])
             .add_road(vec![South])
-            .build()
+            .add_field([South.segment(Left)])
+            .add_field([South.segment(Right)])
+            .try_build()
+            .expect("invalid tile definition")
     }
 
     fn build_t_town() -> Tile {
-        TileBuilder::new()
+        TileBuilder::new(TileId('T'))
+            .print_run(Base, 1)
             .add_town(vec![North, West, East])
             .add_road(vec![South])
-            .build()
+            .add_field([South.segment(Left)])
+            .add_field([South.segment(Right)])
+            .try_build()
+            .expect("invalid tile definition")
     }
 }
 
@@ -164,7 +251,7 @@ mod tests {
     use crate::model::tile_feature::{
         Edge,
         Edge::{East, North, South, West},
-        Road, Shield, Town,
+        EdgeSet, Road, TileFeatureEnhancement, Town,
     };
     use std::any::TypeId;
 
@@ -183,10 +270,10 @@ mod tests {
 
                 assert_eq!(town_features.len(), $towns.len(), "Wrong number of Town features for {}", stringify!($name));
                 for (feature, (edges, has_shield)) in town_features.iter().zip($towns.iter()) {
-                    assert_eq!(&feature.edges, edges);
-                    match (feature.enhancement.as_ref(), has_shield) {
+                    assert_eq!(feature.edges, EdgeSet::from(*edges));
+                    match (feature.enhancement, has_shield) {
                         (Some(enhancement), true) => {
-                            assert_eq!(enhancement.as_ref().type_id(), TypeId::of::<Shield>())
+                            assert_eq!(enhancement, TileFeatureEnhancement::Shield)
                         }
                         (None, false) => (),
                         (Some(_), false) => panic!("Unexpected shield"),
@@ -196,7 +283,7 @@ mod tests {
 
                 assert_eq!(road_features.len(), $roads.len(), "Wrong number of Road features for {}", stringify!($name));
                 for (feature, edges) in road_features.iter().zip($roads.iter()) {
-                    assert_eq!(&feature.edges, edges);
+                    assert_eq!(feature.edges, EdgeSet::from(*edges));
                 }
             }
         )*
@@ -218,7 +305,7 @@ mod tests {
             (&[West][..], false)
         ], Vec::<&[Edge]>::new();
         j_town => TileFactory::build_j_town(), vec![(&[North][..], false)], vec![&[South, East][..]];
-        k_town => TileFactory::build_k_town(), vec![(&[North][..], false)], vec![&[West, East][..]];
+        k_town => TileFactory::build_k_town(), vec![(&[North][..], false)], vec![&[South, West][..]];
         l_town => TileFactory::build_l_town(), vec![(&[North][..], false)], vec![&[West][..], &[South][..], &[East][..]];
         m_town => TileFactory::build_m_town(), vec![(&[North, West][..], true)], Vec::<&[Edge]>::new();
         n_town => TileFactory::build_n_town(), vec![(&[North, West][..], false)], Vec::<&[Edge]>::new();
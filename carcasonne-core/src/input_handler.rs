@@ -3,6 +3,7 @@ use crate::action::Action;
 /// Represents a user input event, typically from a keyboard or controller.
 ///
 /// These events are used to drive the interaction logic of the application.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum InputEvent {
     /// Move focus or selection up.
     Up,
@@ -1,3 +1,5 @@
+pub mod border_style;
 pub mod node;
 pub mod point;
+pub mod score_board_view;
 pub mod size;
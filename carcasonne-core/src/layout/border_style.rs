@@ -0,0 +1,26 @@
+/// The line style used to draw a border around a framed node.
+///
+/// Styles let renderers visually distinguish panels, e.g. a focused panel
+/// drawn with a double line next to unfocused single-line panels.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum BorderStyle {
+    /// Thin single line with square corners.
+    #[default]
+    Single,
+    /// Double line.
+    Double,
+    /// Thin single line with rounded corners.
+    Rounded,
+    /// Thick single line.
+    Heavy,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_border_style_is_single() {
+        assert_eq!(BorderStyle::default(), BorderStyle::Single);
+    }
+}
@@ -1,3 +1,5 @@
+use crate::layout::border_style::BorderStyle;
+use crate::model::board::Board;
 use crate::model::tile::Tile;
 
 /// A node in the layout tree used for rendering.
@@ -13,10 +15,14 @@ pub enum Node<'a> {
     Text(&'a str),
     /// A tile to render
     Tile(&'a Tile),
+    /// The placed tiles of a board, in a grid following their positions.
+    ///
+    /// The grid spans the bounds of the board; empty positions are left blank.
+    Board(&'a Board),
     /// A vertical container that stacks child nodes top-to-bottom.
     VerticalContainer(Vec<Box<Node<'a>>>),
     /// A horizontal container that lays out child nodes left-to-right.
     HorizontalContainer(Vec<Box<Node<'a>>>),
-    /// A framed-drawn border around a single child node.
-    Framed(Box<Node<'a>>),
+    /// A framed-drawn border around a single child node, drawn with the given style.
+    Framed(Box<Node<'a>>, BorderStyle),
 }
@@ -0,0 +1,120 @@
+use crate::layout::node::Node;
+use crate::model::player::Player;
+use crate::scoring::score_board::ScoreBoard;
+
+/// The marker put in front of the player in the lead.
+const LEADER_MARKER: char = '*';
+
+/// The scores of the players, laid out as one row per player with their name, points
+/// and meeples left, the leader marked with a `*`.
+///
+/// The rows are written once, when the view is built, so the node borrows them.
+///
+/// # Examples
+///
+/// ```
+/// use carcasonne_core::layout::score_board_view::ScoreBoardView;
+/// use carcasonne_core::model::player::Player;
+/// use carcasonne_core::scoring::score_board::ScoreBoard;
+/// use carcasonne_core::scoring::ScoreCategory;
+///
+/// let mut score_board = ScoreBoard::new(2);
+/// score_board.add_points(1, 12, ScoreCategory::Town);
+///
+/// let view = ScoreBoardView::new(&score_board, &Player::local_players(2), |_| 7);
+/// assert_eq!(view.rows(), ["  Player 1  0 pts 7 meeples", "* Player 2 12 pts 7 meeples"]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ScoreBoardView {
+    rows: Vec<String>,
+}
+
+impl ScoreBoardView {
+    /// Lays out the scores of `players` kept by `score_board`, with `meeples_left`
+    /// telling how many meeples each player has left.
+    pub fn new(
+        score_board: &ScoreBoard,
+        players: &[Player],
+        meeples_left: impl Fn(usize) -> usize,
+    ) -> Self {
+        let leader = score_board.leader();
+        let name_width = players
+            .iter()
+            .map(|player| player.name.chars().count())
+            .max()
+            .unwrap_or(0);
+        let points_width = (0..players.len())
+            .map(|player| score_board.score_of(player).to_string().len())
+            .max()
+            .unwrap_or(0);
+        let rows = players
+            .iter()
+            .enumerate()
+            .map(|(index, player)| {
+                let marker = if leader == Some(index) {
+                    LEADER_MARKER
+                } else {
+                    ' '
+                };
+                format!(
+                    "{marker} {:<name_width$} {:>points_width$} pts {} meeples",
+                    player.name,
+                    score_board.score_of(index),
+                    meeples_left(index),
+                )
+            })
+            .collect();
+        Self { rows }
+    }
+
+    /// Returns the row of each player, in turn order.
+    pub fn rows(&self) -> &[String] {
+        &self.rows
+    }
+
+    /// Builds the node stacking the rows.
+    pub fn node(&self) -> Node<'_> {
+        Node::VerticalContainer(
+            self.rows
+                .iter()
+                .map(|row| Box::new(Node::Text(row)))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scoring::ScoreCategory;
+
+    #[test]
+    fn test_no_leader_while_tied() {
+        let mut score_board = ScoreBoard::new(2);
+        score_board.add_points(0, 3, ScoreCategory::Road);
+        score_board.add_points(1, 3, ScoreCategory::Road);
+        let players = vec![Player::new("Ann"), Player::new("Bartholomew")];
+
+        let view = ScoreBoardView::new(&score_board, &players, |player| player);
+        assert_eq!(
+            view.rows(),
+            [
+                "  Ann         3 pts 0 meeples",
+                "  Bartholomew 3 pts 1 meeples"
+            ]
+        );
+        assert!(matches!(view.node(), Node::VerticalContainer(rows) if rows.len() == 2));
+    }
+
+    #[test]
+    fn test_names_are_aligned_by_characters() {
+        let score_board = ScoreBoard::new(2);
+        let players = vec![Player::new("Zoë"), Player::new("Bob")];
+
+        let view = ScoreBoardView::new(&score_board, &players, |_| 7);
+        assert_eq!(
+            view.rows(),
+            ["  Zoë 0 pts 7 meeples", "  Bob 0 pts 7 meeples"]
+        );
+    }
+}
@@ -1,9 +1,15 @@
+#[cfg(feature = "ui")]
 pub mod action;
-mod builder;
+pub mod builder;
 pub mod context;
 pub mod factory;
+#[cfg(feature = "ui")]
 pub mod input_handler;
+#[cfg(feature = "ui")]
 pub mod layout;
 pub mod model;
+#[cfg(feature = "ui")]
 pub mod renderer;
+pub mod scoring;
+#[cfg(feature = "ui")]
 pub mod state;
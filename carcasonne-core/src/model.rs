@@ -1,4 +1,9 @@
+pub mod board;
 pub mod game;
+pub mod meeple;
+pub mod player;
+pub mod rules;
 pub mod tile;
 pub mod tile_extension;
 pub mod tile_feature;
+pub mod tile_meta;
@@ -0,0 +1,2160 @@
+pub mod feature_graph;
+mod zobrist;
+
+use crate::model::board::feature_graph::{FeatureGraph, FeatureRef};
+use crate::model::meeple::{Meeple, SlotId};
+use crate::model::tile::{Rotation, Tile};
+use crate::model::tile_extension::TileExtension;
+use crate::model::tile_feature::{Edge, EdgeSegment, FeatureKind, TileFeatureEnhancement};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+use std::sync::Arc;
+
+/// The position of a tile on the board, relative to the start tile.
+///
+/// The start tile lies at the origin. `x` grows towards the east and `y` towards
+/// the south, and both may be negative, as the board grows in every direction.
+///
+/// Coordinates also serve as offsets between two positions: they can be added,
+/// subtracted, and moved by a `Direction`.
+///
+/// # Examples
+///
+/// ```
+/// use carcasonne_core::model::board::{Coord, Direction};
+///
+/// let position = Coord::new(-1, 2) + Direction::North;
+/// assert_eq!(position, Coord::new(-1, 1));
+/// assert_eq!(position - Coord::new(1, 1), Coord::new(-2, 0));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Coord {
+    /// The column of the position, from the start tile towards the east.
+    pub x: i32,
+    /// The row of the position, from the start tile towards the south.
+    pub y: i32,
+}
+
+impl Coord {
+    /// The position of the start tile.
+    pub const ORIGIN: Coord = Coord { x: 0, y: 0 };
+
+    /// Creates a new position.
+    pub const fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+
+    /// Returns the position next to this one in the given direction.
+    pub fn neighbor(self, direction: Direction) -> Coord {
+        self + direction
+    }
+
+    /// Returns the eight positions around this one, diagonals included, clockwise
+    /// from the north-west.
+    pub fn surrounding(self) -> [Coord; 8] {
+        [
+            (-1, -1),
+            (0, -1),
+            (1, -1),
+            (1, 0),
+            (1, 1),
+            (0, 1),
+            (-1, 1),
+            (-1, 0),
+        ]
+        .map(|(dx, dy)| self + Coord::new(dx, dy))
+    }
+}
+
+impl Add for Coord {
+    type Output = Coord;
+
+    fn add(self, other: Coord) -> Coord {
+        Coord::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Coord {
+    type Output = Coord;
+
+    fn sub(self, other: Coord) -> Coord {
+        Coord::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Neg for Coord {
+    type Output = Coord;
+
+    fn neg(self) -> Coord {
+        Coord::new(-self.x, -self.y)
+    }
+}
+
+impl AddAssign for Coord {
+    fn add_assign(&mut self, other: Coord) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Coord {
+    fn sub_assign(&mut self, other: Coord) {
+        *self = *self - other;
+    }
+}
+
+impl Add<Direction> for Coord {
+    type Output = Coord;
+
+    /// Moves one step in the given direction.
+    fn add(self, direction: Direction) -> Coord {
+        self + direction.offset()
+    }
+}
+
+impl AddAssign<Direction> for Coord {
+    fn add_assign(&mut self, direction: Direction) {
+        *self = *self + direction;
+    }
+}
+
+/// One of the four directions from a board position to its neighbors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// Towards negative `y`.
+    North,
+    /// Towards positive `x`.
+    East,
+    /// Towards positive `y`.
+    South,
+    /// Towards negative `x`.
+    West,
+}
+
+impl Direction {
+    /// All directions, in clockwise order starting from the north.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// Returns the offset of a step in this direction.
+    pub const fn offset(self) -> Coord {
+        match self {
+            Direction::North => Coord::new(0, -1),
+            Direction::East => Coord::new(1, 0),
+            Direction::South => Coord::new(0, 1),
+            Direction::West => Coord::new(-1, 0),
+        }
+    }
+
+    /// Returns the direction pointing the other way.
+    pub fn opposite(self) -> Direction {
+        match self {
+            Direction::North => Direction::South,
+            Direction::East => Direction::West,
+            Direction::South => Direction::North,
+            Direction::West => Direction::East,
+        }
+    }
+
+    /// Returns the edge of a tile facing this direction, as the tile lies on the board.
+    pub fn edge(self) -> Edge {
+        match self {
+            Direction::North => Edge::North,
+            Direction::East => Edge::East,
+            Direction::South => Edge::South,
+            Direction::West => Edge::West,
+        }
+    }
+}
+
+impl From<Edge> for Direction {
+    fn from(edge: Edge) -> Self {
+        match edge {
+            Edge::North => Direction::North,
+            Edge::East => Direction::East,
+            Edge::South => Direction::South,
+            Edge::West => Direction::West,
+        }
+    }
+}
+
+/// The smallest rectangle of positions holding every placed tile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bounds {
+    /// The north-west corner of the rectangle.
+    pub min: Coord,
+    /// The south-east corner of the rectangle, included.
+    pub max: Coord,
+}
+
+impl Bounds {
+    /// Returns the number of columns of the rectangle.
+    pub fn width(&self) -> usize {
+        (self.max.x - self.min.x) as usize + 1
+    }
+
+    /// Returns the number of rows of the rectangle.
+    pub fn height(&self) -> usize {
+        (self.max.y - self.min.y) as usize + 1
+    }
+
+    /// Returns `true` if the position lies within the rectangle.
+    pub fn contains(&self, position: Coord) -> bool {
+        (self.min.x..=self.max.x).contains(&position.x)
+            && (self.min.y..=self.max.y).contains(&position.y)
+    }
+
+    /// Returns the `(column, row)` of a position within the rectangle, counted from
+    /// its north-west corner, or `None` if the position lies outside.
+    ///
+    /// This turns board positions, which may be negative, into indices of a grid
+    /// holding the rectangle, such as the cells of a rendered board.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use carcasonne_core::model::board::{Bounds, Coord};
+    ///
+    /// let bounds = Bounds {
+    ///     min: Coord::new(-2, -1),
+    ///     max: Coord::new(1, 1),
+    /// };
+    /// assert_eq!(bounds.cell_of(Coord::ORIGIN), Some((2, 1)));
+    /// assert_eq!(bounds.cell_of(Coord::new(-3, 0)), None);
+    /// ```
+    pub fn cell_of(&self, position: Coord) -> Option<(usize, usize)> {
+        if !self.contains(position) {
+            return None;
+        }
+        let offset = position - self.min;
+        Some((offset.x as usize, offset.y as usize))
+    }
+}
+
+/// The reasons a tile cannot be placed on the board.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlacementError {
+    /// A tile is already placed at the position.
+    Occupied,
+    /// The position does not touch any placed tile.
+    NotAdjacent,
+    /// An edge of the tile does not match the neighbor it touches.
+    EdgeMismatch {
+        /// The direction of the neighbor, which is also the mismatching edge of the tile.
+        direction: Direction,
+        /// The kind of terrain the neighbor requires, at the first mismatching segment.
+        expected: FeatureKind,
+        /// The kind of terrain the tile has there.
+        found: FeatureKind,
+    },
+}
+
+impl Display for PlacementError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PlacementError::Occupied => write!(f, "a tile is already placed there"),
+            PlacementError::NotAdjacent => write!(f, "the tile must touch a placed tile"),
+            PlacementError::EdgeMismatch {
+                direction,
+                expected,
+                found,
+            } => {
+                write!(
+                    f,
+                    "the {direction:?} edge has a {found:?} where its neighbor needs a {expected:?}"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for PlacementError {}
+
+/// A tile laid on the board, along with how and where it was placed.
+///
+/// The tile is kept in its canonical orientation; `rotation` tells how it was turned
+/// when placed, so the original definition is never lost.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlacedTile {
+    /// The tile, in its canonical orientation.
+    pub tile: Tile,
+    /// The clockwise rotation applied to the tile when it was placed.
+    pub rotation: Rotation,
+    /// The position of the board the tile was placed on.
+    pub position: Coord,
+    /// The meeple standing on one of the tile features, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub meeple: Option<Meeple>,
+}
+
+impl PlacedTile {
+    /// Creates a placed tile without any meeple.
+    pub fn new(tile: Tile, rotation: Rotation, position: Coord) -> Self {
+        Self {
+            tile,
+            rotation,
+            position,
+            meeple: None,
+        }
+    }
+
+    /// Returns a copy of the tile as it lies on the board, with the rotation applied.
+    pub fn oriented_tile(&self) -> Tile {
+        let mut tile = self.tile.clone();
+        tile.rotate(self.rotation);
+        tile
+    }
+
+    /// Returns the kind of terrain at an edge segment of the tile, as it lies on the board.
+    ///
+    /// Unlike going through `oriented_tile`, this does not copy the tile.
+    pub fn kind_at(&self, segment: EdgeSegment) -> FeatureKind {
+        self.tile.kind_at(segment.rotated(self.rotation.inverse()))
+    }
+
+    /// Returns the index of the feature covering an edge segment of the tile, as it
+    /// lies on the board, if any.
+    pub fn feature_index_covering(&self, segment: EdgeSegment) -> Option<usize> {
+        self.tile
+            .feature_index_covering(segment.rotated(self.rotation.inverse()))
+    }
+}
+
+/// A town, road or field spread over one or more tiles, as found by
+/// `Board::completed_features_after`, `Board::unfinished_features` and `Board::fields`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeatureSummary {
+    /// Whether the feature is a town, a road or a field.
+    pub kind: FeatureKind,
+    /// The tile features forming the feature, in placement order.
+    pub members: Vec<FeatureRef>,
+    /// The number of tiles the feature spreads over.
+    pub tile_count: usize,
+    /// The number of shields in the feature.
+    pub shields: usize,
+    /// The meeples standing on the feature.
+    pub meeples: Vec<Meeple>,
+}
+
+/// An abbey, or another tile extension, surrounded by eight tiles, as found by
+/// `Board::completed_abbeys_after`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompletedAbbey {
+    /// The position of the tile holding the extension.
+    pub position: Coord,
+    /// The extension of the tile.
+    pub extension: TileExtension,
+    /// The meeple standing on the extension, if any.
+    pub meeple: Option<Meeple>,
+}
+
+/// The tiles laid during a game, indexed by their position.
+///
+/// Only placed tiles are stored, so the board can grow in any direction from the
+/// start tile without reallocating or shifting the existing tiles.
+///
+/// The board also keeps its frontier, the empty positions next to a placed tile,
+/// and the `FeatureGraph` of the towns, roads and fields spread over several tiles
+/// up to date as tiles are placed. It remembers the order of the placements so
+/// they can be undone.
+///
+/// Every change bumps the version of the board, so a renderer can ask which positions
+/// changed since the last frame it drew.
+///
+/// Placed tiles are shared between a board and its clones, and only copied when one
+/// of them changes a tile, so `snapshot` is cheap. Snapshots may be handed to other
+/// threads, for instance to search moves in parallel.
+///
+/// A board is serialized as the list of its placed tiles, in placement order; the
+/// frontier and the feature graph are rebuilt when it is loaded.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(from = "BoardData", into = "BoardData")]
+pub struct Board {
+    tiles: HashMap<Coord, Arc<PlacedTile>>,
+    open_positions: HashSet<Coord>,
+    placement_order: Vec<Coord>,
+    features: FeatureGraph,
+    hash: u64,
+    version: u64,
+    changes: Vec<(u64, Coord)>,
+}
+
+/// The serialized form of a `Board`.
+#[derive(Serialize, Deserialize)]
+struct BoardData {
+    tiles: Vec<PlacedTile>,
+}
+
+impl From<Board> for BoardData {
+    fn from(mut board: Board) -> Self {
+        let tiles = board
+            .placement_order
+            .iter()
+            .filter_map(|position| board.tiles.remove(position))
+            .map(Arc::unwrap_or_clone)
+            .collect();
+        Self { tiles }
+    }
+}
+
+impl From<BoardData> for Board {
+    fn from(data: BoardData) -> Self {
+        let mut board = Board::new();
+        for placed_tile in data.tiles {
+            board.place(placed_tile);
+        }
+        board
+    }
+}
+
+impl Board {
+    /// Creates an empty board.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a board holding the start tile at the origin, in its canonical orientation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use carcasonne_core::factory::game_factory::GameTilesFactory;
+    /// use carcasonne_core::model::board::{Board, Coord};
+    ///
+    /// let tiles = GameTilesFactory::build_base_game();
+    /// let board = Board::with_start_tile(tiles.start_tile.unwrap());
+    /// assert_eq!(board.get(Coord::ORIGIN).unwrap().tile.id.0, 'D');
+    /// assert_eq!(board.open_positions().len(), 4);
+    /// ```
+    pub fn with_start_tile(start_tile: Tile) -> Self {
+        let mut board = Self::new();
+        board.place(PlacedTile::new(start_tile, Rotation::Deg0, Coord::ORIGIN));
+        board
+    }
+
+    /// Returns a copy of the board sharing its placed tiles with this one.
+    ///
+    /// Taking a snapshot does not copy any tile, so hypothetical placements can be
+    /// tried on it, and thrown away, at little cost. Tiles changed on either board
+    /// afterwards, such as by putting a meeple on them, are copied on the fly and
+    /// never affect the other board.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use carcasonne_core::builder::tile_builder::TileBuilder;
+    /// use carcasonne_core::model::board::{Board, Coord};
+    /// use carcasonne_core::model::tile::{Rotation, TileId};
+    ///
+    /// let tile = TileBuilder::new(TileId('B')).add_abbey().build();
+    /// let mut board = Board::new();
+    /// board.place_tile(Coord::ORIGIN, tile.clone(), Rotation::Deg0).unwrap();
+    ///
+    /// let mut branch = board.snapshot();
+    /// branch.place_tile(Coord::new(1, 0), tile, Rotation::Deg0).unwrap();
+    /// assert_eq!(branch.len(), 2);
+    /// assert_eq!(board.len(), 1);
+    /// ```
+    pub fn snapshot(&self) -> Board {
+        self.clone()
+    }
+
+    /// Returns the tile placed at `position`, if any.
+    pub fn get(&self, position: Coord) -> Option<&PlacedTile> {
+        self.tiles.get(&position).map(Arc::as_ref)
+    }
+
+    /// Puts a meeple on the tile placed at `position`, or takes it off with `None`,
+    /// and returns the meeple that stood there before.
+    ///
+    /// Nothing happens if no tile is placed at `position`. The tile is copied first
+    /// if it is shared with a snapshot.
+    pub fn set_meeple(&mut self, position: Coord, meeple: Option<Meeple>) -> Option<Meeple> {
+        let placed_tile = Arc::make_mut(self.tiles.get_mut(&position)?);
+        let previous = std::mem::replace(&mut placed_tile.meeple, meeple);
+        for meeple in previous.iter().chain(&meeple) {
+            self.hash ^= zobrist::meeple_key(position, *meeple);
+        }
+        self.record_change(position);
+        previous
+    }
+
+    /// Returns the version of the board, which grows with every change made to it.
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// Returns the positions changed after `version`, row by row from the north-west.
+    ///
+    /// Positions where a tile was placed or removed, or where a meeple was put or
+    /// taken back, are listed once each, even if they changed several times.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use carcasonne_core::builder::tile_builder::TileBuilder;
+    /// use carcasonne_core::model::board::{Board, Coord};
+    /// use carcasonne_core::model::tile::{Rotation, TileId};
+    ///
+    /// let tile = TileBuilder::new(TileId('B')).add_abbey().build();
+    /// let mut board = Board::new();
+    /// board.place_tile(Coord::ORIGIN, tile.clone(), Rotation::Deg0).unwrap();
+    /// let drawn = board.version();
+    ///
+    /// board.place_tile(Coord::new(1, 0), tile, Rotation::Deg0).unwrap();
+    /// assert_eq!(board.changes_since(drawn), vec![Coord::new(1, 0)]);
+    /// assert!(board.changes_since(board.version()).is_empty());
+    /// ```
+    pub fn changes_since(&self, version: u64) -> Vec<Coord> {
+        let start = self
+            .changes
+            .partition_point(|(changed_in, _)| *changed_in <= version);
+        let mut positions: Vec<Coord> = self.changes[start..]
+            .iter()
+            .map(|(_, position)| *position)
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        positions.sort_by_key(|position| (position.y, position.x));
+        positions
+    }
+
+    /// Bumps the version of the board, recording a change at `position`.
+    fn record_change(&mut self, position: Coord) {
+        self.version += 1;
+        self.changes.push((self.version, position));
+    }
+
+    /// Returns the hash of the position: the placed tiles, their rotation and their meeples.
+    ///
+    /// The hash is kept up to date as tiles and meeples are placed or removed, and
+    /// does not depend on the order of the placements: two boards holding the same
+    /// tiles and meeples have the same hash. Boards with different contents almost
+    /// always hash differently, but may collide.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use carcasonne_core::builder::tile_builder::TileBuilder;
+    /// use carcasonne_core::model::board::{Board, Coord};
+    /// use carcasonne_core::model::tile::{Rotation, TileId};
+    ///
+    /// let tile = TileBuilder::new(TileId('B')).add_abbey().build();
+    /// let mut board = Board::new();
+    /// board.place_tile(Coord::ORIGIN, tile.clone(), Rotation::Deg0).unwrap();
+    /// let before = board.hash();
+    ///
+    /// board.place_tile(Coord::new(1, 0), tile, Rotation::Deg0).unwrap();
+    /// assert_ne!(board.hash(), before);
+    /// board.remove_last();
+    /// assert_eq!(board.hash(), before);
+    /// ```
+    pub fn hash(&self) -> u64 {
+        self.hash
+    }
+
+    /// Returns `true` if a tile is placed at `position`.
+    pub fn contains(&self, position: Coord) -> bool {
+        self.tiles.contains_key(&position)
+    }
+
+    /// Returns the number of placed tiles.
+    pub fn len(&self) -> usize {
+        self.tiles.len()
+    }
+
+    /// Returns `true` if no tile has been placed yet.
+    pub fn is_empty(&self) -> bool {
+        self.tiles.is_empty()
+    }
+
+    /// Iterates over the placed tiles and their positions, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &PlacedTile)> + '_ {
+        self.tiles
+            .iter()
+            .map(|(position, tile)| (*position, tile.as_ref()))
+    }
+
+    /// Returns the smallest rectangle holding every placed tile, or `None` if the board is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use carcasonne_core::builder::tile_builder::TileBuilder;
+    /// use carcasonne_core::model::board::{Board, Coord, PlacedTile};
+    /// use carcasonne_core::model::tile::{Rotation, TileId};
+    ///
+    /// let tile = TileBuilder::new(TileId('B')).add_abbey().build();
+    /// let mut board = Board::new();
+    /// assert!(board.bounds().is_none());
+    ///
+    /// board.place(PlacedTile::new(tile.clone(), Rotation::Deg0, Coord::new(-2, 1)));
+    /// board.place(PlacedTile::new(tile, Rotation::Deg0, Coord::new(1, -1)));
+    ///
+    /// let bounds = board.bounds().unwrap();
+    /// assert_eq!((bounds.min, bounds.max), (Coord::new(-2, -1), Coord::new(1, 1)));
+    /// assert_eq!((bounds.width(), bounds.height()), (4, 3));
+    /// ```
+    pub fn bounds(&self) -> Option<Bounds> {
+        let mut positions = self.tiles.keys();
+        let first = *positions.next()?;
+        Some(positions.fold(
+            Bounds {
+                min: first,
+                max: first,
+            },
+            |bounds, position| Bounds {
+                min: Coord::new(bounds.min.x.min(position.x), bounds.min.y.min(position.y)),
+                max: Coord::new(bounds.max.x.max(position.x), bounds.max.y.max(position.y)),
+            },
+        ))
+    }
+
+    /// Returns a text description of the whole board, meant for golden tests.
+    ///
+    /// The first line gives the bounds of the board; each following line is a row,
+    /// from the north. Cells are separated by spaces and padded to the same width, so
+    /// columns line up:
+    /// - an empty position is `.`;
+    /// - a tile is its letter followed by its number of clockwise quarter turns;
+    /// - a meeple on the tile follows as `:`, the index of its player, then `x` for
+    ///   the extension or `f` and the index of the feature.
+    ///
+    /// The output only depends on the tiles and meeples of the board, not on the order
+    /// they were placed in.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use carcasonne_core::builder::tile_builder::TileBuilder;
+    /// use carcasonne_core::model::board::{Board, Coord, PlacedTile};
+    /// use carcasonne_core::model::meeple::{Meeple, SlotId};
+    /// use carcasonne_core::model::tile::{Rotation, TileId};
+    ///
+    /// let abbey = TileBuilder::new(TileId('B')).add_abbey().build();
+    /// let mut board = Board::new();
+    /// board.place(PlacedTile::new(abbey.clone(), Rotation::Deg0, Coord::ORIGIN));
+    /// board.place(PlacedTile::new(abbey, Rotation::Deg90, Coord::new(1, 1)));
+    /// board.set_meeple(Coord::ORIGIN, Some(Meeple::new(1, SlotId::Extension)));
+    ///
+    /// assert_eq!(
+    ///     board.to_ascii_snapshot(),
+    ///     "bounds (0, 0) to (1, 1)\nB0:1x .\n.     B1\n"
+    /// );
+    /// ```
+    pub fn to_ascii_snapshot(&self) -> String {
+        let Some(bounds) = self.bounds() else {
+            return String::from("empty\n");
+        };
+        let cell = |position: Coord| match self.get(position) {
+            None => String::from("."),
+            Some(placed_tile) => {
+                let mut cell = format!(
+                    "{}{}",
+                    placed_tile.tile.id,
+                    placed_tile.rotation.quarter_turns()
+                );
+                if let Some(meeple) = placed_tile.meeple {
+                    let slot = match meeple.slot {
+                        SlotId::Extension => String::from("x"),
+                        SlotId::Feature(index) => format!("f{index}"),
+                    };
+                    cell += &format!(":{}{slot}", meeple.player);
+                }
+                cell
+            }
+        };
+
+        let rows: Vec<Vec<String>> = (bounds.min.y..=bounds.max.y)
+            .map(|y| {
+                (bounds.min.x..=bounds.max.x)
+                    .map(|x| cell(Coord::new(x, y)))
+                    .collect()
+            })
+            .collect();
+        let width = rows.iter().flatten().map(String::len).max().unwrap_or(1);
+
+        let mut snapshot = format!(
+            "bounds ({}, {}) to ({}, {})\n",
+            bounds.min.x, bounds.min.y, bounds.max.x, bounds.max.y
+        );
+        for row in rows {
+            let line: Vec<String> = row.iter().map(|cell| format!("{cell:width$}")).collect();
+            snapshot += line.join(" ").trim_end();
+            snapshot.push('\n');
+        }
+        snapshot
+    }
+
+    /// Returns the empty positions touching at least one placed tile.
+    ///
+    /// These are the only positions where a tile may be placed, except on an empty
+    /// board, whose frontier is empty as the first tile may go anywhere.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use carcasonne_core::builder::tile_builder::TileBuilder;
+    /// use carcasonne_core::model::board::{Board, Coord, PlacedTile};
+    /// use carcasonne_core::model::tile::{Rotation, TileId};
+    ///
+    /// let tile = TileBuilder::new(TileId('B')).add_abbey().build();
+    /// let mut board = Board::new();
+    /// board.place(PlacedTile::new(tile, Rotation::Deg0, Coord::ORIGIN));
+    ///
+    /// assert_eq!(board.open_positions().len(), 4);
+    /// assert!(board.open_positions().contains(&Coord::new(0, -1)));
+    /// ```
+    pub fn open_positions(&self) -> &HashSet<Coord> {
+        &self.open_positions
+    }
+
+    /// Returns the graph telling which tile features form a single feature across the board.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use carcasonne_core::builder::tile_builder::TileBuilder;
+    /// use carcasonne_core::model::board::feature_graph::FeatureRef;
+    /// use carcasonne_core::model::board::{Board, Coord};
+    /// use carcasonne_core::model::tile::{Rotation, TileId};
+    /// use carcasonne_core::model::tile_feature::Edge;
+    ///
+    /// let town = TileBuilder::new(TileId('E')).add_town(vec![Edge::North]).build();
+    /// let mut board = Board::new();
+    /// board.place_tile(Coord::ORIGIN, town.clone(), Rotation::Deg0).unwrap();
+    /// board.place_tile(Coord::new(0, -1), town, Rotation::Deg180).unwrap();
+    ///
+    /// let first = FeatureRef::new(Coord::ORIGIN, 0);
+    /// let second = FeatureRef::new(Coord::new(0, -1), 0);
+    /// assert!(board.features().connected(first, second));
+    /// assert_eq!(board.features().members(first).len(), 2);
+    /// ```
+    pub fn features(&self) -> &FeatureGraph {
+        &self.features
+    }
+
+    /// Returns `true` if the town or road `feature` belongs to is closed on every side.
+    ///
+    /// A feature is closed when each edge reached by one of its tiles leads to another
+    /// placed tile. Fields are never closed, nor are features that are not on the board.
+    pub fn is_feature_complete(&self, feature: FeatureRef) -> bool {
+        let members = self.features.members(feature);
+        !members.is_empty()
+            && members.iter().all(|member| {
+                let placed_tile = &self.tiles[&member.position];
+                let tile_feature = &placed_tile.tile.tile_features[member.index];
+                !tile_feature.is_field()
+                    && tile_feature
+                        .edges
+                        .rotated(placed_tile.rotation)
+                        .iter()
+                        .all(|edge| self.contains(member.position.neighbor(Direction::from(edge))))
+            })
+    }
+
+    /// Returns the empty positions the open edges of the town or road `feature`
+    /// belongs to lead to, row by row from the north-west.
+    ///
+    /// A feature is complete once it has no open position left. Fields, and features
+    /// that are not on the board, have none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use carcasonne_core::builder::tile_builder::TileBuilder;
+    /// use carcasonne_core::model::board::feature_graph::FeatureRef;
+    /// use carcasonne_core::model::board::{Board, Coord};
+    /// use carcasonne_core::model::tile::TileId;
+    /// use carcasonne_core::model::tile_feature::Edge;
+    ///
+    /// let road = TileBuilder::new(TileId('U')).add_road(vec![Edge::West, Edge::East]).build();
+    /// let board = Board::with_start_tile(road);
+    /// assert_eq!(
+    ///     board.open_positions_of(FeatureRef::new(Coord::ORIGIN, 0)),
+    ///     vec![Coord::new(-1, 0), Coord::new(1, 0)]
+    /// );
+    /// ```
+    pub fn open_positions_of(&self, feature: FeatureRef) -> Vec<Coord> {
+        let mut positions = Vec::new();
+        for member in self.features.members(feature) {
+            let placed_tile = &self.tiles[&member.position];
+            let tile_feature = &placed_tile.tile.tile_features[member.index];
+            if tile_feature.is_field() {
+                continue;
+            }
+            for edge in tile_feature.edges.rotated(placed_tile.rotation).iter() {
+                let neighbor = member.position.neighbor(Direction::from(edge));
+                if !self.contains(neighbor) && !positions.contains(&neighbor) {
+                    positions.push(neighbor);
+                }
+            }
+        }
+        positions.sort_by_key(|position| (position.y, position.x));
+        positions
+    }
+
+    /// Returns the town, road or field `feature` belongs to, or `None` if it is not on
+    /// the board.
+    pub fn feature_summary(&self, feature: FeatureRef) -> Option<FeatureSummary> {
+        let placed_tile = self.get(feature.position)?;
+        placed_tile.tile.tile_features.get(feature.index)?;
+        Some(self.describe_feature(feature))
+    }
+
+    /// Returns the towns and roads closed by the tile placed at `position`.
+    ///
+    /// Meant to be called right after a placement, to know which features must be
+    /// scored. Each feature is listed once, even when the tile closes it from two sides.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use carcasonne_core::builder::tile_builder::TileBuilder;
+    /// use carcasonne_core::model::board::{Board, Coord};
+    /// use carcasonne_core::model::tile::{Rotation, TileId};
+    /// use carcasonne_core::model::tile_feature::{Edge, FeatureKind};
+    ///
+    /// let town = TileBuilder::new(TileId('E')).add_town(vec![Edge::North]).build();
+    /// let mut board = Board::new();
+    /// board.place_tile(Coord::ORIGIN, town.clone(), Rotation::Deg0).unwrap();
+    /// assert!(board.completed_features_after(Coord::ORIGIN).is_empty());
+    ///
+    /// board.place_tile(Coord::new(0, -1), town, Rotation::Deg180).unwrap();
+    /// let completed = board.completed_features_after(Coord::new(0, -1));
+    /// assert_eq!(completed.len(), 1);
+    /// assert_eq!(completed[0].kind, FeatureKind::Town);
+    /// assert_eq!(completed[0].tile_count, 2);
+    /// ```
+    pub fn completed_features_after(&self, position: Coord) -> Vec<FeatureSummary> {
+        let Some(placed_tile) = self.get(position) else {
+            return Vec::new();
+        };
+        let mut completed: Vec<FeatureSummary> = Vec::new();
+        for index in 0..placed_tile.tile.tile_features.len() {
+            let feature = FeatureRef::new(position, index);
+            let already_listed = completed
+                .iter()
+                .any(|other| self.features.connected(other.members[0], feature));
+            if !already_listed && self.is_feature_complete(feature) {
+                completed.push(self.describe_feature(feature));
+            }
+        }
+        completed
+    }
+
+    /// Returns the towns and roads that are still open, each listed once, in the order
+    /// their first tile was placed.
+    ///
+    /// Meant for the end of the game, when unfinished features are scored too.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use carcasonne_core::builder::tile_builder::TileBuilder;
+    /// use carcasonne_core::model::board::{Board, Coord};
+    /// use carcasonne_core::model::tile::{Rotation, TileId};
+    /// use carcasonne_core::model::tile_feature::{Edge, FeatureKind};
+    ///
+    /// let road = TileBuilder::new(TileId('U')).add_road(vec![Edge::West, Edge::East]).build();
+    /// let mut board = Board::with_start_tile(road.clone());
+    /// board.place_tile(Coord::new(1, 0), road, Rotation::Deg0).unwrap();
+    ///
+    /// let unfinished = board.unfinished_features();
+    /// assert_eq!(unfinished.len(), 1);
+    /// assert_eq!(unfinished[0].kind, FeatureKind::Road);
+    /// assert_eq!(unfinished[0].tile_count, 2);
+    /// ```
+    pub fn unfinished_features(&self) -> Vec<FeatureSummary> {
+        self.distinct_features(|is_field| !is_field)
+            .into_iter()
+            .filter(|summary| !self.is_feature_complete(summary.members[0]))
+            .collect()
+    }
+
+    /// Returns the fields of the board, each listed once, in the order their first
+    /// tile was placed.
+    ///
+    /// Only tiles defining their fields take part: segments left to the default
+    /// field kind belong to no field.
+    pub fn fields(&self) -> Vec<FeatureSummary> {
+        self.distinct_features(|is_field| is_field)
+    }
+
+    /// Returns the towns bordering the field `field` belongs to, one tile feature for
+    /// each town, in placement order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use carcasonne_core::builder::tile_builder::TileBuilder;
+    /// use carcasonne_core::model::board::feature_graph::FeatureRef;
+    /// use carcasonne_core::model::board::{Board, Coord};
+    /// use carcasonne_core::model::tile::{Rotation, TileId};
+    /// use carcasonne_core::model::tile_feature::Edge::{East, North, South, West};
+    ///
+    /// let e = TileBuilder::new(TileId('E'))
+    ///     .add_town(vec![North])
+    ///     .add_field(vec![East, South, West])
+    ///     .build();
+    /// let mut board = Board::with_start_tile(e.clone());
+    /// board.place_tile(Coord::new(0, 1), e, Rotation::Deg180).unwrap();
+    ///
+    /// // The field of the start tile runs into the field below, and borders both towns.
+    /// let towns = board.towns_bordering(FeatureRef::new(Coord::ORIGIN, 1));
+    /// assert_eq!(towns.len(), 2);
+    /// ```
+    pub fn towns_bordering(&self, field: FeatureRef) -> Vec<FeatureRef> {
+        let mut towns: Vec<FeatureRef> = Vec::new();
+        for member in self.features.members(field) {
+            let tile = &self.tiles[&member.position].tile;
+            for index in tile.towns_bordering(member.index) {
+                let town = FeatureRef::new(member.position, index);
+                if !towns
+                    .iter()
+                    .any(|other| self.features.connected(*other, town))
+                {
+                    towns.push(town);
+                }
+            }
+        }
+        towns
+    }
+
+    /// Describes each feature of the board once, keeping only fields, or only towns
+    /// and roads, as `keep` tells from whether the tile feature is a field.
+    fn distinct_features(&self, keep: impl Fn(bool) -> bool) -> Vec<FeatureSummary> {
+        let mut listed = HashSet::new();
+        let mut summaries = Vec::new();
+        for position in &self.placement_order {
+            let tile_features = &self.tiles[position].tile.tile_features;
+            for (index, tile_feature) in tile_features.iter().enumerate() {
+                let feature = FeatureRef::new(*position, index);
+                if !keep(tile_feature.is_field()) || listed.contains(&feature) {
+                    continue;
+                }
+                let summary = self.describe_feature(feature);
+                listed.extend(summary.members.iter().copied());
+                summaries.push(summary);
+            }
+        }
+        summaries
+    }
+
+    /// Gathers the tiles, shields and meeples of the feature `feature` belongs to.
+    fn describe_feature(&self, feature: FeatureRef) -> FeatureSummary {
+        let members = self.features.members(feature);
+        let mut positions: Vec<Coord> = members.iter().map(|member| member.position).collect();
+        positions.sort_by_key(|position| (position.y, position.x));
+        positions.dedup();
+
+        let shields = members
+            .iter()
+            .filter(|member| {
+                let tile_feature = &self.tiles[&member.position].tile.tile_features[member.index];
+                tile_feature.enhancement == Some(TileFeatureEnhancement::Shield)
+            })
+            .count();
+
+        FeatureSummary {
+            kind: self.tiles[&feature.position].tile.tile_features[feature.index].kind(),
+            tile_count: positions.len(),
+            meeples: self.meeples_on(feature),
+            members,
+            shields,
+        }
+    }
+
+    /// Returns the meeples standing on the feature `feature` belongs to, across all its
+    /// tiles, in placement order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use carcasonne_core::builder::tile_builder::TileBuilder;
+    /// use carcasonne_core::model::board::feature_graph::FeatureRef;
+    /// use carcasonne_core::model::board::{Board, Coord};
+    /// use carcasonne_core::model::meeple::{Meeple, SlotId};
+    /// use carcasonne_core::model::tile::{Rotation, TileId};
+    /// use carcasonne_core::model::tile_feature::Edge;
+    ///
+    /// let road = TileBuilder::new(TileId('U')).add_road(vec![Edge::West, Edge::East]).build();
+    /// let mut board = Board::with_start_tile(road.clone());
+    /// board.place_tile(Coord::new(1, 0), road, Rotation::Deg0).unwrap();
+    /// board.set_meeple(Coord::ORIGIN, Some(Meeple::new(0, SlotId::Feature(0))));
+    ///
+    /// let meeples = board.meeples_on(FeatureRef::new(Coord::new(1, 0), 0));
+    /// assert_eq!(meeples, vec![Meeple::new(0, SlotId::Feature(0))]);
+    /// ```
+    pub fn meeples_on(&self, feature: FeatureRef) -> Vec<Meeple> {
+        self.features
+            .members(feature)
+            .into_iter()
+            .filter_map(|member| {
+                self.tiles[&member.position]
+                    .meeple
+                    .filter(|meeple| meeple.slot == SlotId::Feature(member.index))
+            })
+            .collect()
+    }
+
+    /// Returns the number of tiles placed around `position`, diagonals included, from 0 to 8.
+    pub fn surrounding_count(&self, position: Coord) -> usize {
+        position
+            .surrounding()
+            .into_iter()
+            .filter(|around| self.contains(*around))
+            .count()
+    }
+
+    /// Returns the abbeys surrounded by the tile placed at `position`.
+    ///
+    /// An abbey is scored once the eight positions around it hold a tile. The placed
+    /// tile may complete its own abbey, or any of the abbeys around it. Gardens and
+    /// monasteries are scored the same way, and are listed too.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use carcasonne_core::builder::tile_builder::TileBuilder;
+    /// use carcasonne_core::model::board::{Board, Coord, PlacedTile};
+    /// use carcasonne_core::model::tile::{Rotation, TileId};
+    ///
+    /// let abbey = TileBuilder::new(TileId('B')).add_abbey().build();
+    /// let mut board = Board::new();
+    /// board.place(PlacedTile::new(abbey.clone(), Rotation::Deg0, Coord::ORIGIN));
+    /// for position in Coord::ORIGIN.surrounding().into_iter().skip(1) {
+    ///     board.place(PlacedTile::new(abbey.clone(), Rotation::Deg0, position));
+    /// }
+    /// assert_eq!(board.surrounding_count(Coord::ORIGIN), 7);
+    ///
+    /// let last = Coord::new(-1, -1);
+    /// board.place(PlacedTile::new(abbey, Rotation::Deg0, last));
+    /// let completed = board.completed_abbeys_after(last);
+    /// assert_eq!(completed.len(), 1);
+    /// assert_eq!(completed[0].position, Coord::ORIGIN);
+    /// ```
+    pub fn completed_abbeys_after(&self, position: Coord) -> Vec<CompletedAbbey> {
+        if !self.contains(position) {
+            return Vec::new();
+        }
+        std::iter::once(position)
+            .chain(position.surrounding())
+            .filter_map(|around| {
+                let placed_tile = self.get(around)?;
+                let extension = placed_tile.tile.tile_extension?;
+                (self.surrounding_count(around) == 8).then(|| CompletedAbbey {
+                    position: around,
+                    extension,
+                    meeple: placed_tile
+                        .meeple
+                        .filter(|meeple| meeple.slot == SlotId::Extension),
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the tile placed next to `position` in the given direction, if any.
+    pub fn neighbor_in(&self, position: Coord, direction: Direction) -> Option<&PlacedTile> {
+        self.get(position.neighbor(direction))
+    }
+
+    /// Returns the tiles placed next to `position`, with the direction they lie in.
+    ///
+    /// Neighbors are listed clockwise starting from the north; each placed tile
+    /// carries its rotation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use carcasonne_core::builder::tile_builder::TileBuilder;
+    /// use carcasonne_core::model::board::{Board, Coord, Direction, PlacedTile};
+    /// use carcasonne_core::model::tile::{Rotation, TileId};
+    ///
+    /// let tile = TileBuilder::new(TileId('B')).add_abbey().build();
+    /// let mut board = Board::new();
+    /// board.place(PlacedTile::new(tile, Rotation::Deg90, Coord::new(-1, 0)));
+    ///
+    /// let neighbors = board.neighbors(Coord::ORIGIN);
+    /// assert_eq!(neighbors.len(), 1);
+    /// assert_eq!(neighbors[0].0, Direction::West);
+    /// assert_eq!(neighbors[0].1.rotation, Rotation::Deg90);
+    /// ```
+    pub fn neighbors(&self, position: Coord) -> Vec<(Direction, &PlacedTile)> {
+        Direction::ALL
+            .into_iter()
+            .filter_map(|direction| {
+                self.neighbor_in(position, direction)
+                    .map(|tile| (direction, tile))
+            })
+            .collect()
+    }
+
+    /// Places a tile at its position, replacing any tile already there.
+    ///
+    /// No rule is checked: the tile may lie anywhere, even away from the other tiles.
+    /// Use `place_tile` to lay a tile following the rules.
+    pub fn place(&mut self, placed_tile: PlacedTile) {
+        let position = placed_tile.position;
+        let links = self.feature_links(&placed_tile);
+        let feature_count = placed_tile.tile.tile_features.len();
+        self.hash ^= zobrist::placed_tile_key(&placed_tile);
+        self.record_change(position);
+        if let Some(replaced) = self.tiles.insert(position, Arc::new(placed_tile)) {
+            self.hash ^= zobrist::placed_tile_key(&replaced);
+            self.placement_order.retain(|placed| *placed != position);
+            self.placement_order.push(position);
+            self.rebuild_features();
+        } else {
+            self.placement_order.push(position);
+            self.features.add_tile(position, feature_count, &links);
+        }
+
+        self.open_positions.remove(&position);
+        for direction in Direction::ALL {
+            let neighbor = position.neighbor(direction);
+            if !self.contains(neighbor) {
+                self.open_positions.insert(neighbor);
+            }
+        }
+    }
+
+    /// Places a tile turned by `rotation` at `position`, if the rules allow it.
+    ///
+    /// The first tile may be placed anywhere. Every other tile must touch at least
+    /// one placed tile, and each of its edges must match the neighbor across it:
+    /// towns meet towns, roads meet roads and fields meet fields.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PlacementError`, leaving the board untouched, if the position is
+    /// taken, isolated, or if an edge does not match its neighbor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use carcasonne_core::builder::tile_builder::TileBuilder;
+    /// use carcasonne_core::model::board::{Board, Coord, Direction, PlacementError};
+    /// use carcasonne_core::model::tile::{Rotation, TileId};
+    /// use carcasonne_core::model::tile_feature::{Edge, FeatureKind};
+    ///
+    /// let town = TileBuilder::new(TileId('E')).add_town(vec![Edge::North]).build();
+    /// let mut board = Board::new();
+    /// board.place_tile(Coord::ORIGIN, town.clone(), Rotation::Deg0).unwrap();
+    ///
+    /// // The town of the new tile would face the field of the start tile.
+    /// assert_eq!(
+    ///     board.place_tile(Coord::new(0, 1), town.clone(), Rotation::Deg0),
+    ///     Err(PlacementError::EdgeMismatch {
+    ///         direction: Direction::North,
+    ///         expected: FeatureKind::Field,
+    ///         found: FeatureKind::Town,
+    ///     })
+    /// );
+    /// assert!(board.place_tile(Coord::new(0, -1), town, Rotation::Deg180).is_ok());
+    /// ```
+    pub fn place_tile(
+        &mut self,
+        position: Coord,
+        tile: Tile,
+        rotation: Rotation,
+    ) -> Result<(), PlacementError> {
+        let placed_tile = PlacedTile::new(tile, rotation, position);
+        self.check_placement(&placed_tile)?;
+        self.place(placed_tile);
+        Ok(())
+    }
+
+    /// Removes the most recently placed tile and returns it, or `None` if the board is empty.
+    ///
+    /// The board is left as it was before the placement, meeples of the removed tile
+    /// included, so a move can be taken back before it is confirmed. A tile replaced
+    /// by `place` is not brought back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use carcasonne_core::builder::tile_builder::TileBuilder;
+    /// use carcasonne_core::model::board::{Board, Coord};
+    /// use carcasonne_core::model::tile::{Rotation, TileId};
+    ///
+    /// let tile = TileBuilder::new(TileId('B')).add_abbey().build();
+    /// let mut board = Board::new();
+    /// board.place_tile(Coord::ORIGIN, tile.clone(), Rotation::Deg0).unwrap();
+    /// board.place_tile(Coord::new(1, 0), tile, Rotation::Deg0).unwrap();
+    ///
+    /// let removed = board.remove_last().unwrap();
+    /// assert_eq!(removed.position, Coord::new(1, 0));
+    /// assert_eq!(board.len(), 1);
+    /// ```
+    pub fn remove_last(&mut self) -> Option<PlacedTile> {
+        let position = self.placement_order.pop()?;
+        let removed = Arc::unwrap_or_clone(self.tiles.remove(&position)?);
+        self.features.remove_last_tile();
+        self.hash ^= zobrist::placed_tile_key(&removed);
+        self.record_change(position);
+
+        if self.is_empty() {
+            self.open_positions.clear();
+            return Some(removed);
+        }
+        if !self.neighbors(position).is_empty() {
+            self.open_positions.insert(position);
+        }
+        for direction in Direction::ALL {
+            let neighbor = position.neighbor(direction);
+            if !self.contains(neighbor) && self.neighbors(neighbor).is_empty() {
+                self.open_positions.remove(&neighbor);
+            }
+        }
+        Some(removed)
+    }
+
+    /// Returns every position and rotation where `tile` could be placed.
+    ///
+    /// Positions are listed row by row from the north-west, and rotations in
+    /// clockwise order. Rotations of a symmetric tile giving the same layout are all
+    /// listed. On an empty board, the tile may go to the origin in any rotation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use carcasonne_core::builder::tile_builder::TileBuilder;
+    /// use carcasonne_core::model::board::{Board, Coord};
+    /// use carcasonne_core::model::tile::{Rotation, TileId};
+    /// use carcasonne_core::model::tile_feature::Edge;
+    ///
+    /// let town = TileBuilder::new(TileId('E')).add_town(vec![Edge::North]).build();
+    /// let mut board = Board::new();
+    /// board.place_tile(Coord::ORIGIN, town.clone(), Rotation::Deg0).unwrap();
+    ///
+    /// let placements = board.legal_placements(&town);
+    /// assert!(placements.contains(&(Coord::new(0, -1), Rotation::Deg180)));
+    /// assert!(!placements.contains(&(Coord::new(0, -1), Rotation::Deg0)));
+    /// ```
+    pub fn legal_placements(&self, tile: &Tile) -> Vec<(Coord, Rotation)> {
+        if self.is_empty() {
+            return Rotation::ALL
+                .into_iter()
+                .map(|rotation| (Coord::ORIGIN, rotation))
+                .collect();
+        }
+
+        let mut positions: Vec<Coord> = self.open_positions.iter().copied().collect();
+        positions.sort_by_key(|position| (position.y, position.x));
+
+        let mut placed_tile = PlacedTile::new(tile.clone(), Rotation::Deg0, Coord::ORIGIN);
+        let mut placements = Vec::new();
+        for position in positions {
+            placed_tile.position = position;
+            for rotation in Rotation::ALL {
+                placed_tile.rotation = rotation;
+                if self.check_placement(&placed_tile).is_ok() {
+                    placements.push((position, rotation));
+                }
+            }
+        }
+        placements
+    }
+
+    /// Returns the pairs of features of `placed_tile` and of its neighbors that meet
+    /// across an edge, and thus belong to the same feature.
+    fn feature_links(&self, placed_tile: &PlacedTile) -> Vec<(FeatureRef, FeatureRef)> {
+        let mut links = Vec::new();
+        for (direction, neighbor) in self.neighbors(placed_tile.position) {
+            for segment in direction.edge().segments() {
+                if placed_tile.kind_at(segment) != neighbor.kind_at(segment.facing()) {
+                    continue;
+                }
+                let own = placed_tile.feature_index_covering(segment);
+                let other = neighbor.feature_index_covering(segment.facing());
+                if let (Some(own), Some(other)) = (own, other) {
+                    links.push((
+                        FeatureRef::new(placed_tile.position, own),
+                        FeatureRef::new(neighbor.position, other),
+                    ));
+                }
+            }
+        }
+        links
+    }
+
+    /// Rebuilds the feature graph from scratch, replaying the placements in order.
+    fn rebuild_features(&mut self) {
+        let mut features = FeatureGraph::default();
+        for position in &self.placement_order {
+            let placed_tile = &self.tiles[position];
+            // Links towards tiles placed later are ignored, and added along with them.
+            let links = self.feature_links(placed_tile);
+            features.add_tile(*position, placed_tile.tile.tile_features.len(), &links);
+        }
+        self.features = features;
+    }
+
+    /// Checks that `placed_tile` can be laid at its position, without placing it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the `PlacementError` telling why the rules forbid the placement.
+    pub fn check_placement(&self, placed_tile: &PlacedTile) -> Result<(), PlacementError> {
+        if self.contains(placed_tile.position) {
+            return Err(PlacementError::Occupied);
+        }
+        if self.is_empty() {
+            return Ok(());
+        }
+
+        if !self.open_positions.contains(&placed_tile.position) {
+            return Err(PlacementError::NotAdjacent);
+        }
+
+        for (direction, neighbor) in self.neighbors(placed_tile.position) {
+            for segment in direction.edge().segments() {
+                let expected = neighbor.kind_at(segment.facing());
+                let found = placed_tile.kind_at(segment);
+                if found != expected {
+                    return Err(PlacementError::EdgeMismatch {
+                        direction,
+                        expected,
+                        found,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::tile_builder::TileBuilder;
+    use crate::model::meeple::SlotId;
+    use crate::model::tile::TileId;
+    use crate::model::tile_feature::Edge::{East, North, South, West};
+    use crate::model::tile_feature::{EdgeSet, SegmentSet, Side};
+
+    #[test]
+    fn test_board_remembers_rotation() {
+        let mut board = Board::new();
+        let tile = TileBuilder::new(TileId('Z')).add_town(vec![North]).build();
+        let position = Coord::new(1, 2);
+
+        board.place(PlacedTile::new(tile, Rotation::Deg90, position));
+
+        let placed = board.get(position).expect("A tile should be placed");
+        assert_eq!(placed.rotation, Rotation::Deg90);
+        assert_eq!(placed.tile.tile_features[0].edges, EdgeSet::from([North]));
+        assert_eq!(
+            placed.oriented_tile().tile_features[0].edges,
+            EdgeSet::from([East])
+        );
+    }
+
+    #[test]
+    fn test_empty_board() {
+        let board = Board::new();
+        assert!(board.is_empty());
+        assert!(board.get(Coord::ORIGIN).is_none());
+        assert!(board.get(Coord::new(-5, 300)).is_none());
+    }
+
+    #[test]
+    fn test_board_grows_in_every_direction() {
+        let mut board = Board::new();
+        let tile = TileBuilder::new(TileId('Z')).add_town(vec![North]).build();
+        for position in [
+            Coord::ORIGIN,
+            Coord::new(-1, 0),
+            Coord::new(0, -1),
+            Coord::new(-40, 25),
+        ] {
+            board.place(PlacedTile::new(tile.clone(), Rotation::Deg0, position));
+        }
+
+        assert_eq!(board.len(), 4);
+        assert!(board.contains(Coord::new(-1, 0)));
+        assert!(board.contains(Coord::new(-40, 25)));
+        assert!(!board.contains(Coord::new(1, 0)));
+        assert_eq!(
+            board.get(Coord::new(0, -1)).unwrap().position,
+            Coord::new(0, -1)
+        );
+    }
+
+    #[test]
+    fn test_place_meeple_on_placed_tile() {
+        let mut board = Board::new();
+        let tile = TileBuilder::new(TileId('Z')).add_town(vec![North]).build();
+        board.place(PlacedTile::new(tile, Rotation::Deg0, Coord::ORIGIN));
+
+        board.set_meeple(Coord::ORIGIN, Some(Meeple::new(0, SlotId::Feature(0))));
+        assert_eq!(
+            board.get(Coord::ORIGIN).unwrap().meeple,
+            Some(Meeple::new(0, SlotId::Feature(0)))
+        );
+    }
+
+    fn start_tile() -> Tile {
+        TileBuilder::new(TileId('D'))
+            .add_town(vec![North])
+            .add_road(vec![West, East])
+            .build()
+    }
+
+    #[test]
+    fn test_place_first_tile_anywhere() {
+        let mut board = Board::new();
+        assert_eq!(
+            board.place_tile(Coord::new(3, -2), start_tile(), Rotation::Deg90),
+            Ok(())
+        );
+        assert_eq!(
+            board.get(Coord::new(3, -2)).unwrap().rotation,
+            Rotation::Deg90
+        );
+    }
+
+    #[test]
+    fn test_place_tile_rejects_occupied_and_isolated_positions() {
+        let mut board = Board::new();
+        board
+            .place_tile(Coord::ORIGIN, start_tile(), Rotation::Deg0)
+            .unwrap();
+
+        assert_eq!(
+            board.place_tile(Coord::ORIGIN, start_tile(), Rotation::Deg0),
+            Err(PlacementError::Occupied)
+        );
+        assert_eq!(
+            board.place_tile(Coord::new(2, 0), start_tile(), Rotation::Deg0),
+            Err(PlacementError::NotAdjacent)
+        );
+        assert_eq!(
+            board.place_tile(Coord::new(1, 1), start_tile(), Rotation::Deg0),
+            Err(PlacementError::NotAdjacent)
+        );
+        assert_eq!(board.len(), 1);
+    }
+
+    #[test]
+    fn test_place_tile_matches_every_neighbor() {
+        let mut board = Board::new();
+        board
+            .place_tile(Coord::ORIGIN, start_tile(), Rotation::Deg0)
+            .unwrap();
+
+        // Continuing the road to the east and to the west.
+        assert!(
+            board
+                .place_tile(Coord::new(1, 0), start_tile(), Rotation::Deg0)
+                .is_ok()
+        );
+        assert_eq!(
+            board.place_tile(Coord::new(-1, 0), start_tile(), Rotation::Deg90),
+            Err(PlacementError::EdgeMismatch {
+                direction: Direction::East,
+                expected: FeatureKind::Field,
+                found: FeatureKind::Town,
+            })
+        );
+        assert!(
+            board
+                .place_tile(Coord::new(-1, 0), start_tile(), Rotation::Deg0)
+                .is_ok()
+        );
+
+        // Closing the town to the north takes a town facing south.
+        let town = TileBuilder::new(TileId('E')).add_town(vec![North]).build();
+        assert_eq!(
+            board.place_tile(Coord::new(0, -1), town.clone(), Rotation::Deg0),
+            Err(PlacementError::EdgeMismatch {
+                direction: Direction::South,
+                expected: FeatureKind::Town,
+                found: FeatureKind::Field,
+            })
+        );
+        assert!(
+            board
+                .place_tile(Coord::new(0, -1), town, Rotation::Deg180)
+                .is_ok()
+        );
+
+        // Below the road, a plain field fits; a road facing north does not.
+        let road = TileBuilder::new(TileId('U'))
+            .add_road(vec![North, South])
+            .build();
+        assert_eq!(
+            board.place_tile(Coord::new(0, 1), road, Rotation::Deg0),
+            Err(PlacementError::EdgeMismatch {
+                direction: Direction::North,
+                expected: FeatureKind::Field,
+                found: FeatureKind::Road,
+            })
+        );
+        let field = TileBuilder::new(TileId('B')).add_abbey().build();
+        assert!(
+            board
+                .place_tile(Coord::new(0, 1), field, Rotation::Deg0)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_neighbor_positions() {
+        let position = Coord::new(2, -3);
+        assert_eq!(position.neighbor(Direction::North), Coord::new(2, -4));
+        assert_eq!(position.neighbor(Direction::East), Coord::new(3, -3));
+        assert_eq!(position.neighbor(Direction::South), Coord::new(2, -2));
+        assert_eq!(position.neighbor(Direction::West), Coord::new(1, -3));
+        for edge in Edge::ALL {
+            assert_eq!(Direction::from(edge).edge(), edge);
+        }
+    }
+
+    #[test]
+    fn test_coord_arithmetic() {
+        let mut position = Coord::new(3, -1);
+        assert_eq!(position + Coord::new(-5, 2), Coord::new(-2, 1));
+        assert_eq!(position - Coord::new(-5, 2), Coord::new(8, -3));
+        assert_eq!(-position, Coord::new(-3, 1));
+
+        position += Direction::West;
+        position += Direction::West;
+        assert_eq!(position, Coord::new(1, -1));
+        position -= Coord::new(1, -1);
+        assert_eq!(position, Coord::ORIGIN);
+
+        for direction in Direction::ALL {
+            assert_eq!(
+                direction.offset() + direction.opposite().offset(),
+                Coord::ORIGIN
+            );
+            assert_eq!(Coord::ORIGIN + direction, direction.offset());
+            assert_eq!(direction.opposite().edge(), direction.edge().opposite());
+        }
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let mut board = Board::new();
+        let tile = start_tile();
+        board.place(PlacedTile::new(
+            tile.clone(),
+            Rotation::Deg0,
+            Coord::new(0, -1),
+        ));
+        board.place(PlacedTile::new(
+            tile.clone(),
+            Rotation::Deg270,
+            Coord::new(-1, 0),
+        ));
+        board.place(PlacedTile::new(tile, Rotation::Deg0, Coord::new(1, 1)));
+
+        let neighbors: Vec<_> = board
+            .neighbors(Coord::ORIGIN)
+            .into_iter()
+            .map(|(direction, placed)| (direction, placed.rotation))
+            .collect();
+        assert_eq!(
+            neighbors,
+            vec![
+                (Direction::North, Rotation::Deg0),
+                (Direction::West, Rotation::Deg270)
+            ]
+        );
+        assert!(board.neighbor_in(Coord::ORIGIN, Direction::West).is_some());
+        assert!(board.neighbor_in(Coord::ORIGIN, Direction::South).is_none());
+    }
+
+    #[test]
+    fn test_open_positions_follow_placements() {
+        let mut board = Board::new();
+        assert!(board.open_positions().is_empty());
+
+        board
+            .place_tile(Coord::ORIGIN, start_tile(), Rotation::Deg0)
+            .unwrap();
+        board
+            .place_tile(Coord::new(1, 0), start_tile(), Rotation::Deg0)
+            .unwrap();
+
+        let expected: HashSet<Coord> = [
+            Coord::new(-1, 0),
+            Coord::new(0, -1),
+            Coord::new(0, 1),
+            Coord::new(1, -1),
+            Coord::new(1, 1),
+            Coord::new(2, 0),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(board.open_positions(), &expected);
+
+        // A rejected placement leaves the frontier untouched.
+        assert!(
+            board
+                .place_tile(Coord::new(0, 1), start_tile(), Rotation::Deg90)
+                .is_err()
+        );
+        assert_eq!(board.open_positions(), &expected);
+    }
+
+    #[test]
+    fn test_legal_placements() {
+        let mut board = Board::new();
+        assert_eq!(board.legal_placements(&start_tile()).len(), 4);
+
+        board
+            .place_tile(Coord::ORIGIN, start_tile(), Rotation::Deg0)
+            .unwrap();
+        let town = TileBuilder::new(TileId('E')).add_town(vec![North]).build();
+        let placements = board.legal_placements(&town);
+
+        // Above the start tile, only the rotation closing its town fits. The tile has
+        // no road to continue the one on the sides, and fits below in any rotation
+        // keeping its town away from the start tile.
+        assert_eq!(
+            placements,
+            vec![
+                (Coord::new(0, -1), Rotation::Deg180),
+                (Coord::new(0, 1), Rotation::Deg90),
+                (Coord::new(0, 1), Rotation::Deg180),
+                (Coord::new(0, 1), Rotation::Deg270),
+            ]
+        );
+        for (position, rotation) in placements {
+            assert!(
+                board
+                    .clone()
+                    .place_tile(position, town.clone(), rotation)
+                    .is_ok()
+            );
+        }
+    }
+
+    #[test]
+    fn test_iter_and_bounds() {
+        let mut board = Board::new();
+        board
+            .place_tile(Coord::ORIGIN, start_tile(), Rotation::Deg0)
+            .unwrap();
+        board
+            .place_tile(Coord::new(1, 0), start_tile(), Rotation::Deg0)
+            .unwrap();
+        let town = TileBuilder::new(TileId('E')).add_town(vec![North]).build();
+        board
+            .place_tile(Coord::new(0, -1), town, Rotation::Deg180)
+            .unwrap();
+
+        let mut positions: Vec<_> = board.iter().map(|(position, _)| position).collect();
+        positions.sort_by_key(|position| (position.y, position.x));
+        assert_eq!(
+            positions,
+            vec![Coord::new(0, -1), Coord::ORIGIN, Coord::new(1, 0)]
+        );
+        assert!(
+            board
+                .iter()
+                .all(|(position, placed)| placed.position == position)
+        );
+
+        let bounds = board.bounds().unwrap();
+        assert_eq!(bounds.min, Coord::new(0, -1));
+        assert_eq!(bounds.max, Coord::new(1, 0));
+        assert!(bounds.contains(Coord::new(1, -1)));
+        assert!(!bounds.contains(Coord::new(-1, 0)));
+    }
+
+    #[test]
+    fn test_remove_last_restores_previous_board() {
+        let mut board = Board::new();
+        assert!(board.remove_last().is_none());
+
+        board
+            .place_tile(Coord::ORIGIN, start_tile(), Rotation::Deg0)
+            .unwrap();
+        board
+            .place_tile(Coord::new(1, 0), start_tile(), Rotation::Deg0)
+            .unwrap();
+        let frontier = board.open_positions().clone();
+        let placements = board.legal_placements(&start_tile());
+
+        board
+            .place_tile(Coord::new(2, 0), start_tile(), Rotation::Deg0)
+            .unwrap();
+        let removed = board.remove_last().unwrap();
+
+        assert_eq!(removed.position, Coord::new(2, 0));
+        assert!(!board.contains(Coord::new(2, 0)));
+        assert_eq!(board.open_positions(), &frontier);
+        assert_eq!(board.legal_placements(&start_tile()), placements);
+
+        board.remove_last();
+        board.remove_last();
+        assert!(board.is_empty());
+        assert!(board.open_positions().is_empty());
+    }
+
+    #[test]
+    fn test_remove_last_after_replacing_a_tile() {
+        let mut board = Board::new();
+        board.place(PlacedTile::new(start_tile(), Rotation::Deg0, Coord::ORIGIN));
+        board.place(PlacedTile::new(
+            start_tile(),
+            Rotation::Deg0,
+            Coord::new(1, 0),
+        ));
+        board.place(PlacedTile::new(
+            start_tile(),
+            Rotation::Deg90,
+            Coord::ORIGIN,
+        ));
+
+        assert_eq!(board.remove_last().unwrap().rotation, Rotation::Deg90);
+        assert_eq!(board.remove_last().unwrap().position, Coord::new(1, 0));
+        assert!(board.remove_last().is_none());
+    }
+
+    /// Tile D with its fields: north of the road, and south of it.
+    fn start_tile_with_fields() -> Tile {
+        TileBuilder::new(TileId('D'))
+            .add_town(vec![North])
+            .add_road(vec![West, East])
+            .add_field([West.segment(Side::Right), East.segment(Side::Left)])
+            .add_field(
+                SegmentSet::from([South])
+                    .with(East.segment(Side::Right))
+                    .with(West.segment(Side::Left)),
+            )
+            .build()
+    }
+
+    #[test]
+    fn test_features_join_across_tiles() {
+        let mut board = Board::new();
+        let east = Coord::new(1, 0);
+        board
+            .place_tile(Coord::ORIGIN, start_tile_with_fields(), Rotation::Deg0)
+            .unwrap();
+        board
+            .place_tile(east, start_tile_with_fields(), Rotation::Deg0)
+            .unwrap();
+        let features = board.features();
+
+        // The road and both fields go on; the towns are separate.
+        let road = FeatureRef::new(Coord::ORIGIN, 1);
+        assert!(features.connected(road, FeatureRef::new(east, 1)));
+        assert!(features.connected(FeatureRef::new(Coord::ORIGIN, 2), FeatureRef::new(east, 2)));
+        assert!(features.connected(FeatureRef::new(Coord::ORIGIN, 3), FeatureRef::new(east, 3)));
+        assert!(!features.connected(FeatureRef::new(Coord::ORIGIN, 2), FeatureRef::new(east, 3)));
+        assert!(!features.connected(FeatureRef::new(Coord::ORIGIN, 0), FeatureRef::new(east, 0)));
+        assert_eq!(features.members(road), vec![road, FeatureRef::new(east, 1)]);
+
+        // Closing the town below a rotated tile joins the town edge to edge.
+        let north = Coord::new(0, -1);
+        board
+            .place_tile(north, start_tile_with_fields(), Rotation::Deg180)
+            .unwrap();
+        assert!(
+            board
+                .features()
+                .connected(FeatureRef::new(Coord::ORIGIN, 0), FeatureRef::new(north, 0))
+        );
+    }
+
+    #[test]
+    fn test_remove_last_splits_features() {
+        let mut board = Board::new();
+        board
+            .place_tile(Coord::ORIGIN, start_tile_with_fields(), Rotation::Deg0)
+            .unwrap();
+        // The east tile touches no other tile, so it is laid without the rules.
+        board.place(PlacedTile::new(
+            start_tile_with_fields(),
+            Rotation::Deg0,
+            Coord::new(2, 0),
+        ));
+        let west = FeatureRef::new(Coord::ORIGIN, 1);
+        let east = FeatureRef::new(Coord::new(2, 0), 1);
+        assert!(!board.features().connected(west, east));
+
+        board
+            .place_tile(Coord::new(1, 0), start_tile_with_fields(), Rotation::Deg0)
+            .unwrap();
+        assert!(board.features().connected(west, east));
+
+        board.remove_last();
+        assert!(!board.features().connected(west, east));
+        assert_eq!(board.features().members(west), vec![west]);
+    }
+
+    #[test]
+    fn test_replacing_a_tile_rebuilds_features() {
+        let mut board = Board::new();
+        let east = Coord::new(1, 0);
+        board.place(PlacedTile::new(
+            start_tile_with_fields(),
+            Rotation::Deg0,
+            Coord::ORIGIN,
+        ));
+        board.place(PlacedTile::new(
+            start_tile_with_fields(),
+            Rotation::Deg0,
+            east,
+        ));
+        let road = FeatureRef::new(Coord::ORIGIN, 1);
+        assert!(board.features().connected(road, FeatureRef::new(east, 1)));
+
+        // Turned a quarter, the road of the east tile runs north to south.
+        board.place(PlacedTile::new(
+            start_tile_with_fields(),
+            Rotation::Deg90,
+            east,
+        ));
+        assert!(!board.features().connected(road, FeatureRef::new(east, 1)));
+        assert_eq!(board.features().members(road), vec![road]);
+
+        board.remove_last();
+        assert!(
+            board
+                .features()
+                .members(FeatureRef::new(east, 1))
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_completed_town_with_shield_and_meeple() {
+        let mut board = Board::new();
+        let town = TileBuilder::new(TileId('E')).add_town(vec![North]).build();
+        let middle = TileBuilder::new(TileId('G'))
+            .add_shielded_town(vec![West, East])
+            .build();
+        board
+            .place_tile(Coord::ORIGIN, middle, Rotation::Deg0)
+            .unwrap();
+        board.set_meeple(Coord::ORIGIN, Some(Meeple::new(1, SlotId::Feature(0))));
+        board
+            .place_tile(Coord::new(-1, 0), town.clone(), Rotation::Deg90)
+            .unwrap();
+        assert!(board.completed_features_after(Coord::new(-1, 0)).is_empty());
+        assert!(!board.is_feature_complete(FeatureRef::new(Coord::ORIGIN, 0)));
+        let unfinished = board.unfinished_features();
+        assert_eq!(unfinished.len(), 1);
+        assert_eq!(unfinished[0].tile_count, 2);
+        assert_eq!(unfinished[0].shields, 1);
+
+        board
+            .place_tile(Coord::new(1, 0), town, Rotation::Deg270)
+            .unwrap();
+        assert!(board.unfinished_features().is_empty());
+        let completed = board.completed_features_after(Coord::new(1, 0));
+        assert_eq!(completed.len(), 1);
+        assert_eq!(completed[0].kind, FeatureKind::Town);
+        assert_eq!(completed[0].tile_count, 3);
+        assert_eq!(completed[0].shields, 1);
+        assert_eq!(
+            completed[0].meeples,
+            vec![Meeple::new(1, SlotId::Feature(0))]
+        );
+        assert!(board.is_feature_complete(FeatureRef::new(Coord::ORIGIN, 0)));
+    }
+
+    #[test]
+    fn test_completed_road_and_feature_closed_from_two_sides() {
+        let mut board = Board::new();
+        let road_end = TileBuilder::new(TileId('A'))
+            .add_abbey()
+            .add_road(vec![South])
+            .build();
+        let road = TileBuilder::new(TileId('U'))
+            .add_road(vec![North, South])
+            .build();
+        board.place(PlacedTile::new(
+            road_end.clone(),
+            Rotation::Deg0,
+            Coord::ORIGIN,
+        ));
+        board.place(PlacedTile::new(
+            road_end,
+            Rotation::Deg180,
+            Coord::new(0, 2),
+        ));
+
+        // The middle tile closes the road at both of its ends.
+        board
+            .place_tile(Coord::new(0, 1), road, Rotation::Deg0)
+            .unwrap();
+        let completed = board.completed_features_after(Coord::new(0, 1));
+        assert_eq!(completed.len(), 1);
+        assert_eq!(completed[0].kind, FeatureKind::Road);
+        assert_eq!(completed[0].tile_count, 3);
+        assert_eq!(completed[0].members.len(), 3);
+        assert!(completed[0].meeples.is_empty());
+
+        board.remove_last();
+        assert!(board.completed_features_after(Coord::ORIGIN).is_empty());
+    }
+
+    #[test]
+    fn test_surrounding_count_and_completed_abbeys() {
+        let mut board = Board::new();
+        let abbey = TileBuilder::new(TileId('B')).add_abbey().build();
+        let field = TileBuilder::new(TileId('Z')).build();
+        let mut with_meeple = PlacedTile::new(abbey.clone(), Rotation::Deg0, Coord::ORIGIN);
+        with_meeple.meeple = Some(Meeple::new(0, SlotId::Extension));
+        board.place(with_meeple);
+        board.place(PlacedTile::new(abbey, Rotation::Deg0, Coord::new(1, 0)));
+        assert_eq!(board.surrounding_count(Coord::ORIGIN), 1);
+
+        let around: Vec<Coord> = Coord::ORIGIN
+            .surrounding()
+            .into_iter()
+            .filter(|position| *position != Coord::new(1, 0))
+            .collect();
+        for position in &around[..around.len() - 1] {
+            board.place(PlacedTile::new(field.clone(), Rotation::Deg0, *position));
+            assert!(board.completed_abbeys_after(*position).is_empty());
+        }
+        assert_eq!(board.surrounding_count(Coord::ORIGIN), 7);
+
+        let last = around[around.len() - 1];
+        board.place(PlacedTile::new(field, Rotation::Deg0, last));
+        assert_eq!(board.surrounding_count(Coord::ORIGIN), 8);
+        assert_eq!(board.surrounding_count(Coord::new(1, 0)), 5);
+        assert_eq!(
+            board.completed_abbeys_after(last),
+            vec![CompletedAbbey {
+                position: Coord::ORIGIN,
+                extension: TileExtension::Abbey,
+                meeple: Some(Meeple::new(0, SlotId::Extension)),
+            }]
+        );
+        assert!(board.completed_abbeys_after(Coord::new(5, 5)).is_empty());
+    }
+
+    #[test]
+    fn test_serialize_board() {
+        let mut board = Board::new();
+        board
+            .place_tile(Coord::ORIGIN, start_tile_with_fields(), Rotation::Deg0)
+            .unwrap();
+        board
+            .place_tile(Coord::new(1, 0), start_tile_with_fields(), Rotation::Deg0)
+            .unwrap();
+        board.set_meeple(Coord::new(1, 0), Some(Meeple::new(1, SlotId::Feature(1))));
+        board
+            .place_tile(
+                Coord::new(0, -1),
+                start_tile_with_fields(),
+                Rotation::Deg180,
+            )
+            .unwrap();
+
+        let json = serde_json::to_string(&board).unwrap();
+        let mut decoded: Board = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(decoded.len(), 3);
+        assert_eq!(decoded.open_positions(), board.open_positions());
+        assert_eq!(
+            decoded.legal_placements(&start_tile()),
+            board.legal_placements(&start_tile())
+        );
+        assert_eq!(
+            decoded.get(Coord::new(1, 0)).unwrap().meeple,
+            Some(Meeple::new(1, SlotId::Feature(1)))
+        );
+        assert!(decoded.features().connected(
+            FeatureRef::new(Coord::ORIGIN, 1),
+            FeatureRef::new(Coord::new(1, 0), 1)
+        ));
+        assert_eq!(decoded.remove_last().unwrap().position, Coord::new(0, -1));
+        assert_eq!(decoded.remove_last().unwrap().position, Coord::new(1, 0));
+    }
+
+    #[test]
+    fn test_serialize_placed_tile() {
+        let placed = PlacedTile::new(start_tile(), Rotation::Deg90, Coord::new(-1, 2));
+        let json = serde_json::to_value(&placed).unwrap();
+        assert_eq!(json["position"], serde_json::json!({ "x": -1, "y": 2 }));
+        assert_eq!(json["rotation"], "Deg90");
+        assert!(json.get("meeple").is_none());
+
+        let decoded: PlacedTile = serde_json::from_value(json).unwrap();
+        assert_eq!(decoded.position, placed.position);
+        assert_eq!(decoded.rotation, placed.rotation);
+        assert!(decoded.tile.equivalent_to(&placed.tile));
+        assert!(decoded.meeple.is_none());
+    }
+
+    #[test]
+    fn test_snapshot_shares_tiles_until_changed() {
+        let mut board = Board::new();
+        board
+            .place_tile(Coord::ORIGIN, start_tile(), Rotation::Deg0)
+            .unwrap();
+        board
+            .place_tile(Coord::new(1, 0), start_tile(), Rotation::Deg0)
+            .unwrap();
+
+        let mut snapshot = board.snapshot();
+        assert!(Arc::ptr_eq(
+            &board.tiles[&Coord::ORIGIN],
+            &snapshot.tiles[&Coord::ORIGIN]
+        ));
+
+        snapshot.set_meeple(Coord::ORIGIN, Some(Meeple::new(0, SlotId::Feature(0))));
+        snapshot.remove_last();
+        assert!(!Arc::ptr_eq(
+            &board.tiles[&Coord::ORIGIN],
+            &snapshot.tiles[&Coord::ORIGIN]
+        ));
+        assert!(board.get(Coord::ORIGIN).unwrap().meeple.is_none());
+        assert_eq!(board.len(), 2);
+        assert!(board.features().connected(
+            FeatureRef::new(Coord::ORIGIN, 1),
+            FeatureRef::new(Coord::new(1, 0), 1)
+        ));
+    }
+
+    #[test]
+    fn test_hash_follows_tiles_and_meeples() {
+        let mut board = Board::new();
+        let empty = board.hash();
+        board
+            .place_tile(Coord::ORIGIN, start_tile(), Rotation::Deg0)
+            .unwrap();
+        board
+            .place_tile(Coord::new(1, 0), start_tile(), Rotation::Deg0)
+            .unwrap();
+        let two_tiles = board.hash();
+
+        // The same tiles placed in another order give the same hash.
+        let mut other = Board::new();
+        other.place(PlacedTile::new(
+            start_tile(),
+            Rotation::Deg0,
+            Coord::new(1, 0),
+        ));
+        other.place(PlacedTile::new(start_tile(), Rotation::Deg0, Coord::ORIGIN));
+        assert_eq!(other.hash(), two_tiles);
+
+        // Meeples count, and taking one back restores the hash.
+        let meeple = Meeple::new(0, SlotId::Feature(1));
+        assert_eq!(board.set_meeple(Coord::ORIGIN, Some(meeple)), None);
+        let with_meeple = board.hash();
+        assert_ne!(with_meeple, two_tiles);
+        assert_eq!(board.set_meeple(Coord::ORIGIN, None), Some(meeple));
+        assert_eq!(board.hash(), two_tiles);
+        assert_eq!(board.set_meeple(Coord::new(7, 7), Some(meeple)), None);
+        assert_eq!(board.hash(), two_tiles);
+
+        // The hash survives snapshots and serialization.
+        board.set_meeple(Coord::ORIGIN, Some(meeple));
+        assert_eq!(board.snapshot().hash(), with_meeple);
+        let json = serde_json::to_string(&board).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Board>(&json).unwrap().hash(),
+            with_meeple
+        );
+
+        // Replacing a tile, then undoing every placement, leaves the empty hash.
+        board.place(PlacedTile::new(
+            start_tile(),
+            Rotation::Deg90,
+            Coord::new(1, 0),
+        ));
+        assert_ne!(board.hash(), with_meeple);
+        board.remove_last();
+        board.remove_last();
+        assert_eq!(board.hash(), empty);
+    }
+
+    #[test]
+    fn test_changes_since_version() {
+        let mut board = Board::new();
+        assert_eq!(board.version(), 0);
+        board
+            .place_tile(Coord::ORIGIN, start_tile(), Rotation::Deg0)
+            .unwrap();
+        let first = board.version();
+        board
+            .place_tile(Coord::new(1, 0), start_tile(), Rotation::Deg0)
+            .unwrap();
+        board
+            .place_tile(Coord::new(-1, 0), start_tile(), Rotation::Deg0)
+            .unwrap();
+        board.set_meeple(Coord::new(1, 0), Some(Meeple::new(0, SlotId::Feature(1))));
+
+        assert_eq!(
+            board.changes_since(0),
+            vec![Coord::new(-1, 0), Coord::ORIGIN, Coord::new(1, 0)]
+        );
+        assert_eq!(
+            board.changes_since(first),
+            vec![Coord::new(-1, 0), Coord::new(1, 0)]
+        );
+
+        // A rejected placement changes nothing; an undone one must be redrawn.
+        let drawn = board.version();
+        assert!(
+            board
+                .place_tile(Coord::new(0, 1), start_tile(), Rotation::Deg90)
+                .is_err()
+        );
+        board.set_meeple(Coord::new(0, 5), Some(Meeple::new(0, SlotId::Extension)));
+        assert!(board.changes_since(drawn).is_empty());
+        board.remove_last();
+        assert_eq!(board.changes_since(drawn), vec![Coord::new(-1, 0)]);
+    }
+
+    #[test]
+    fn test_ascii_snapshot() {
+        assert_eq!(Board::new().to_ascii_snapshot(), "empty\n");
+
+        let mut board = Board::new();
+        board
+            .place_tile(Coord::ORIGIN, start_tile(), Rotation::Deg0)
+            .unwrap();
+        board
+            .place_tile(Coord::new(-1, 0), start_tile(), Rotation::Deg0)
+            .unwrap();
+        let town = TileBuilder::new(TileId('E')).add_town(vec![North]).build();
+        board
+            .place_tile(Coord::new(0, -1), town, Rotation::Deg180)
+            .unwrap();
+        board.set_meeple(Coord::new(-1, 0), Some(Meeple::new(2, SlotId::Feature(1))));
+
+        let snapshot = board.to_ascii_snapshot();
+        assert_eq!(
+            snapshot,
+            "bounds (-1, -1) to (0, 0)\n\
+             .      E2\n\
+             D0:2f1 D0\n"
+        );
+
+        // Building the same board in another order gives the same snapshot.
+        let mut other = Board::new();
+        for position in [Coord::new(0, -1), Coord::new(-1, 0), Coord::ORIGIN] {
+            let placed = board.get(position).unwrap().clone();
+            other.place(placed);
+        }
+        assert_eq!(other.to_ascii_snapshot(), snapshot);
+    }
+
+    #[test]
+    fn test_placed_tile_with_meeple() {
+        let tile = TileBuilder::new(TileId('Z')).add_town(vec![North]).build();
+        let mut placed = PlacedTile::new(tile, Rotation::Deg0, Coord::default());
+        assert!(placed.meeple.is_none());
+
+        placed.meeple = Some(Meeple::new(1, SlotId::Feature(0)));
+        assert_eq!(placed.meeple, Some(Meeple::new(1, SlotId::Feature(0))));
+    }
+}
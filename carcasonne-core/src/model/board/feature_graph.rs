@@ -0,0 +1,186 @@
+use crate::model::board::Coord;
+use std::collections::HashMap;
+
+/// A feature of a placed tile: the position of the tile and the index of the
+/// feature in its `tile_features`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FeatureRef {
+    /// The position of the tile holding the feature.
+    pub position: Coord,
+    /// The index of the feature in the `tile_features` of the tile.
+    pub index: usize,
+}
+
+impl FeatureRef {
+    /// Creates a reference to the feature at `index` on the tile placed at `position`.
+    pub fn new(position: Coord, index: usize) -> Self {
+        Self { position, index }
+    }
+}
+
+/// What a tile placement changed in the graph, so it can be undone.
+#[derive(Debug, Clone, Default)]
+struct PlacementRecord {
+    /// The number of features the tile added.
+    added: usize,
+    /// The merges done when the tile was added, as `(child, parent)` roots.
+    merges: Vec<(usize, usize)>,
+}
+
+/// Tracks which features of the placed tiles form a single feature across the board.
+///
+/// Every town, road and field of a placed tile is a node of a union-find structure.
+/// Placing a tile merges its features with the ones they touch on neighboring tiles,
+/// so a town spread over five tiles ends up as one set. Sets are merged by size
+/// without path compression, which keeps every merge reversible: placements can be
+/// undone in reverse order.
+#[derive(Debug, Clone, Default)]
+pub struct FeatureGraph {
+    nodes: HashMap<FeatureRef, usize>,
+    features: Vec<FeatureRef>,
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    records: Vec<PlacementRecord>,
+}
+
+impl FeatureGraph {
+    /// Adds the `feature_count` features of the tile placed at `position`.
+    ///
+    /// Each pair of `links` joins a feature of the new tile to a feature of an
+    /// already placed tile that it touches.
+    pub(crate) fn add_tile(
+        &mut self,
+        position: Coord,
+        feature_count: usize,
+        links: &[(FeatureRef, FeatureRef)],
+    ) {
+        for index in 0..feature_count {
+            let node = self.features.len();
+            let feature = FeatureRef::new(position, index);
+            self.nodes.insert(feature, node);
+            self.features.push(feature);
+            self.parent.push(node);
+            self.size.push(1);
+        }
+
+        let mut record = PlacementRecord {
+            added: feature_count,
+            merges: Vec::new(),
+        };
+        for (a, b) in links {
+            let (Some(&a), Some(&b)) = (self.nodes.get(a), self.nodes.get(b)) else {
+                continue;
+            };
+            let (a, b) = (self.root(a), self.root(b));
+            if a == b {
+                continue;
+            }
+            let (child, parent) = if self.size[a] <= self.size[b] {
+                (a, b)
+            } else {
+                (b, a)
+            };
+            self.parent[child] = parent;
+            self.size[parent] += self.size[child];
+            record.merges.push((child, parent));
+        }
+        self.records.push(record);
+    }
+
+    /// Undoes the most recent call to `add_tile`.
+    pub(crate) fn remove_last_tile(&mut self) {
+        let Some(record) = self.records.pop() else {
+            return;
+        };
+        for (child, parent) in record.merges.into_iter().rev() {
+            self.parent[child] = child;
+            self.size[parent] -= self.size[child];
+        }
+        for _ in 0..record.added {
+            if let Some(feature) = self.features.pop() {
+                self.nodes.remove(&feature);
+            }
+        }
+        let len = self.features.len();
+        self.parent.truncate(len);
+        self.size.truncate(len);
+    }
+
+    /// Returns the root node of the set holding `node`.
+    fn root(&self, mut node: usize) -> usize {
+        while self.parent[node] != node {
+            node = self.parent[node];
+        }
+        node
+    }
+
+    /// Returns `true` if both features are part of the same feature across the board.
+    ///
+    /// Features of tiles that are not placed are never connected.
+    pub fn connected(&self, a: FeatureRef, b: FeatureRef) -> bool {
+        match (self.nodes.get(&a), self.nodes.get(&b)) {
+            (Some(&a), Some(&b)) => self.root(a) == self.root(b),
+            _ => false,
+        }
+    }
+
+    /// Returns the tile features forming, with `feature`, one feature across the board.
+    ///
+    /// The list includes `feature` itself, and follows the order the tiles were placed in.
+    /// It is empty if `feature` is not on the board.
+    pub fn members(&self, feature: FeatureRef) -> Vec<FeatureRef> {
+        let Some(&node) = self.nodes.get(&feature) else {
+            return Vec::new();
+        };
+        let root = self.root(node);
+        (0..self.features.len())
+            .filter(|other| self.root(*other) == root)
+            .map(|other| self.features[other])
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_links_merge_features() {
+        let mut graph = FeatureGraph::default();
+        let a = FeatureRef::new(Coord::ORIGIN, 0);
+        let b = FeatureRef::new(Coord::new(1, 0), 1);
+        let c = FeatureRef::new(Coord::new(1, 0), 0);
+
+        graph.add_tile(Coord::ORIGIN, 1, &[]);
+        graph.add_tile(Coord::new(1, 0), 2, &[(b, a)]);
+
+        assert!(graph.connected(a, b));
+        assert!(!graph.connected(a, c));
+        assert_eq!(graph.members(b), vec![a, b]);
+        assert_eq!(graph.members(c), vec![c]);
+        assert!(
+            graph
+                .members(FeatureRef::new(Coord::new(5, 5), 0))
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_remove_last_tile_splits_features() {
+        let mut graph = FeatureGraph::default();
+        let left = FeatureRef::new(Coord::ORIGIN, 0);
+        let right = FeatureRef::new(Coord::new(2, 0), 0);
+        let middle = FeatureRef::new(Coord::new(1, 0), 0);
+
+        graph.add_tile(Coord::ORIGIN, 1, &[]);
+        graph.add_tile(Coord::new(2, 0), 1, &[]);
+        graph.add_tile(Coord::new(1, 0), 1, &[(middle, left), (middle, right)]);
+        assert!(graph.connected(left, right));
+
+        graph.remove_last_tile();
+        assert!(!graph.connected(left, right));
+        assert!(!graph.connected(left, middle));
+        assert_eq!(graph.members(left), vec![left]);
+        assert_eq!(graph.members(right), vec![right]);
+    }
+}
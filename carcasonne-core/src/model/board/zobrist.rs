@@ -0,0 +1,88 @@
+//! Zobrist keys of the elements of a board.
+//!
+//! The hash of a board is the exclusive or of the keys of its placed tiles and
+//! meeples. Adding or removing an element flips its key, so the hash is updated in
+//! constant time, and two boards holding the same elements hash the same whatever
+//! the order they were built in.
+//!
+//! Positions are unbounded, so keys are not drawn from a random table: they are
+//! derived from the element they stand for by a fixed mixing function, and thus
+//! stay the same from one run to the next.
+use crate::model::board::{Coord, PlacedTile};
+use crate::model::meeple::{Meeple, SlotId};
+
+/// Tells the keys of tiles from the keys of meeples.
+const TILE_TAG: u64 = 1 << 40;
+/// Tells the keys of meeples from the keys of tiles.
+const MEEPLE_TAG: u64 = 2 << 40;
+
+/// Scrambles the bits of `value` (the finalizer of SplitMix64).
+fn mix(value: u64) -> u64 {
+    let mut z = value.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Returns the key of an element, described by `code`, lying at `position`.
+fn key(position: Coord, code: u64) -> u64 {
+    let position = u64::from(position.x as u32) | (u64::from(position.y as u32) << 32);
+    mix(mix(position) ^ code)
+}
+
+/// Returns the key of a placed tile, which depends on its position, its definition
+/// and its rotation, but not on its meeple.
+pub(crate) fn tile_key(placed_tile: &PlacedTile) -> u64 {
+    let code = TILE_TAG
+        | (u64::from(placed_tile.tile.id.0) << 2)
+        | placed_tile.rotation.quarter_turns() as u64;
+    key(placed_tile.position, code)
+}
+
+/// Returns the key of a meeple standing on the tile placed at `position`.
+pub(crate) fn meeple_key(position: Coord, meeple: Meeple) -> u64 {
+    let slot = match meeple.slot {
+        SlotId::Extension => 0,
+        SlotId::Feature(index) => index as u64 + 1,
+    };
+    key(position, MEEPLE_TAG | ((meeple.player as u64) << 16) | slot)
+}
+
+/// Returns the key of a placed tile along with its meeple, if any.
+pub(crate) fn placed_tile_key(placed_tile: &PlacedTile) -> u64 {
+    let meeple = placed_tile
+        .meeple
+        .map_or(0, |meeple| meeple_key(placed_tile.position, meeple));
+    tile_key(placed_tile) ^ meeple
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::tile_builder::TileBuilder;
+    use crate::model::tile::{Rotation, TileId};
+
+    #[test]
+    fn test_keys_tell_elements_apart() {
+        let tile = TileBuilder::new(TileId('B')).add_abbey().build();
+        let placed = PlacedTile::new(tile.clone(), Rotation::Deg0, Coord::ORIGIN);
+        let keys = [
+            tile_key(&placed),
+            tile_key(&PlacedTile::new(
+                tile.clone(),
+                Rotation::Deg90,
+                Coord::ORIGIN,
+            )),
+            tile_key(&PlacedTile::new(tile, Rotation::Deg0, Coord::new(0, 1))),
+            meeple_key(Coord::ORIGIN, Meeple::new(0, SlotId::Extension)),
+            meeple_key(Coord::ORIGIN, Meeple::new(1, SlotId::Extension)),
+            meeple_key(Coord::ORIGIN, Meeple::new(0, SlotId::Feature(0))),
+        ];
+        for (i, a) in keys.iter().enumerate() {
+            for b in &keys[i + 1..] {
+                assert_ne!(a, b);
+            }
+        }
+        assert_eq!(placed_tile_key(&placed), tile_key(&placed));
+    }
+}
@@ -1,46 +1,1256 @@
-use crate::model::tile::Tile;
-use rand::rng;
+pub mod clock;
+pub mod event;
+pub mod history;
+pub mod moves;
+pub mod notation;
+pub mod replay;
+pub mod turn_engine;
+
+use crate::model::board::feature_graph::FeatureRef;
+use crate::model::board::{Board, Coord, PlacedTile, PlacementError};
+use crate::model::game::clock::{TimeControl, Timeout};
+use crate::model::game::event::{GameEvent, ScoreEvent};
+use crate::model::game::history::{Command, Counters, History};
+use crate::model::game::moves::Move;
+use crate::model::game::turn_engine::{TurnEngine, TurnPhase};
+use crate::model::meeple::{MEEPLES_PER_PLAYER, Meeple, SlotId};
+use crate::model::player::Player;
+use crate::model::rules::{AbbeyCompletion, RulesConfig};
+use crate::model::tile::{Rotation, Tile, TileId};
+use crate::model::tile_feature::FeatureKind;
+use crate::model::tile_meta::ExpansionId;
+use crate::scoring::estimator::Estimator;
+use crate::scoring::score_board::{ScoreBoard, Standing};
+use crate::scoring::scorer::{ScoredFeature, Scorers};
+use crate::scoring::{FeatureScore, ScoreBreakdown};
+use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
+use rand::{Rng, RngCore, SeedableRng};
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
 
 /// A collection of tiles available in the game.
 ///
 /// This structure represents the "tile bag" from which tiles can be drawn.
 /// Tiles can be shuffled and drawn randomly one at a time.
+///
+/// The start tile, laid at the origin before the first turn, is kept aside from the bag.
 #[derive(Debug)]
 pub struct GameTiles {
     /// The list of remaining tiles in the game.
     pub available_tiles: Vec<Tile>,
+    /// The tile placed on the board when the game begins, if any.
+    pub start_tile: Option<Tile>,
+    /// The seed of the random number generator drawing the tiles, making the game
+    /// reproducible; `None` seeds it from the operating system.
+    pub seed: Option<u64>,
+    /// The rules the game is played with.
+    pub rules: RulesConfig,
 }
 
 impl GameTiles {
+    /// Returns a new random number generator to draw the tiles with, seeded with
+    /// `seed` if it is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use carcasonne_core::builder::game_builder::GameBuilder;
+    /// use rand::Rng;
+    ///
+    /// let tiles = GameBuilder::new().seed(7).build();
+    /// assert_eq!(tiles.rng().random::<u64>(), tiles.rng().random::<u64>());
+    /// ```
+    pub fn rng(&self) -> StdRng {
+        match self.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_os_rng(),
+        }
+    }
+
     /// Randomly selects and removes a tile from the remaining pool.
     ///
-    /// Internally, this method shuffles the remaining tiles and pops one
+    /// Internally, this method shuffles the remaining tiles with `rng` and pops one
     /// from the end of the vector. It returns `None` if no tiles remain.
     ///
     /// # Examples
     ///
     /// ```
     /// use carcasonne_core::model::game::GameTiles;
+    /// use carcasonne_core::model::rules::RulesConfig;
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
     ///
-    /// let mut game_tiles = GameTiles { available_tiles: vec![] };
-    /// let tile = game_tiles.select_random_tile();
+    /// let mut game_tiles = GameTiles {
+    ///     available_tiles: vec![],
+    ///     start_tile: None,
+    ///     seed: None,
+    ///     rules: RulesConfig::default(),
+    /// };
+    /// let tile = game_tiles.select_random_tile(&mut StdRng::seed_from_u64(7));
     /// ```
-    pub fn select_random_tile(&mut self) -> Option<Tile> {
-        self.available_tiles.shuffle(&mut rng());
+    pub fn select_random_tile<R: Rng + ?Sized>(&mut self, rng: &mut R) -> Option<Tile> {
+        self.available_tiles.shuffle(rng);
         self.available_tiles.pop()
     }
+
+    /// Draws tiles until one can be placed on `board`, and returns it along with the
+    /// tiles drawn before, which fit nowhere.
+    ///
+    /// Following the rules, a tile that cannot be placed anywhere is put back in the
+    /// box and another one is drawn. The discarded tiles are removed from the bag. If
+    /// the bag runs out first, no tile is returned, but the discarded ones still are.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use carcasonne_core::builder::tile_builder::TileBuilder;
+    /// use carcasonne_core::model::board::Board;
+    /// use carcasonne_core::model::game::GameTiles;
+    /// use carcasonne_core::model::rules::RulesConfig;
+    /// use carcasonne_core::model::tile::TileId;
+    /// use carcasonne_core::model::tile_feature::Edge;
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    ///
+    /// let town = TileBuilder::new(TileId('E')).add_town(vec![Edge::North]).build();
+    /// let mut game_tiles = GameTiles {
+    ///     available_tiles: vec![town.clone()],
+    ///     start_tile: None,
+    ///     seed: None,
+    ///     rules: RulesConfig::default(),
+    /// };
+    /// let board = Board::with_start_tile(town);
+    ///
+    /// let draw = game_tiles.draw_playable_tile(&board, &mut StdRng::seed_from_u64(7));
+    /// assert!(draw.tile.is_some());
+    /// assert!(draw.discarded.is_empty());
+    /// ```
+    pub fn draw_playable_tile<R: Rng + ?Sized>(&mut self, board: &Board, rng: &mut R) -> TileDraw {
+        let mut discarded = Vec::new();
+        while let Some(tile) = self.select_random_tile(rng) {
+            if !board.legal_placements(&tile).is_empty() {
+                return TileDraw {
+                    tile: Some(tile),
+                    discarded,
+                };
+            }
+            discarded.push(tile);
+        }
+        TileDraw {
+            tile: None,
+            discarded,
+        }
+    }
+
+    /// Returns the number of remaining tiles printed in the given expansion.
+    ///
+    /// Tiles without metadata are not counted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use carcasonne_core::factory::game_factory::GameTilesFactory;
+    /// use carcasonne_core::model::tile_meta::ExpansionId;
+    ///
+    /// let tiles = GameTilesFactory::build_base_game();
+    /// let remaining = tiles.remaining_from(ExpansionId::Base);
+    /// assert_eq!(
+    ///     format!("{remaining} tiles from {} remaining", ExpansionId::Base),
+    ///     "71 tiles from Base game remaining"
+    /// );
+    /// ```
+    pub fn remaining_from(&self, expansion: ExpansionId) -> usize {
+        self.available_tiles
+            .iter()
+            .filter(|tile| tile.meta.is_some_and(|meta| meta.expansion == expansion))
+            .count()
+    }
+
+    /// Keeps only the tiles printed in one of the given expansions.
+    ///
+    /// Tiles without metadata are kept, as their origin is unknown.
+    pub fn retain_expansions(&mut self, expansions: &[ExpansionId]) {
+        self.available_tiles.retain(|tile| {
+            tile.meta
+                .is_none_or(|meta| expansions.contains(&meta.expansion))
+        });
+    }
+
+    /// Keeps only `count` tiles of the bag, picked at random with `rng`, as in a short
+    /// game. Every tile is kept if there are no more than `count`.
+    pub fn keep_random<R: Rng + ?Sized>(&mut self, count: usize, rng: &mut R) {
+        if count < self.available_tiles.len() {
+            self.available_tiles.shuffle(rng);
+            self.available_tiles.truncate(count);
+        }
+    }
+}
+
+/// The outcome of `GameTiles::draw_playable_tile`.
+#[derive(Debug)]
+pub struct TileDraw {
+    /// The tile drawn, which fits somewhere on the board, or `None` if the bag ran out.
+    pub tile: Option<Tile>,
+    /// The tiles drawn before, which fit nowhere, in the order they were drawn.
+    pub discarded: Vec<Tile>,
+}
+
+/// A meeple given back to its owner because the feature it stood on was completed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReturnedMeeple {
+    /// The position of the tile the meeple stood on.
+    pub position: Coord,
+    /// The meeple, which is back in the supply of its owner.
+    pub meeple: Meeple,
+}
+
+/// What happened when a turn was scored.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TurnScore {
+    /// The features completed this turn, with the points they were worth.
+    pub scores: Vec<FeatureScore>,
+    /// The meeples taken off the completed features, in the order they were returned.
+    pub returned_meeples: Vec<ReturnedMeeple>,
+}
+
+/// The outcome of a finished game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameResult {
+    /// The final points of each player, in turn order.
+    pub scores: Vec<u32>,
+    /// The final points of each player split by category, in turn order, with the
+    /// points scored when the game ended.
+    pub breakdowns: Vec<ScoreBreakdown>,
+    /// The players ranked first, in turn order. Players still tied after the tie-break
+    /// share the win, which players who resigned cannot take.
+    pub winners: Vec<usize>,
+    /// The standings of the players who did not resign, first place first.
+    pub ranking: Vec<Standing>,
+    /// The number of turns played.
+    pub turns: usize,
+    /// The unfinished features scored when the game ended.
+    pub final_scores: Vec<FeatureScore>,
+}
+
+/// The reasons a step of a turn is refused.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuleError {
+    /// The step is not the one the turn stands at.
+    WrongPhase {
+        /// The step the action belongs to.
+        expected: TurnPhase,
+        /// The step the turn stands at.
+        actual: TurnPhase,
+    },
+    /// The drawn tile cannot be placed there.
+    Placement(PlacementError),
+    /// The placed tile has no such slot for a meeple, or the rules forbid it.
+    InvalidSlot(SlotId),
+    /// A meeple already stands on the feature the slot belongs to, on another tile.
+    FeatureOccupied(SlotId),
+    /// The move places another tile than the one drawn.
+    WrongTile {
+        /// The tile drawn this turn.
+        drawn: TileId,
+        /// The tile named by the move.
+        played: TileId,
+    },
+    /// The active player has no meeple left in their supply.
+    NoMeeplesLeft,
+    /// The player does not take part in the game, or resigned.
+    NotInGame(usize),
+    /// The game is over: nothing can be played anymore.
+    GameOver,
+    /// No move is left to undo.
+    NothingToUndo,
+    /// No undone move is left to redo.
+    NothingToRedo,
+}
+
+impl Display for RuleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RuleError::WrongPhase { expected, actual } => {
+                write!(
+                    f,
+                    "cannot play a {expected:?} step during the {actual:?} step"
+                )
+            }
+            RuleError::Placement(e) => write!(f, "invalid placement: {e}"),
+            RuleError::InvalidSlot(slot) => write!(f, "the tile has no {slot:?} slot"),
+            RuleError::FeatureOccupied(slot) => {
+                write!(
+                    f,
+                    "a meeple already stands on the feature of the {slot:?} slot"
+                )
+            }
+            RuleError::WrongTile { drawn, played } => {
+                write!(f, "cannot place tile {played}, the drawn tile is {drawn}")
+            }
+            RuleError::NoMeeplesLeft => write!(f, "no meeple left in the supply"),
+            RuleError::NotInGame(player) => write!(f, "player {player} is not in the game"),
+            RuleError::GameOver => write!(f, "the game is over"),
+            RuleError::NothingToUndo => write!(f, "no move to undo"),
+            RuleError::NothingToRedo => write!(f, "no move to redo"),
+        }
+    }
+}
+
+impl std::error::Error for RuleError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RuleError::Placement(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// A game in progress: the tile bag, the board, and the meeples left to each player.
+///
+/// Turns are played step by step, in the order kept by a `TurnEngine`: the active
+/// player draws a tile, places it, may put a meeple on it, then the turn is scored
+/// and passes to the next player. Calling a step out of order is refused, leaving
+/// the game untouched.
+///
+/// # Examples
+///
+/// ```
+/// use carcasonne_core::factory::game_factory::GameTilesFactory;
+/// use carcasonne_core::model::game::turn_engine::TurnPhase;
+/// use carcasonne_core::model::game::Game;
+/// use carcasonne_core::model::player::Player;
+///
+/// let mut game = Game::new(GameTilesFactory::build_base_game(), Player::local_players(2));
+/// assert_eq!(game.board().len(), 1);
+///
+/// let tile = game.draw_tile().unwrap().unwrap().clone();
+/// let (position, rotation) = game.board().legal_placements(&tile)[0];
+/// game.place_tile(position, rotation).unwrap();
+/// game.place_meeple(None).unwrap();
+/// game.score().unwrap();
+///
+/// assert_eq!(game.active_player(), 1);
+/// assert_eq!(game.phase(), TurnPhase::DrawTile);
+/// ```
+pub struct Game {
+    tiles: GameTiles,
+    board: Board,
+    players: Vec<Player>,
+    meeples: Vec<usize>,
+    score_board: ScoreBoard,
+    engine: TurnEngine,
+    drawn_tile: Option<Tile>,
+    discarded_tiles: Vec<Tile>,
+    placed_at: Option<Coord>,
+    result: Option<GameResult>,
+    history: History,
+    scorers: Scorers,
+    rng: Box<dyn RngCore + Send + Sync>,
+}
+
+impl Game {
+    /// Starts a game, laying the start tile of `tiles` at the origin.
+    ///
+    /// Tiles are drawn using a random number generator seeded with the seed of `tiles`.
+    /// If it has none, a random seed is picked and kept in `tiles()`, so that the game
+    /// can still be replayed. Features are scored with the modifiers of the expansions
+    /// the tiles are printed in, see `Scorers::for_tiles`.
+    pub fn new(mut tiles: GameTiles, players: Vec<Player>) -> Self {
+        tiles.seed = Some(tiles.seed.unwrap_or_else(rand::random));
+        let rng = Box::new(tiles.rng());
+        Self::with_rng(tiles, players, rng)
+    }
+
+    /// Starts a game drawing tiles with the given random number generator.
+    ///
+    /// The generator is `Send` and `Sync`, so the game can be handed to another thread.
+    pub fn with_rng(
+        mut tiles: GameTiles,
+        players: Vec<Player>,
+        rng: Box<dyn RngCore + Send + Sync>,
+    ) -> Self {
+        let scorers = Scorers::for_tiles(
+            tiles.rules,
+            tiles.available_tiles.iter().chain(&tiles.start_tile),
+        );
+        let board = match tiles.start_tile.take() {
+            Some(start_tile) => Board::with_start_tile(start_tile),
+            None => Board::new(),
+        };
+        let score_board = ScoreBoard::new(players.len()).with_tie_break(tiles.rules.tie_break);
+        Self {
+            tiles,
+            board,
+            meeples: vec![MEEPLES_PER_PLAYER; players.len()],
+            score_board,
+            engine: TurnEngine::new(players.len()),
+            players,
+            drawn_tile: None,
+            discarded_tiles: Vec::new(),
+            placed_at: None,
+            result: None,
+            history: History::default(),
+            scorers,
+            rng,
+        }
+    }
+
+    /// Returns the board.
+    pub fn board(&self) -> &Board {
+        &self.board
+    }
+
+    /// Returns an estimator of what the unfinished features of the board could still
+    /// be worth, given the tiles left in the bag.
+    pub fn estimator(&self) -> Estimator<'_> {
+        Estimator::new(&self.board, &self.tiles.available_tiles, self.tiles.rules)
+    }
+
+    /// Returns the tiles left in the bag.
+    pub fn tiles(&self) -> &GameTiles {
+        &self.tiles
+    }
+
+    /// Returns the rules the game is played with.
+    pub fn rules(&self) -> &RulesConfig {
+        &self.tiles.rules
+    }
+
+    /// Returns the players, in turn order.
+    pub fn players(&self) -> &[Player] {
+        &self.players
+    }
+
+    /// Returns the current step of the turn.
+    pub fn phase(&self) -> TurnPhase {
+        self.engine.phase()
+    }
+
+    /// Returns the index, in turn order, of the player whose turn it is.
+    pub fn active_player(&self) -> usize {
+        self.engine.active_player()
+    }
+
+    /// Returns the number of the current turn, starting from 1.
+    pub fn turn(&self) -> usize {
+        self.engine.turn()
+    }
+
+    /// Returns the tile drawn this turn, until it is placed.
+    pub fn drawn_tile(&self) -> Option<&Tile> {
+        self.drawn_tile.as_ref()
+    }
+
+    /// Returns the tiles discarded since the start of the game, because they fit nowhere.
+    pub fn discarded_tiles(&self) -> &[Tile] {
+        &self.discarded_tiles
+    }
+
+    /// Returns the number of meeples left in the supply of `player`.
+    pub fn meeples_left(&self, player: usize) -> usize {
+        self.meeples.get(player).copied().unwrap_or(0)
+    }
+
+    /// Returns the points scored so far by `player`.
+    pub fn score_of(&self, player: usize) -> u32 {
+        self.score_board.score_of(player)
+    }
+
+    /// Returns the points scored so far by `player`, split by category.
+    pub fn breakdown_of(&self, player: usize) -> ScoreBreakdown {
+        self.score_board.breakdown_of(player)
+    }
+
+    /// Returns the points scored so far by each player, in turn order.
+    pub fn scores(&self) -> &[u32] {
+        self.score_board.scores()
+    }
+
+    /// Returns the points of each player, and how they were scored.
+    pub fn score_board(&self) -> &ScoreBoard {
+        &self.score_board
+    }
+
+    /// Returns the outcome of the game, once it is over.
+    pub fn result(&self) -> Option<&GameResult> {
+        self.result.as_ref()
+    }
+
+    /// Returns the moves applied so far, which can be undone.
+    pub fn history(&self) -> &History {
+        &self.history
+    }
+
+    /// Scores the features with `scorers` rather than the ones following the rules of
+    /// the tiles.
+    pub fn with_scorers(mut self, scorers: Scorers) -> Self {
+        self.scorers = scorers;
+        self
+    }
+
+    /// Times the game: each player gets a clock following `control`.
+    pub fn with_time_control(mut self, control: TimeControl) -> Self {
+        self.engine = self.engine.clone().with_clocks(control);
+        self
+    }
+
+    /// Returns the time `player` has left, if the game is timed.
+    pub fn remaining_time(&self, player: usize) -> Option<Duration> {
+        self.engine.remaining_time(player)
+    }
+
+    /// Returns the slots of the tile placed at `position` where a meeple may be put.
+    ///
+    /// A meeple may not join a town, road or field where another meeple already
+    /// stands, even on a distant tile, so slots whose feature is occupied are left
+    /// out. The extension of the tile is always free, as it belongs to a single tile.
+    /// Fields are left out as well when the rules are played without farmers.
+    /// Returns no slot if no tile is placed at `position`.
+    pub fn legal_meeple_placements(&self, position: Coord) -> Vec<SlotId> {
+        let Some(placed_tile) = self.board.get(position) else {
+            return Vec::new();
+        };
+        placed_tile
+            .tile
+            .placement_slots()
+            .into_iter()
+            .filter(|slot| match slot {
+                SlotId::Feature(index) => {
+                    (self.tiles.rules.farmers
+                        || placed_tile.tile.tile_features[*index].kind() != FeatureKind::Field)
+                        && self
+                            .board
+                            .meeples_on(FeatureRef::new(position, *index))
+                            .is_empty()
+                }
+                SlotId::Extension => placed_tile.meeple.is_none(),
+            })
+            .collect()
+    }
+
+    /// Checks whether the active player may play `player_move`, without playing it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the `RuleError` telling why the move is refused, the same `apply`
+    /// would return.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use carcasonne_core::factory::game_factory::GameTilesFactory;
+    /// use carcasonne_core::model::board::{Coord, PlacementError};
+    /// use carcasonne_core::model::game::moves::Move;
+    /// use carcasonne_core::model::game::{Game, RuleError};
+    /// use carcasonne_core::model::player::Player;
+    /// use carcasonne_core::model::tile::Rotation;
+    ///
+    /// let mut game = Game::new(GameTilesFactory::build_base_game(), Player::local_players(2));
+    /// game.advance();
+    /// let tile = game.drawn_tile().unwrap().id;
+    ///
+    /// let far_away = Move::PlaceTile { tile, position: Coord::new(5, 5), rotation: Rotation::Deg0 };
+    /// assert_eq!(
+    ///     game.validate(far_away),
+    ///     Err(RuleError::Placement(PlacementError::NotAdjacent))
+    /// );
+    /// ```
+    pub fn validate(&self, player_move: Move) -> Result<(), RuleError> {
+        match player_move {
+            Move::PlaceTile {
+                tile,
+                position,
+                rotation,
+            } => {
+                self.engine.expect(TurnPhase::PlaceTile)?;
+                let drawn = self
+                    .drawn_tile
+                    .as_ref()
+                    .expect("a tile is drawn before being placed");
+                if drawn.id != tile {
+                    return Err(RuleError::WrongTile {
+                        drawn: drawn.id,
+                        played: tile,
+                    });
+                }
+                self.board
+                    .check_placement(&PlacedTile::new(drawn.clone(), rotation, position))
+                    .map_err(RuleError::Placement)
+            }
+            Move::PlaceMeeple(slot) => {
+                self.engine.expect(TurnPhase::PlaceMeeple)?;
+                self.check_meeple(slot)
+            }
+            Move::SkipMeeple => self.engine.expect(TurnPhase::PlaceMeeple),
+        }
+    }
+
+    /// Plays a decision of the active player, then the steps following it that need
+    /// no decision, and returns what happened.
+    ///
+    /// This is the single entry point front ends and AI players go through: once the
+    /// meeple step is over, the turn is scored and the next player draws a tile, so the
+    /// game always stands at a decision when `apply` returns, unless it is over.
+    ///
+    /// The move is recorded in the history of the game, to be undone with `undo`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the `RuleError` of the refused step, leaving the game untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use carcasonne_core::factory::game_factory::GameTilesFactory;
+    /// use carcasonne_core::model::game::event::GameEvent;
+    /// use carcasonne_core::model::game::moves::Move;
+    /// use carcasonne_core::model::game::Game;
+    /// use carcasonne_core::model::player::Player;
+    ///
+    /// let mut game = Game::new(GameTilesFactory::build_base_game(), Player::local_players(2));
+    /// game.advance();
+    ///
+    /// let tile = game.drawn_tile().unwrap().clone();
+    /// let (position, rotation) = game.board().legal_placements(&tile)[0];
+    /// game.apply(Move::PlaceTile { tile: tile.id, position, rotation }).unwrap();
+    /// let events = game.apply(Move::SkipMeeple).unwrap();
+    ///
+    /// assert!(matches!(events.last(), Some(GameEvent::TileDrawn { player: 1, .. })));
+    /// ```
+    pub fn apply(&mut self, player_move: Move) -> Result<Vec<GameEvent>, RuleError> {
+        let before = Counters::of(self);
+        let events = self.play(player_move)?;
+        self.history.record(Command::new(
+            player_move,
+            events.clone(),
+            self.tiles.available_tiles.len(),
+            before,
+            Counters::of(self),
+        ));
+        Ok(events)
+    }
+
+    /// Undoes the last move applied with `apply`, along with the scoring and the draws
+    /// that followed it, and returns it.
+    ///
+    /// Moves can be undone one after the other back to the start of the game, and
+    /// redone with `redo` until another move is applied. Only the moves applied with
+    /// `apply` are recorded: moves played step by step during a turn are taken back
+    /// with `take_back` instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RuleError::NothingToUndo` if no move is left to undo.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use carcasonne_core::factory::game_factory::GameTilesFactory;
+    /// use carcasonne_core::model::game::moves::Move;
+    /// use carcasonne_core::model::game::Game;
+    /// use carcasonne_core::model::player::Player;
+    ///
+    /// let mut game = Game::new(GameTilesFactory::build_base_game(), Player::local_players(2));
+    /// game.advance();
+    /// let tile = game.drawn_tile().unwrap().clone();
+    /// let (position, rotation) = game.board().legal_placements(&tile)[0];
+    /// game.apply(Move::PlaceTile { tile: tile.id, position, rotation }).unwrap();
+    /// game.apply(Move::SkipMeeple).unwrap();
+    /// let next_tile = game.drawn_tile().unwrap().id;
+    ///
+    /// assert_eq!(game.undo(), Ok(Move::SkipMeeple));
+    /// game.undo().unwrap();
+    /// assert_eq!(game.board().len(), 1);
+    /// assert_eq!(game.active_player(), 0);
+    ///
+    /// game.redo().unwrap();
+    /// game.redo().unwrap();
+    /// assert_eq!(game.drawn_tile().unwrap().id, next_tile);
+    /// ```
+    pub fn undo(&mut self) -> Result<Move, RuleError> {
+        let command = self
+            .history
+            .undo()
+            .cloned()
+            .ok_or(RuleError::NothingToUndo)?;
+        command.undo(self);
+        Ok(command.player_move())
+    }
+
+    /// Applies again the last move undone with `undo`, and returns it.
+    ///
+    /// The move is played as it was the first time: the same tiles are drawn after it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RuleError::NothingToRedo` if no move is left to redo.
+    pub fn redo(&mut self) -> Result<Move, RuleError> {
+        let command = self
+            .history
+            .redo()
+            .cloned()
+            .ok_or(RuleError::NothingToRedo)?;
+        command.redo(self);
+        Ok(command.player_move())
+    }
+
+    /// Plays `player_move`, then the steps following it that need no decision.
+    fn play(&mut self, player_move: Move) -> Result<Vec<GameEvent>, RuleError> {
+        self.validate(player_move)?;
+        let player = self.active_player();
+        let mut events = Vec::new();
+        match player_move {
+            Move::PlaceTile {
+                tile,
+                position,
+                rotation,
+            } => {
+                self.place_tile(position, rotation)?;
+                events.push(GameEvent::TilePlaced {
+                    player,
+                    tile,
+                    position,
+                    rotation,
+                });
+            }
+            Move::PlaceMeeple(slot) => {
+                self.place_meeple(Some(slot))?;
+                events.push(GameEvent::MeeplePlaced {
+                    player,
+                    position: self.placed_at.expect("a tile is placed before its meeple"),
+                    slot,
+                });
+            }
+            Move::SkipMeeple => {
+                self.skip_meeple()?;
+                events.push(GameEvent::MeepleSkipped { player });
+            }
+        }
+        events.extend(self.advance());
+        Ok(events)
+    }
+
+    /// Plays the steps needing no decision, scoring the turn and drawing the next
+    /// tile, until a player has to decide or the game is over. Returns what happened.
+    ///
+    /// Called by `apply` after each move, and once when the game begins, to draw the
+    /// first tile. Nothing happens while a decision is awaited.
+    pub fn advance(&mut self) -> Vec<GameEvent> {
+        let mut events = Vec::new();
+        loop {
+            match self.phase() {
+                TurnPhase::Score => events.extend(self.score_turn()),
+                TurnPhase::DrawTile => {
+                    let player = self.active_player();
+                    let discarded_before = self.discarded_tiles.len();
+                    let scored_before = self.score_board.history().len();
+                    let drawn = self
+                        .draw_tile()
+                        .expect("a tile is to be drawn")
+                        .map(|tile| tile.id);
+                    events.extend(
+                        self.discarded_tiles[discarded_before..]
+                            .iter()
+                            .map(|tile| GameEvent::TileDiscarded { tile: tile.id }),
+                    );
+                    events.extend(self.points_scored_since(scored_before));
+                    events.push(match drawn {
+                        Some(tile) => GameEvent::TileDrawn { player, tile },
+                        None => GameEvent::GameOver(self.result.clone().expect("the game is over")),
+                    });
+                }
+                TurnPhase::PlaceTile | TurnPhase::PlaceMeeple | TurnPhase::GameOver => {
+                    return events;
+                }
+            }
+        }
+    }
+
+    /// Makes `player` resign, and returns what happened.
+    ///
+    /// The player leaves the rotation and their meeples are taken off the board, from
+    /// the top-left. If it was their turn, a drawn tile goes back to the bag and the
+    /// turn passes to the next player, while a tile already placed is still scored.
+    /// When a single player is left, the game ends and is scored as if the bag had
+    /// run out; a player who resigned keeps their points but cannot win. A
+    /// resignation cannot be undone: the history of the game is cleared.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RuleError::GameOver` once the game is over, and
+    /// `RuleError::NotInGame` if the player is unknown or already resigned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use carcasonne_core::factory::game_factory::GameTilesFactory;
+    /// use carcasonne_core::model::game::event::GameEvent;
+    /// use carcasonne_core::model::game::Game;
+    /// use carcasonne_core::model::player::Player;
+    ///
+    /// let mut game = Game::new(GameTilesFactory::build_base_game(), Player::local_players(2));
+    /// game.advance();
+    ///
+    /// let events = game.resign(0).unwrap();
+    /// assert_eq!(events[0], GameEvent::PlayerResigned { player: 0 });
+    /// assert_eq!(game.result().unwrap().winners, vec![1]);
+    /// ```
+    pub fn resign(&mut self, player: usize) -> Result<Vec<GameEvent>, RuleError> {
+        if self.phase() == TurnPhase::GameOver {
+            return Err(RuleError::GameOver);
+        }
+        if player >= self.players.len() || self.engine.has_resigned(player) {
+            return Err(RuleError::NotInGame(player));
+        }
+        let mut events = vec![GameEvent::PlayerResigned { player }];
+        self.history.clear();
+        if player == self.active_player() {
+            self.put_back_drawn_tile();
+        }
+
+        let mut meeples: Vec<ReturnedMeeple> = self
+            .board
+            .iter()
+            .filter_map(|(position, placed_tile)| {
+                let meeple = placed_tile
+                    .meeple
+                    .filter(|meeple| meeple.player == player)?;
+                Some(ReturnedMeeple { position, meeple })
+            })
+            .collect();
+        meeples.sort_by_key(|returned| (returned.position.y, returned.position.x));
+        for returned in &meeples {
+            self.board.set_meeple(returned.position, None);
+            self.meeples[player] += 1;
+        }
+        events.extend(meeples.into_iter().map(GameEvent::MeepleReturned));
+
+        self.engine.resign(player);
+        if self.engine.players_left() <= 1 {
+            // The tile placed this turn is scored before the game ends, the last
+            // player giving up their meeple if they were still to place it.
+            if self.phase() == TurnPhase::PlaceMeeple {
+                events.push(GameEvent::MeepleSkipped {
+                    player: self.active_player(),
+                });
+                self.engine.skip_turn();
+            }
+            if self.phase() == TurnPhase::Score {
+                events.extend(self.score_turn());
+            }
+            let scored_before = self.score_board.history().len();
+            self.finish();
+            events.extend(self.points_scored_since(scored_before));
+            events.push(GameEvent::GameOver(
+                self.result.clone().expect("the game is over"),
+            ));
+        } else {
+            events.extend(self.advance());
+        }
+        Ok(events)
+    }
+
+    /// Counts `elapsed` against the clock of the active player, while they have a
+    /// decision to make, and returns what happened.
+    ///
+    /// Nothing happens in an untimed game. When the clock runs out, the player either
+    /// resigns or loses the rest of their turn, as the time control says, and the game
+    /// moves on. A timeout cannot be undone: the history of the game is cleared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use carcasonne_core::factory::game_factory::GameTilesFactory;
+    /// use carcasonne_core::model::game::clock::TimeControl;
+    /// use carcasonne_core::model::game::event::GameEvent;
+    /// use carcasonne_core::model::game::Game;
+    /// use carcasonne_core::model::player::Player;
+    /// use std::time::Duration;
+    ///
+    /// let control = TimeControl::new(Duration::from_secs(60), Duration::from_secs(5));
+    /// let mut game = Game::new(GameTilesFactory::build_base_game(), Player::local_players(2))
+    ///     .with_time_control(control);
+    /// game.advance();
+    ///
+    /// assert!(game.spend_time(Duration::from_secs(20)).is_empty());
+    /// assert_eq!(game.remaining_time(0), Some(Duration::from_secs(40)));
+    ///
+    /// let events = game.spend_time(Duration::from_secs(50));
+    /// assert_eq!(events[0], GameEvent::TimedOut { player: 0 });
+    /// assert_eq!(game.active_player(), 1);
+    /// assert_eq!(game.remaining_time(0), Some(Duration::from_secs(5)));
+    /// ```
+    pub fn spend_time(&mut self, elapsed: Duration) -> Vec<GameEvent> {
+        if !self.engine.spend_time(elapsed) {
+            return Vec::new();
+        }
+        let player = self.active_player();
+        let mut events = vec![GameEvent::TimedOut { player }];
+        let timeout = self
+            .engine
+            .clocks()
+            .map(|clocks| clocks.control().timeout)
+            .unwrap_or_default();
+        match timeout {
+            Timeout::Forfeit => {
+                events.extend(
+                    self.resign(player)
+                        .expect("the active player is in the game"),
+                );
+            }
+            Timeout::SkipTurn => {
+                self.history.clear();
+                if self.phase() == TurnPhase::PlaceMeeple {
+                    events.push(GameEvent::MeepleSkipped { player });
+                }
+                self.put_back_drawn_tile();
+                self.engine.skip_turn();
+                events.extend(self.advance());
+            }
+        }
+        events
+    }
+
+    /// Puts the tile drawn by the active player back in the bag, if they did not place
+    /// it yet.
+    fn put_back_drawn_tile(&mut self) {
+        if self.phase() == TurnPhase::PlaceTile {
+            let tile = self
+                .drawn_tile
+                .take()
+                .expect("a tile is drawn before being placed");
+            self.tiles.available_tiles.push(tile);
+        }
+    }
+
+    /// Draws a tile for the active player, and returns it.
+    ///
+    /// Tiles that cannot be placed anywhere are discarded, and another one is drawn.
+    /// Returns `None` when the bag is empty: the unfinished features and the farmers
+    /// are then scored, and the game ends.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RuleError::WrongPhase` unless a tile is to be drawn.
+    pub fn draw_tile(&mut self) -> Result<Option<&Tile>, RuleError> {
+        self.engine.expect(TurnPhase::DrawTile)?;
+        let draw = self.tiles.draw_playable_tile(&self.board, &mut self.rng);
+        self.discarded_tiles.extend(draw.discarded);
+        match draw.tile {
+            Some(tile) => {
+                self.engine.advance();
+                Ok(Some(self.drawn_tile.insert(tile)))
+            }
+            None => {
+                self.finish();
+                Ok(None)
+            }
+        }
+    }
+
+    /// Places the drawn tile at `position`, turned by `rotation`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RuleError::WrongPhase` unless a tile is to be placed, and
+    /// `RuleError::Placement` if the rules forbid the placement.
+    pub fn place_tile(&mut self, position: Coord, rotation: Rotation) -> Result<(), RuleError> {
+        self.engine.expect(TurnPhase::PlaceTile)?;
+        let tile = self
+            .drawn_tile
+            .clone()
+            .expect("a tile is drawn before being placed");
+        let id = tile.id;
+        self.board
+            .place_tile(position, tile, rotation)
+            .map_err(RuleError::Placement)?;
+        self.drawn_tile = None;
+        self.placed_at = Some(position);
+        self.engine.push_pending(Move::PlaceTile {
+            tile: id,
+            position,
+            rotation,
+        });
+        self.engine.advance();
+        Ok(())
+    }
+
+    /// Puts a meeple of the active player on a slot of the tile just placed, or none
+    /// with `None`, which is the same as `skip_meeple`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RuleError::WrongPhase` unless a meeple may be placed,
+    /// `RuleError::InvalidSlot` if the tile has no such slot, or it is a field and the
+    /// game is played without farmers,
+    /// `RuleError::FeatureOccupied` if the feature of the slot already has a meeple,
+    /// and `RuleError::NoMeeplesLeft` if the player has no meeple to place.
+    pub fn place_meeple(&mut self, slot: Option<SlotId>) -> Result<(), RuleError> {
+        let Some(slot) = slot else {
+            return self.skip_meeple();
+        };
+        self.engine.expect(TurnPhase::PlaceMeeple)?;
+        self.check_meeple(slot)?;
+        let position = self.placed_at.expect("a tile is placed before its meeple");
+        let player = self.active_player();
+        self.board
+            .set_meeple(position, Some(Meeple::new(player, slot)));
+        self.meeples[player] -= 1;
+        self.engine.push_pending(Move::PlaceMeeple(slot));
+        self.engine.advance();
+        Ok(())
+    }
+
+    /// Keeps the meeples of the active player in their supply this turn, moving on to
+    /// scoring.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RuleError::WrongPhase` unless a meeple may be placed.
+    pub fn skip_meeple(&mut self) -> Result<(), RuleError> {
+        self.engine.skip_meeple()?;
+        self.engine.push_pending(Move::SkipMeeple);
+        Ok(())
+    }
+
+    /// Takes back the last move the active player played this turn, and returns it.
+    ///
+    /// Until the turn is scored, the tile and the meeple placed are only tentative:
+    /// an undone meeple goes back to the supply, and an undone tile is taken off the
+    /// board, to be placed again. Scoring the turn confirms it. As the history no
+    /// longer matches the game afterwards, it is cleared.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RuleError::NothingToUndo` if no move of the turn is left to take back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use carcasonne_core::factory::game_factory::GameTilesFactory;
+    /// use carcasonne_core::model::game::turn_engine::TurnPhase;
+    /// use carcasonne_core::model::game::Game;
+    /// use carcasonne_core::model::player::Player;
+    ///
+    /// let mut game = Game::new(GameTilesFactory::build_base_game(), Player::local_players(2));
+    /// let tile = game.draw_tile().unwrap().unwrap().clone();
+    /// let (position, rotation) = game.board().legal_placements(&tile)[0];
+    /// game.place_tile(position, rotation).unwrap();
+    ///
+    /// game.take_back().unwrap();
+    /// assert_eq!(game.phase(), TurnPhase::PlaceTile);
+    /// assert_eq!(game.drawn_tile().unwrap().id, tile.id);
+    /// assert_eq!(game.board().len(), 1);
+    /// ```
+    pub fn take_back(&mut self) -> Result<Move, RuleError> {
+        let player_move = self.engine.undo().ok_or(RuleError::NothingToUndo)?;
+        self.history.clear();
+        match player_move {
+            Move::PlaceTile { .. } => {
+                let removed = self
+                    .board
+                    .remove_last()
+                    .expect("the tile placed this turn is the last one");
+                self.drawn_tile = Some(removed.tile);
+                self.placed_at = None;
+            }
+            Move::PlaceMeeple(_) => {
+                let position = self.placed_at.expect("a tile is placed before its meeple");
+                let player = self.active_player();
+                self.board.set_meeple(position, None);
+                self.meeples[player] += 1;
+            }
+            Move::SkipMeeple => {}
+        }
+        Ok(player_move)
+    }
+
+    /// Checks that a meeple of the active player may stand on `slot` of the tile just
+    /// placed, assuming the turn stands at the meeple step.
+    fn check_meeple(&self, slot: SlotId) -> Result<(), RuleError> {
+        let position = self.placed_at.expect("a tile is placed before its meeple");
+        let placed_tile = self.board.get(position).expect("the tile was placed");
+        let farmer = match slot {
+            SlotId::Feature(index) => placed_tile
+                .tile
+                .tile_features
+                .get(index)
+                .is_some_and(|feature| feature.kind() == FeatureKind::Field),
+            SlotId::Extension => false,
+        };
+        if !placed_tile.tile.placement_slots().contains(&slot)
+            || (farmer && !self.tiles.rules.farmers)
+        {
+            return Err(RuleError::InvalidSlot(slot));
+        }
+        if !self.legal_meeple_placements(position).contains(&slot) {
+            return Err(RuleError::FeatureOccupied(slot));
+        }
+        if self.meeples_left(self.active_player()) == 0 {
+            return Err(RuleError::NoMeeplesLeft);
+        }
+        Ok(())
+    }
+
+    /// Scores the turn and passes it to the next player.
+    ///
+    /// The features completed by the tile placed this turn are scored, then the
+    /// meeples standing on them go back to their owners, and are returned in the
+    /// order they are taken off the board.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RuleError::WrongPhase` unless the turn is to be scored.
+    pub fn score(&mut self) -> Result<TurnScore, RuleError> {
+        self.engine.expect(TurnPhase::Score)?;
+        let mut turn_score = TurnScore::default();
+        if let Some(position) = self.placed_at.take() {
+            let scored = self.scorers.audit_placement(&self.board, position);
+            scored.iter().for_each(|scored| self.award(scored));
+            turn_score.scores = scored.into_iter().map(|scored| scored.score).collect();
+            turn_score.returned_meeples = self.return_meeples(position);
+        }
+        self.engine.advance();
+        Ok(turn_score)
+    }
+
+    /// Scores the features left unfinished, and ends the game.
+    fn finish(&mut self) {
+        self.score_board.end_game();
+        let mut scored = self.scorers.audit_game_end(&self.board);
+        scored.extend(self.scorers.audit_farmers(&self.board));
+        scored.iter().for_each(|scored| self.award(scored));
+        let final_scores: Vec<FeatureScore> =
+            scored.into_iter().map(|scored| scored.score).collect();
+
+        for player in 0..self.players.len() {
+            let stranded = MEEPLES_PER_PLAYER.saturating_sub(self.meeples_left(player));
+            self.score_board.set_stranded_meeples(player, stranded);
+        }
+
+        let contenders: Vec<usize> = (0..self.players.len())
+            .filter(|player| !self.engine.has_resigned(*player))
+            .collect();
+        let ranking = self.score_board.ranking_of(&contenders);
+        self.result = Some(GameResult {
+            scores: self.scores().to_vec(),
+            breakdowns: self.score_board.breakdowns().to_vec(),
+            winners: ranking
+                .iter()
+                .filter(|standing| standing.rank == 1)
+                .map(|standing| standing.player)
+                .collect(),
+            ranking,
+            turns: self.engine.turn() - 1,
+            final_scores,
+        });
+        self.engine.end_game();
+    }
+
+    /// Scores the turn, and reports each scored feature followed by the points it gave,
+    /// then the meeples returned.
+    fn score_turn(&mut self) -> Vec<GameEvent> {
+        let scored_before = self.score_board.history().len();
+        let turn_score = self.score().expect("the turn is to be scored");
+        let mut points = self.points_scored_since(scored_before).into_iter();
+        let mut events = Vec::new();
+        for score in turn_score.scores {
+            let players = score.players.len();
+            events.push(GameEvent::FeatureScored(score));
+            events.extend(points.by_ref().take(players));
+        }
+        events.extend(
+            turn_score
+                .returned_meeples
+                .into_iter()
+                .map(GameEvent::MeepleReturned),
+        );
+        events
+    }
+
+    /// Reports the points given since the history of the score board held
+    /// `scored_before` entries.
+    fn points_scored_since(&self, scored_before: usize) -> Vec<GameEvent> {
+        self.score_board.history()[scored_before..]
+            .iter()
+            .map(|entry| {
+                GameEvent::PointsScored(ScoreEvent {
+                    player: entry.player,
+                    points: entry.points,
+                    feature: entry.reason,
+                    turn: entry.turn,
+                })
+            })
+            .collect()
+    }
+
+    /// Gives the points of `scored` to the players it goes to, keeping the tiles of
+    /// the feature in the audit log of the score board.
+    fn award(&mut self, scored: &ScoredFeature) {
+        self.score_board.set_turn(self.engine.turn());
+        self.score_board.score_feature(scored);
+    }
+
+    /// Takes off the board the meeples standing on the features completed by the tile
+    /// placed at `position`, and puts them back in the supplies of their owners.
+    fn return_meeples(&mut self, position: Coord) -> Vec<ReturnedMeeple> {
+        let mut returned = Vec::new();
+        for feature in self.board.completed_features_after(position) {
+            for member in feature.members {
+                let meeple = self.board.get(member.position).and_then(|placed_tile| {
+                    placed_tile
+                        .meeple
+                        .filter(|meeple| meeple.slot == SlotId::Feature(member.index))
+                });
+                if let Some(meeple) = meeple {
+                    returned.push(ReturnedMeeple {
+                        position: member.position,
+                        meeple,
+                    });
+                }
+            }
+        }
+        let abbeys = match self.tiles.rules.abbey_completion {
+            AbbeyCompletion::Surrounded => self.board.completed_abbeys_after(position),
+            AbbeyCompletion::GameEndOnly => Vec::new(),
+        };
+        for abbey in abbeys {
+            if let Some(meeple) = abbey.meeple {
+                returned.push(ReturnedMeeple {
+                    position: abbey.position,
+                    meeple,
+                });
+            }
+        }
+
+        for returned_meeple in &returned {
+            self.board.set_meeple(returned_meeple.position, None);
+            self.meeples[returned_meeple.meeple.player] += 1;
+        }
+        returned
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::model::tile::Tile;
+    use crate::builder::tile_builder::TileBuilder;
+    use crate::factory::game_factory::GameTilesFactory;
+    use crate::model::board::Direction;
+    use crate::model::rules::TieBreak;
+    use crate::model::tile_feature::Edge::{East, North, South, West};
+    use crate::model::tile_meta::TileMeta;
+    use crate::scoring::ScoreCategory;
+    use crate::scoring::scorer::{Feature, Scorer, TownScorer};
+    use rand::rng;
 
     fn dummy_tile() -> Tile {
         Tile {
+            id: TileId('Z'),
             tile_features: vec![],
             tile_extension: None,
+            meta: None,
         }
     }
 
@@ -48,9 +1258,12 @@ mod tests {
     fn test_select_random_tile_returns_tile() {
         let mut game_tiles = GameTiles {
             available_tiles: vec![dummy_tile()],
+            start_tile: None,
+            seed: None,
+            rules: RulesConfig::default(),
         };
 
-        let tile = game_tiles.select_random_tile();
+        let tile = game_tiles.select_random_tile(&mut rng());
         assert!(tile.is_some(), "Expected to get a tile");
         assert_eq!(
             game_tiles.available_tiles.len(),
@@ -63,8 +1276,11 @@ mod tests {
     fn test_select_random_tile_from_empty_deck_returns_none() {
         let mut game_tiles = GameTiles {
             available_tiles: vec![],
+            start_tile: None,
+            seed: None,
+            rules: RulesConfig::default(),
         };
-        let tile = game_tiles.select_random_tile();
+        let tile = game_tiles.select_random_tile(&mut rng());
         assert!(
             tile.is_none(),
             "Expected None when drawing from the empty deck"
@@ -81,10 +1297,13 @@ mod tests {
                 dummy_tile(),
                 dummy_tile(),
             ],
+            start_tile: None,
+            seed: None,
+            rules: RulesConfig::default(),
         };
 
         let mut drawn = vec![];
-        while let Some(tile) = game_tiles.select_random_tile() {
+        while let Some(tile) = game_tiles.select_random_tile(&mut rng()) {
             drawn.push(tile);
         }
 
@@ -100,18 +1319,935 @@ mod tests {
         let tiles: Vec<Tile> = vec![dummy_tile(), dummy_tile(), dummy_tile()];
         let mut game_tiles_1 = GameTiles {
             available_tiles: tiles.clone(),
+            start_tile: None,
+            seed: None,
+            rules: RulesConfig::default(),
         };
         let mut game_tiles_2 = GameTiles {
             available_tiles: tiles.clone(),
+            start_tile: None,
+            seed: None,
+            rules: RulesConfig::default(),
         };
 
         // Shuffle both
-        game_tiles_1.select_random_tile(); // first shuffle (done implicitly)
-        game_tiles_2.select_random_tile(); // second shuffle
+        game_tiles_1.select_random_tile(&mut rng()); // first shuffle (done implicitly)
+        game_tiles_2.select_random_tile(&mut rng()); // second shuffle
 
         // We can't guarantee difference, but we can at least check that the deck was modified
         // (it shrinks and is in a different order than initial)
         assert!(game_tiles_1.available_tiles.len() < 3);
         assert!(game_tiles_2.available_tiles.len() < 3);
     }
+
+    #[test]
+    fn test_filter_by_expansion() {
+        let from = |expansion| Tile {
+            meta: Some(TileMeta {
+                expansion,
+                copies: 1,
+                letter: 'Z',
+            }),
+            ..dummy_tile()
+        };
+        let mut game_tiles = GameTiles {
+            available_tiles: vec![
+                from(ExpansionId::Base),
+                from(ExpansionId::InnsAndCathedrals),
+                from(ExpansionId::InnsAndCathedrals),
+                dummy_tile(),
+            ],
+            start_tile: None,
+            seed: None,
+            rules: RulesConfig::default(),
+        };
+        assert_eq!(game_tiles.remaining_from(ExpansionId::InnsAndCathedrals), 2);
+
+        game_tiles.retain_expansions(&[ExpansionId::Base]);
+        assert_eq!(game_tiles.available_tiles.len(), 2);
+        assert_eq!(game_tiles.remaining_from(ExpansionId::Base), 1);
+        assert_eq!(game_tiles.remaining_from(ExpansionId::InnsAndCathedrals), 0);
+    }
+
+    fn two_player_game(tiles: Vec<Tile>) -> Game {
+        two_player_game_with_rules(tiles, RulesConfig::default())
+    }
+
+    fn two_player_game_with_rules(tiles: Vec<Tile>, rules: RulesConfig) -> Game {
+        let start_tile = TileBuilder::new(TileId('D'))
+            .add_town(vec![North])
+            .add_road(vec![West, East])
+            .build();
+        Game::with_rng(
+            GameTiles {
+                available_tiles: tiles,
+                start_tile: Some(start_tile),
+                seed: None,
+                rules,
+            },
+            Player::local_players(2),
+            Box::new(StdRng::seed_from_u64(3)),
+        )
+    }
+
+    fn road() -> Tile {
+        TileBuilder::new(TileId('U'))
+            .add_road(vec![West, East])
+            .build()
+    }
+
+    #[test]
+    fn test_game_plays_turns_in_order() {
+        let mut game = two_player_game(vec![road(), road()]);
+        assert_eq!(game.board().len(), 1);
+        assert!(game.tiles().start_tile.is_none());
+
+        for player in [0, 1] {
+            assert_eq!(game.active_player(), player);
+            assert_eq!(game.draw_tile().unwrap().unwrap().id, TileId('U'));
+            assert_eq!(game.phase(), TurnPhase::PlaceTile);
+            let position = Coord::new(player as i32 + 1, 0);
+            game.place_tile(position, Rotation::Deg0).unwrap();
+            assert!(game.drawn_tile().is_none());
+            // Both tiles extend the same road, which only the first player may claim.
+            let slot = (player == 0).then_some(SlotId::Feature(0));
+            game.place_meeple(slot).unwrap();
+            game.score().unwrap();
+            assert_eq!(game.meeples_left(player), MEEPLES_PER_PLAYER - 1 + player);
+        }
+
+        assert_eq!(game.turn(), 3);
+        assert!(game.draw_tile().unwrap().is_none());
+        assert_eq!(game.phase(), TurnPhase::GameOver);
+    }
+
+    #[test]
+    fn test_game_refuses_steps_out_of_order() {
+        let mut game = two_player_game(vec![road()]);
+        assert_eq!(
+            game.place_tile(Coord::new(1, 0), Rotation::Deg0),
+            Err(RuleError::WrongPhase {
+                expected: TurnPhase::PlaceTile,
+                actual: TurnPhase::DrawTile,
+            })
+        );
+        game.draw_tile().unwrap();
+        assert!(matches!(
+            game.draw_tile(),
+            Err(RuleError::WrongPhase { .. })
+        ));
+
+        // A refused placement keeps the drawn tile.
+        assert_eq!(
+            game.place_tile(Coord::new(0, -1), Rotation::Deg0),
+            Err(RuleError::Placement(PlacementError::EdgeMismatch {
+                direction: Direction::South,
+                expected: FeatureKind::Town,
+                found: FeatureKind::Field,
+            }))
+        );
+        assert!(game.drawn_tile().is_some());
+        game.place_tile(Coord::new(1, 0), Rotation::Deg0).unwrap();
+
+        assert_eq!(
+            game.place_meeple(Some(SlotId::Extension)),
+            Err(RuleError::InvalidSlot(SlotId::Extension))
+        );
+        assert_eq!(game.phase(), TurnPhase::PlaceMeeple);
+        game.place_meeple(None).unwrap();
+        assert_eq!(game.meeples_left(0), MEEPLES_PER_PLAYER);
+    }
+
+    #[test]
+    fn test_unplaceable_tiles_are_discarded() {
+        // Only the road fits next to the start tile; two towns on all sides never do.
+        let closed = TileBuilder::new(TileId('Z'))
+            .add_town(vec![North, East, South, West])
+            .build();
+        let mut game_tiles = GameTiles {
+            available_tiles: vec![road(), closed.clone(), closed.clone()],
+            start_tile: None,
+            seed: None,
+            rules: RulesConfig::default(),
+        };
+        let board = Board::with_start_tile(road());
+
+        let mut rng = StdRng::seed_from_u64(1);
+        let mut drawn = 0;
+        let mut discarded = 0;
+        loop {
+            let draw = game_tiles.draw_playable_tile(&board, &mut rng);
+            discarded += draw.discarded.len();
+            assert!(draw.discarded.iter().all(|tile| tile.id == TileId('Z')));
+            match draw.tile {
+                Some(tile) => {
+                    assert_eq!(tile.id, TileId('U'));
+                    drawn += 1;
+                }
+                None => break,
+            }
+        }
+        assert_eq!((drawn, discarded), (1, 2));
+        assert!(game_tiles.available_tiles.is_empty());
+    }
+
+    #[test]
+    fn test_game_discards_unplaceable_tiles() {
+        let closed = TileBuilder::new(TileId('Z'))
+            .add_town(vec![North, East, South, West])
+            .build();
+        let mut game = Game::with_rng(
+            GameTiles {
+                available_tiles: vec![road()],
+                start_tile: Some(closed),
+                seed: None,
+                rules: RulesConfig::default(),
+            },
+            Player::local_players(2),
+            Box::new(StdRng::seed_from_u64(3)),
+        );
+        assert!(game.draw_tile().unwrap().is_none());
+        assert_eq!(game.discarded_tiles().len(), 1);
+        assert_eq!(game.phase(), TurnPhase::GameOver);
+    }
+
+    #[test]
+    fn test_meeple_cannot_join_occupied_feature() {
+        let mut game = two_player_game(vec![road(), road()]);
+
+        // The first player claims the road going east from the start tile.
+        game.draw_tile().unwrap();
+        game.place_tile(Coord::new(1, 0), Rotation::Deg0).unwrap();
+        assert_eq!(
+            game.legal_meeple_placements(Coord::new(1, 0)),
+            vec![SlotId::Feature(0)]
+        );
+        game.place_meeple(Some(SlotId::Feature(0))).unwrap();
+        game.score().unwrap();
+
+        // Continuing the road, the second player may not join it.
+        game.draw_tile().unwrap();
+        game.place_tile(Coord::new(2, 0), Rotation::Deg0).unwrap();
+        assert!(game.legal_meeple_placements(Coord::new(2, 0)).is_empty());
+        assert!(
+            game.legal_meeple_placements(Coord::ORIGIN)
+                .contains(&SlotId::Feature(0))
+        );
+        assert_eq!(
+            game.place_meeple(Some(SlotId::Feature(0))),
+            Err(RuleError::FeatureOccupied(SlotId::Feature(0)))
+        );
+        game.place_meeple(None).unwrap();
+        assert!(game.legal_meeple_placements(Coord::new(5, 5)).is_empty());
+    }
+
+    #[test]
+    fn test_no_farmer_slots_without_farmers() {
+        let mut tiles = GameTilesFactory::build_base_game();
+        tiles.rules.farmers = false;
+        let game = Game::new(tiles, Player::local_players(2));
+        assert_eq!(
+            game.legal_meeple_placements(Coord::ORIGIN),
+            vec![SlotId::Feature(0), SlotId::Feature(1)]
+        );
+
+        let game = Game::new(
+            GameTilesFactory::build_base_game(),
+            Player::local_players(2),
+        );
+        assert_eq!(game.legal_meeple_placements(Coord::ORIGIN).len(), 4);
+    }
+
+    #[test]
+    fn test_meeples_return_when_feature_completes() {
+        let road_end = TileBuilder::new(TileId('V')).add_road(vec![West]).build();
+        let mut game = two_player_game(vec![road_end.clone(), road_end]);
+
+        game.draw_tile().unwrap();
+        game.place_tile(Coord::new(1, 0), Rotation::Deg0).unwrap();
+        game.place_meeple(Some(SlotId::Feature(0))).unwrap();
+        assert!(game.score().unwrap().returned_meeples.is_empty());
+        assert_eq!(game.meeples_left(0), MEEPLES_PER_PLAYER - 1);
+
+        // The second player closes the road of the first one from the other end.
+        game.draw_tile().unwrap();
+        game.place_tile(Coord::new(-1, 0), Rotation::Deg180)
+            .unwrap();
+        game.place_meeple(None).unwrap();
+        assert_eq!(
+            game.score().unwrap().returned_meeples,
+            vec![ReturnedMeeple {
+                position: Coord::new(1, 0),
+                meeple: Meeple::new(0, SlotId::Feature(0)),
+            }]
+        );
+        assert_eq!(game.meeples_left(0), MEEPLES_PER_PLAYER);
+        assert!(game.board().get(Coord::new(1, 0)).unwrap().meeple.is_none());
+        // The road spreads over the start tile and both road ends.
+        assert_eq!(game.scores(), &[3, 0]);
+    }
+
+    #[test]
+    fn test_completed_town_is_scored() {
+        let town = TileBuilder::new(TileId('E')).add_town(vec![South]).build();
+        let mut game = two_player_game(vec![town]);
+
+        game.draw_tile().unwrap();
+        game.place_tile(Coord::new(0, -1), Rotation::Deg0).unwrap();
+        game.place_meeple(Some(SlotId::Feature(0))).unwrap();
+        let turn_score = game.score().unwrap();
+
+        assert_eq!(
+            turn_score.scores,
+            vec![FeatureScore {
+                category: ScoreCategory::Town,
+                points: 4,
+                players: vec![0],
+            }]
+        );
+        assert_eq!(game.scores(), &[4, 0]);
+        assert_eq!(game.score_board().points_in_turn(1), vec![4, 0]);
+        assert_eq!(game.score_board().leader(), Some(0));
+        assert_eq!(game.meeples_left(0), MEEPLES_PER_PLAYER);
+    }
+
+    #[test]
+    fn test_game_uses_swapped_scorers() {
+        struct TenPerTown;
+
+        impl Scorer for TenPerTown {
+            fn score(&self, feature: &Feature, board: &Board) -> FeatureScore {
+                FeatureScore {
+                    points: 10,
+                    ..TownScorer.score(feature, board)
+                }
+            }
+        }
+
+        let town = TileBuilder::new(TileId('E')).add_town(vec![South]).build();
+        let mut game =
+            two_player_game(vec![town]).with_scorers(Scorers::default().with_town(TenPerTown));
+        game.draw_tile().unwrap();
+        game.place_tile(Coord::new(0, -1), Rotation::Deg0).unwrap();
+        game.place_meeple(Some(SlotId::Feature(0))).unwrap();
+        game.score().unwrap();
+        assert_eq!(game.scores(), &[10, 0]);
+    }
+
+    #[test]
+    fn test_abbey_completed_by_opponent() {
+        let abbey = TileBuilder::new(TileId('B')).add_abbey().build();
+        let mut game = Game::with_rng(
+            GameTiles {
+                available_tiles: vec![abbey.clone(), abbey.clone()],
+                start_tile: Some(abbey.clone()),
+                seed: None,
+                rules: RulesConfig::default(),
+            },
+            Player::local_players(2),
+            Box::new(StdRng::seed_from_u64(3)),
+        );
+        game.board
+            .set_meeple(Coord::ORIGIN, Some(Meeple::new(0, SlotId::Extension)));
+        game.meeples[0] -= 1;
+        // From the north, clockwise, so each tile is placed next to another one.
+        let mut around = Coord::ORIGIN.surrounding();
+        around.rotate_left(1);
+        for position in &around[..6] {
+            game.board
+                .place_tile(*position, abbey.clone(), Rotation::Deg0)
+                .unwrap();
+        }
+
+        for (player, position) in [(0, around[6]), (1, around[7])] {
+            assert_eq!(game.active_player(), player);
+            game.draw_tile().unwrap();
+            game.place_tile(position, Rotation::Deg0).unwrap();
+            game.place_meeple(None).unwrap();
+            let turn_score = game.score().unwrap();
+            assert_eq!(turn_score.scores.is_empty(), player == 0);
+        }
+
+        assert_eq!(game.scores(), &[9, 0]);
+        assert_eq!(game.meeples_left(0), MEEPLES_PER_PLAYER);
+        assert!(game.board().get(Coord::ORIGIN).unwrap().meeple.is_none());
+    }
+
+    #[test]
+    fn test_unfinished_features_scored_at_game_end() {
+        let mut game = two_player_game(vec![road()]);
+        game.board
+            .set_meeple(Coord::ORIGIN, Some(Meeple::new(1, SlotId::Feature(0))));
+        game.draw_tile().unwrap();
+        game.place_tile(Coord::new(1, 0), Rotation::Deg0).unwrap();
+        game.place_meeple(Some(SlotId::Feature(0))).unwrap();
+        game.score().unwrap();
+        assert!(game.result().is_none());
+
+        assert!(game.draw_tile().unwrap().is_none());
+        assert_eq!(game.phase(), TurnPhase::GameOver);
+        let result = game.result().unwrap();
+        assert_eq!(
+            result.final_scores,
+            vec![
+                FeatureScore {
+                    category: ScoreCategory::Town,
+                    points: 1,
+                    players: vec![1],
+                },
+                FeatureScore {
+                    category: ScoreCategory::Road,
+                    points: 2,
+                    players: vec![0],
+                },
+            ]
+        );
+        assert_eq!(result.scores, vec![2, 1]);
+        assert_eq!(game.scores(), &[2, 1]);
+        assert_eq!(result.breakdowns[0].roads, 2);
+        assert_eq!(result.breakdowns[1].towns, 1);
+        assert_eq!(result.breakdowns[1].end_game, 1);
+        assert_eq!(result.winners, vec![0]);
+        assert_eq!(result.turns, 1);
+    }
+
+    #[test]
+    fn test_unfinished_abbey_scored_at_game_end() {
+        let abbey = TileBuilder::new(TileId('B')).add_abbey().build();
+        let mut game = Game::with_rng(
+            GameTiles {
+                available_tiles: vec![],
+                start_tile: Some(abbey),
+                seed: None,
+                rules: RulesConfig::default(),
+            },
+            Player::local_players(2),
+            Box::new(StdRng::seed_from_u64(3)),
+        );
+        game.board
+            .set_meeple(Coord::ORIGIN, Some(Meeple::new(0, SlotId::Extension)));
+
+        assert!(game.draw_tile().unwrap().is_none());
+        assert_eq!(
+            game.result().unwrap().final_scores,
+            vec![FeatureScore {
+                category: ScoreCategory::Abbey,
+                points: 1,
+                players: vec![0],
+            }]
+        );
+    }
+
+    #[test]
+    fn test_apply_plays_whole_turns() {
+        let mut game = two_player_game(vec![road()]);
+        assert_eq!(
+            game.advance(),
+            vec![GameEvent::TileDrawn {
+                player: 0,
+                tile: TileId('U'),
+            }]
+        );
+        assert!(game.advance().is_empty());
+
+        assert_eq!(
+            game.apply(Move::PlaceMeeple(SlotId::Feature(0))),
+            Err(RuleError::WrongPhase {
+                expected: TurnPhase::PlaceMeeple,
+                actual: TurnPhase::PlaceTile,
+            })
+        );
+        assert_eq!(
+            game.apply(Move::PlaceTile {
+                tile: TileId('D'),
+                position: Coord::new(1, 0),
+                rotation: Rotation::Deg0,
+            }),
+            Err(RuleError::WrongTile {
+                drawn: TileId('U'),
+                played: TileId('D'),
+            })
+        );
+        let placement = Move::PlaceTile {
+            tile: TileId('U'),
+            position: Coord::new(1, 0),
+            rotation: Rotation::Deg0,
+        };
+        assert_eq!(
+            game.apply(placement).unwrap(),
+            vec![GameEvent::TilePlaced {
+                player: 0,
+                tile: TileId('U'),
+                position: Coord::new(1, 0),
+                rotation: Rotation::Deg0,
+            }]
+        );
+
+        let events = game.apply(Move::PlaceMeeple(SlotId::Feature(0))).unwrap();
+        assert_eq!(
+            events[0],
+            GameEvent::MeeplePlaced {
+                player: 0,
+                position: Coord::new(1, 0),
+                slot: SlotId::Feature(0),
+            }
+        );
+        // The bag is empty: the unfinished road is scored and the game ends.
+        assert_eq!(events.len(), 3);
+        assert!(matches!(
+            events[1],
+            GameEvent::PointsScored(ScoreEvent {
+                player: 0,
+                points: 2,
+                feature: ScoreCategory::Road,
+                ..
+            })
+        ));
+        match &events[2] {
+            GameEvent::GameOver(result) => assert_eq!(result.scores, vec![2, 0]),
+            event => panic!("unexpected event {event:?}"),
+        }
+        assert_eq!(game.phase(), TurnPhase::GameOver);
+    }
+
+    #[test]
+    fn test_skip_meeple_scores_and_passes_the_turn() {
+        let road_end = TileBuilder::new(TileId('V')).add_road(vec![West]).build();
+        let mut game = two_player_game(vec![road_end.clone(), road_end]);
+        game.advance();
+        assert!(game.apply(Move::SkipMeeple).is_err());
+
+        game.apply(Move::PlaceTile {
+            tile: TileId('V'),
+            position: Coord::new(1, 0),
+            rotation: Rotation::Deg0,
+        })
+        .unwrap();
+        let events = game.apply(Move::SkipMeeple).unwrap();
+        assert_eq!(events[0], GameEvent::MeepleSkipped { player: 0 });
+        assert_eq!(
+            events[1],
+            GameEvent::TileDrawn {
+                player: 1,
+                tile: TileId('V'),
+            }
+        );
+        assert_eq!(game.meeples_left(0), MEEPLES_PER_PLAYER);
+        assert!(game.board().get(Coord::new(1, 0)).unwrap().meeple.is_none());
+    }
+
+    #[test]
+    fn test_scored_features_report_the_points_of_each_player() {
+        let road_end = TileBuilder::new(TileId('V')).add_road(vec![West]).build();
+        let mut game = two_player_game(vec![road_end.clone(), road_end]);
+        game.advance();
+        game.apply(Move::PlaceTile {
+            tile: TileId('V'),
+            position: Coord::new(1, 0),
+            rotation: Rotation::Deg0,
+        })
+        .unwrap();
+        game.apply(Move::PlaceMeeple(SlotId::Feature(0))).unwrap();
+        game.apply(Move::PlaceTile {
+            tile: TileId('V'),
+            position: Coord::new(-1, 0),
+            rotation: Rotation::Deg180,
+        })
+        .unwrap();
+
+        let events = game.apply(Move::SkipMeeple).unwrap();
+        assert!(matches!(events[1], GameEvent::FeatureScored(_)));
+        assert_eq!(
+            events[2],
+            GameEvent::PointsScored(ScoreEvent {
+                player: 0,
+                points: 3,
+                feature: ScoreCategory::Road,
+                turn: 2,
+            })
+        );
+        assert!(matches!(events[3], GameEvent::MeepleReturned(_)));
+        assert_eq!(
+            game.score_board().audit()[0].tiles,
+            vec![Coord::new(-1, 0), Coord::ORIGIN, Coord::new(1, 0)]
+        );
+    }
+
+    #[test]
+    fn test_resigning_returns_meeples_and_passes_the_turn() {
+        let mut game = Game::with_rng(
+            GameTiles {
+                available_tiles: vec![road(), road(), road()],
+                start_tile: Some(road()),
+                seed: None,
+                rules: RulesConfig::default(),
+            },
+            Player::local_players(3),
+            Box::new(StdRng::seed_from_u64(3)),
+        );
+        game.draw_tile().unwrap();
+        game.place_tile(Coord::new(1, 0), Rotation::Deg0).unwrap();
+        game.place_meeple(Some(SlotId::Feature(0))).unwrap();
+        game.score().unwrap();
+        game.draw_tile().unwrap();
+
+        assert_eq!(
+            game.resign(2),
+            Ok(vec![GameEvent::PlayerResigned { player: 2 }])
+        );
+        assert_eq!(game.resign(2), Err(RuleError::NotInGame(2)));
+        assert_eq!(
+            game.resign(0).unwrap()[1],
+            GameEvent::MeepleReturned(ReturnedMeeple {
+                position: Coord::new(1, 0),
+                meeple: Meeple::new(0, SlotId::Feature(0)),
+            })
+        );
+        assert!(game.board().get(Coord::new(1, 0)).unwrap().meeple.is_none());
+        assert_eq!(game.meeples_left(0), MEEPLES_PER_PLAYER);
+        assert_eq!(game.phase(), TurnPhase::GameOver);
+        assert_eq!(game.result().unwrap().winners, vec![1]);
+        assert_eq!(game.resign(1), Err(RuleError::GameOver));
+    }
+
+    #[test]
+    fn test_resigning_during_own_turn_puts_the_tile_back() {
+        let mut game = Game::with_rng(
+            GameTiles {
+                available_tiles: vec![road(), road()],
+                start_tile: Some(road()),
+                seed: None,
+                rules: RulesConfig::default(),
+            },
+            Player::local_players(3),
+            Box::new(StdRng::seed_from_u64(3)),
+        );
+        game.advance();
+        assert_eq!(game.tiles().available_tiles.len(), 1);
+
+        let events = game.resign(0).unwrap();
+        assert_eq!(
+            events,
+            vec![
+                GameEvent::PlayerResigned { player: 0 },
+                GameEvent::TileDrawn {
+                    player: 1,
+                    tile: TileId('U'),
+                },
+            ]
+        );
+        assert_eq!(game.tiles().available_tiles.len(), 1);
+        assert_eq!(game.active_player(), 1);
+    }
+
+    #[test]
+    fn test_timeout_while_placing_a_meeple_skips_it() {
+        let control = TimeControl::new(Duration::from_secs(10), Duration::ZERO);
+        let mut game = two_player_game(vec![road(), road()]).with_time_control(control);
+        game.advance();
+        game.place_tile(Coord::new(1, 0), Rotation::Deg0).unwrap();
+
+        let events = game.spend_time(Duration::from_secs(10));
+        assert_eq!(events[0], GameEvent::TimedOut { player: 0 });
+        assert_eq!(events[1], GameEvent::MeepleSkipped { player: 0 });
+        assert_eq!(game.board().len(), 2);
+        assert_eq!(game.active_player(), 1);
+        assert_eq!(game.phase(), TurnPhase::PlaceTile);
+        assert_eq!(game.take_back(), Err(RuleError::NothingToUndo));
+    }
+
+    #[test]
+    fn test_timeout_with_forfeit_resigns() {
+        let control =
+            TimeControl::new(Duration::from_secs(10), Duration::ZERO).on_timeout(Timeout::Forfeit);
+        let mut game = two_player_game(vec![road(), road()]).with_time_control(control);
+        game.advance();
+
+        let events = game.spend_time(Duration::from_secs(15));
+        assert_eq!(events[0], GameEvent::TimedOut { player: 0 });
+        assert_eq!(events[1], GameEvent::PlayerResigned { player: 0 });
+        assert_eq!(game.phase(), TurnPhase::GameOver);
+        assert_eq!(game.result().unwrap().winners, vec![1]);
+        assert!(game.spend_time(Duration::from_secs(15)).is_empty());
+    }
+
+    /// Plays a game where the first player puts a meeple on the town of the start
+    /// tile, and the second player closes it, up to their meeple choice.
+    fn town_closed_by_second_player(control: Option<TimeControl>) -> Game {
+        let town = TileBuilder::new(TileId('F'))
+            .add_town(vec![North, South])
+            .build();
+        let cap = TileBuilder::new(TileId('E')).add_town(vec![North]).build();
+        let mut game = two_player_game(vec![town, cap]);
+        if let Some(control) = control {
+            game = game.with_time_control(control);
+        }
+        game.advance();
+        assert_eq!(game.drawn_tile().unwrap().id, TileId('F'));
+        game.place_tile(Coord::new(0, -1), Rotation::Deg0).unwrap();
+        game.place_meeple(Some(SlotId::Feature(0))).unwrap();
+        game.advance();
+        game.place_tile(Coord::new(0, -2), Rotation::Deg180)
+            .unwrap();
+        game
+    }
+
+    #[test]
+    fn test_resigning_scores_the_tile_placed_this_turn() {
+        let mut game = town_closed_by_second_player(None);
+        let events = game.resign(1).unwrap();
+
+        assert!(
+            events
+                .iter()
+                .any(|event| matches!(event, GameEvent::FeatureScored(_)))
+        );
+        assert!(matches!(events.last(), Some(GameEvent::GameOver(_))));
+        assert_eq!(game.result().unwrap().scores, vec![6, 0]);
+        assert_eq!(game.meeples_left(0), MEEPLES_PER_PLAYER);
+    }
+
+    #[test]
+    fn test_forfeit_scores_the_tile_placed_this_turn() {
+        let control =
+            TimeControl::new(Duration::from_secs(10), Duration::ZERO).on_timeout(Timeout::Forfeit);
+        let mut game = town_closed_by_second_player(Some(control));
+        let events = game.spend_time(Duration::from_secs(10));
+
+        assert_eq!(events[0], GameEvent::TimedOut { player: 1 });
+        assert_eq!(game.result().unwrap().scores, vec![6, 0]);
+        assert_eq!(game.meeples_left(0), MEEPLES_PER_PLAYER);
+    }
+
+    #[test]
+    fn test_cathedral_town_scored_from_the_deck_expansion() {
+        let start_tile = TileBuilder::new(TileId('D'))
+            .add_town(vec![North])
+            .add_road(vec![West, East])
+            .build();
+        let cathedral = TileBuilder::new(TileId('c'))
+            .add_cathedral_town(vec![South])
+            .print_run(ExpansionId::InnsAndCathedrals, 1)
+            .build();
+        let mut game = Game::new(
+            GameTiles {
+                available_tiles: vec![cathedral],
+                start_tile: Some(start_tile),
+                seed: Some(7),
+                rules: RulesConfig::default(),
+            },
+            Player::local_players(2),
+        );
+        game.advance();
+        let tile = game.drawn_tile().unwrap().id;
+        game.apply(Move::PlaceTile {
+            tile,
+            position: Coord::new(0, -1),
+            rotation: Rotation::Deg0,
+        })
+        .unwrap();
+        game.apply(Move::PlaceMeeple(SlotId::Feature(0))).unwrap();
+
+        // Two tiles, worth three points each with the cathedral.
+        assert_eq!(game.score_of(0), 6);
+        assert_eq!(game.breakdown_of(0).towns, 6);
+    }
+
+    #[test]
+    fn test_game_can_be_shared_between_threads() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<Board>();
+        assert_send_sync::<Game>();
+    }
+
+    #[test]
+    fn test_undo_meeple_then_tile() {
+        let mut game = two_player_game(vec![road(), road()]);
+        assert_eq!(game.take_back(), Err(RuleError::NothingToUndo));
+        game.draw_tile().unwrap();
+        game.place_tile(Coord::new(1, 0), Rotation::Deg0).unwrap();
+        game.place_meeple(Some(SlotId::Feature(0))).unwrap();
+
+        assert_eq!(game.take_back(), Ok(Move::PlaceMeeple(SlotId::Feature(0))));
+        assert_eq!(game.phase(), TurnPhase::PlaceMeeple);
+        assert_eq!(game.meeples_left(0), MEEPLES_PER_PLAYER);
+        assert!(game.board().get(Coord::new(1, 0)).unwrap().meeple.is_none());
+
+        game.take_back().unwrap();
+        assert!(game.board().get(Coord::new(1, 0)).is_none());
+        game.place_tile(Coord::new(-1, 0), Rotation::Deg0).unwrap();
+        game.skip_meeple().unwrap();
+        game.score().unwrap();
+        assert_eq!(game.take_back(), Err(RuleError::NothingToUndo));
+        assert!(game.board().get(Coord::new(-1, 0)).is_some());
+    }
+
+    #[test]
+    fn test_undo_and_redo_whole_turns() {
+        let road_end = TileBuilder::new(TileId('V')).add_road(vec![West]).build();
+        let mut game = two_player_game(vec![road_end, road()]);
+        game.advance();
+        let start = (
+            game.drawn_tile().unwrap().id,
+            game.tiles().available_tiles.len(),
+        );
+        let placement = Move::PlaceTile {
+            tile: start.0,
+            position: Coord::new(1, 0),
+            rotation: Rotation::Deg0,
+        };
+        game.apply(placement).unwrap();
+        game.apply(Move::PlaceMeeple(SlotId::Feature(0))).unwrap();
+        let scores = game.scores().to_vec();
+        let drawn = game.drawn_tile().map(|tile| tile.id);
+        assert_eq!(game.history().done().len(), 2);
+
+        assert_eq!(game.undo(), Ok(Move::PlaceMeeple(SlotId::Feature(0))));
+        assert_eq!(game.undo(), Ok(placement));
+        assert_eq!(game.undo(), Err(RuleError::NothingToUndo));
+        assert_eq!(game.board().len(), 1);
+        assert_eq!(game.meeples_left(0), MEEPLES_PER_PLAYER);
+        assert_eq!(game.phase(), TurnPhase::PlaceTile);
+        assert_eq!(
+            (
+                game.drawn_tile().unwrap().id,
+                game.tiles().available_tiles.len()
+            ),
+            start
+        );
+
+        assert_eq!(game.redo(), Ok(placement));
+        assert_eq!(game.redo(), Ok(Move::PlaceMeeple(SlotId::Feature(0))));
+        assert_eq!(game.redo(), Err(RuleError::NothingToRedo));
+        assert_eq!(game.scores(), scores);
+        assert_eq!(game.drawn_tile().map(|tile| tile.id), drawn);
+        assert_eq!(game.active_player(), 1);
+        assert_eq!(game.history().undone().len(), 0);
+
+        game.undo().unwrap();
+        game.apply(Move::SkipMeeple).unwrap();
+        assert_eq!(game.redo(), Err(RuleError::NothingToRedo));
+    }
+
+    #[test]
+    fn test_undo_and_redo_keep_the_seeded_draws() {
+        let play = |undo_after: Option<usize>| {
+            let mut tiles = GameTilesFactory::build_base_game();
+            tiles.seed = Some(5);
+            let mut game = Game::new(tiles, Player::local_players(2));
+            game.advance();
+            let mut drawn = vec![];
+            for turn in 0..12 {
+                let tile = game.drawn_tile().unwrap().clone();
+                drawn.push(tile.id);
+                let (position, rotation) = game.board().legal_placements(&tile)[0];
+                game.apply(Move::PlaceTile {
+                    tile: tile.id,
+                    position,
+                    rotation,
+                })
+                .unwrap();
+                game.apply(Move::SkipMeeple).unwrap();
+                if undo_after == Some(turn) {
+                    game.undo().unwrap();
+                    game.undo().unwrap();
+                    game.redo().unwrap();
+                    game.redo().unwrap();
+                }
+            }
+            (drawn, game.tiles().available_tiles.clone())
+        };
+
+        let (drawn, bag) = play(None);
+        for turn in [0, 5] {
+            let (replayed, replayed_bag) = play(Some(turn));
+            assert_eq!(replayed, drawn);
+            assert_eq!(
+                replayed_bag.iter().map(|tile| tile.id).collect::<Vec<_>>(),
+                bag.iter().map(|tile| tile.id).collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[test]
+    fn test_tied_players_share_the_win() {
+        let mut game = two_player_game(vec![]);
+        game.advance();
+        let result = game.result().unwrap();
+        assert_eq!(result.scores, vec![0, 0]);
+        assert_eq!(result.winners, vec![0, 1]);
+        assert_eq!(result.ranking[1].rank, 1);
+        assert_eq!(result.turns, 0);
+    }
+
+    #[test]
+    fn test_tie_break_ranks_tied_players() {
+        let grass = TileBuilder::new(TileId('G'))
+            .add_field(vec![North, East, South, West])
+            .build();
+        let rules = RulesConfig {
+            tie_break: TieBreak::FewestStrandedMeeples,
+            ..RulesConfig::default()
+        };
+        let mut game = two_player_game_with_rules(vec![grass], rules);
+        game.draw_tile().unwrap();
+        game.place_tile(Coord::new(0, 1), Rotation::Deg0).unwrap();
+        game.place_meeple(Some(SlotId::Feature(0))).unwrap();
+        game.score().unwrap();
+        game.advance();
+
+        let result = game.result().unwrap();
+        assert_eq!(result.scores, vec![0, 0]);
+        assert_eq!(result.winners, vec![1]);
+        assert_eq!(game.score_board().stranded_meeples(0), 1);
+    }
+
+    #[test]
+    fn test_seeded_games_draw_the_same_tiles() {
+        let draws = |seed: u64| {
+            let mut tiles = GameTilesFactory::build_base_game();
+            tiles.seed = Some(seed);
+            let mut game = Game::new(tiles, Player::local_players(2));
+            let mut drawn = vec![];
+            for _ in 0..10 {
+                let tile = game.draw_tile().unwrap().unwrap().clone();
+                drawn.push(tile.id);
+                let (position, rotation) = game.board().legal_placements(&tile)[0];
+                game.place_tile(position, rotation).unwrap();
+                game.place_meeple(None).unwrap();
+                game.score().unwrap();
+            }
+            drawn
+        };
+        assert_eq!(draws(11), draws(11));
+    }
+
+    #[test]
+    fn test_unseeded_game_keeps_the_seed_it_draws_with() {
+        let mut game = Game::new(
+            GameTilesFactory::build_base_game(),
+            Player::local_players(2),
+        );
+        let seed = game.tiles().seed.expect("a seed is picked");
+
+        let mut tiles = GameTilesFactory::build_base_game();
+        tiles.seed = Some(seed);
+        let mut replayed = Game::new(tiles, Player::local_players(2));
+        assert_eq!(
+            game.draw_tile().unwrap().map(|tile| tile.id),
+            replayed.draw_tile().unwrap().map(|tile| tile.id)
+        );
+    }
+
+    #[test]
+    fn test_no_meeple_left() {
+        let mut game = two_player_game(vec![road()]);
+        game.meeples[0] = 0;
+        game.draw_tile().unwrap();
+        game.place_tile(Coord::new(1, 0), Rotation::Deg0).unwrap();
+        assert_eq!(
+            game.place_meeple(Some(SlotId::Feature(0))),
+            Err(RuleError::NoMeeplesLeft)
+        );
+    }
 }
@@ -0,0 +1,121 @@
+use std::time::Duration;
+
+/// What happens to a player whose clock runs out.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Timeout {
+    /// The player loses the rest of their turn: a tile not placed yet goes back in the
+    /// bag, and a tile already placed is left without a meeple. They play the next
+    /// turns on their increment alone.
+    #[default]
+    SkipTurn,
+    /// The player resigns.
+    Forfeit,
+}
+
+/// The time each player is given for the whole game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TimeControl {
+    /// The time each player starts with.
+    pub total: Duration,
+    /// The time added to the clock of a player after each of their turns.
+    pub increment: Duration,
+    /// What happens to a player whose clock runs out.
+    pub timeout: Timeout,
+}
+
+impl TimeControl {
+    /// Creates a time control of `total` per player, plus `increment` per turn, where
+    /// a player running out of time skips their turn.
+    pub fn new(total: Duration, increment: Duration) -> Self {
+        Self {
+            total,
+            increment,
+            timeout: Timeout::default(),
+        }
+    }
+
+    /// Sets what happens to a player whose clock runs out.
+    pub fn on_timeout(mut self, timeout: Timeout) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+/// The clocks of the players of a game, counting down the time they have left.
+///
+/// The clocks do not read the time themselves: the front end tells them how much
+/// time passed with `spend`, which keeps games reproducible and testable.
+///
+/// # Examples
+///
+/// ```
+/// use carcasonne_core::model::game::clock::{Clocks, TimeControl};
+/// use std::time::Duration;
+///
+/// let control = TimeControl::new(Duration::from_secs(60), Duration::from_secs(5));
+/// let mut clocks = Clocks::new(control, 2);
+/// assert!(!clocks.spend(0, Duration::from_secs(45)));
+/// clocks.add_increment(0);
+/// assert_eq!(clocks.remaining(0), Duration::from_secs(20));
+/// assert!(clocks.spend(0, Duration::from_secs(30)));
+/// assert_eq!(clocks.remaining(0), Duration::ZERO);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Clocks {
+    control: TimeControl,
+    remaining: Vec<Duration>,
+}
+
+impl Clocks {
+    /// Creates the clocks of `player_count` players, each starting with the total time
+    /// of `control`.
+    pub fn new(control: TimeControl, player_count: usize) -> Self {
+        Self {
+            control,
+            remaining: vec![control.total; player_count],
+        }
+    }
+
+    /// Returns the time control the clocks follow.
+    pub fn control(&self) -> TimeControl {
+        self.control
+    }
+
+    /// Returns the time `player` has left.
+    pub fn remaining(&self, player: usize) -> Duration {
+        self.remaining.get(player).copied().unwrap_or_default()
+    }
+
+    /// Takes `elapsed` off the clock of `player`, and returns whether it ran out.
+    pub fn spend(&mut self, player: usize, elapsed: Duration) -> bool {
+        let Some(remaining) = self.remaining.get_mut(player) else {
+            return false;
+        };
+        *remaining = remaining.saturating_sub(elapsed);
+        remaining.is_zero()
+    }
+
+    /// Adds the increment to the clock of `player`, at the end of their turn.
+    pub fn add_increment(&mut self, player: usize) {
+        if let Some(remaining) = self.remaining.get_mut(player) {
+            *remaining += self.control.increment;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clocks_count_down_separately() {
+        let control = TimeControl::new(Duration::from_secs(10), Duration::ZERO);
+        let mut clocks = Clocks::new(control, 2);
+        assert!(!clocks.spend(1, Duration::from_secs(4)));
+        assert_eq!(clocks.remaining(0), Duration::from_secs(10));
+        assert_eq!(clocks.remaining(1), Duration::from_secs(6));
+        assert!(clocks.spend(1, Duration::from_secs(7)));
+        assert_eq!(clocks.remaining(1), Duration::ZERO);
+        assert!(!clocks.spend(5, Duration::from_secs(1)));
+    }
+}
@@ -0,0 +1,84 @@
+use crate::model::board::Coord;
+use crate::model::game::{GameResult, ReturnedMeeple};
+use crate::model::meeple::SlotId;
+use crate::model::tile::{Rotation, TileId};
+use crate::scoring::{FeatureScore, ScoreCategory};
+
+/// Something that happened in a game, as reported by `Game::apply` and `Game::advance`.
+///
+/// Events are listed in the order they happened, so front ends can replay them, for
+/// instance to animate the scoring of a turn.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GameEvent {
+    /// A tile fitting nowhere on the board was drawn, and put back in the box.
+    TileDiscarded {
+        /// The discarded tile.
+        tile: TileId,
+    },
+    /// The active player drew a tile.
+    TileDrawn {
+        /// The player who drew the tile.
+        player: usize,
+        /// The drawn tile.
+        tile: TileId,
+    },
+    /// The active player placed the drawn tile.
+    TilePlaced {
+        /// The player who placed the tile.
+        player: usize,
+        /// The placed tile.
+        tile: TileId,
+        /// Where the tile was placed.
+        position: Coord,
+        /// How the tile was turned.
+        rotation: Rotation,
+    },
+    /// The active player put a meeple on the tile just placed.
+    MeeplePlaced {
+        /// The owner of the meeple.
+        player: usize,
+        /// The position of the tile the meeple stands on.
+        position: Coord,
+        /// The slot of the tile the meeple stands on.
+        slot: SlotId,
+    },
+    /// The active player declined to put a meeple on the tile just placed.
+    MeepleSkipped {
+        /// The player who kept their meeples.
+        player: usize,
+    },
+    /// The clock of a player ran out.
+    TimedOut {
+        /// The player out of time.
+        player: usize,
+    },
+    /// A player resigned, leaving the rotation.
+    PlayerResigned {
+        /// The player who resigned.
+        player: usize,
+    },
+    /// A feature was scored.
+    FeatureScored(FeatureScore),
+    /// A player received points. Each scored feature is followed by the points it gave
+    /// to each of its players, and the end of the game is preceded by the points of the
+    /// unfinished features and of the farmers.
+    PointsScored(ScoreEvent),
+    /// A meeple went back to its owner, as its feature was completed or its owner
+    /// resigned.
+    MeepleReturned(ReturnedMeeple),
+    /// The bag ran out, or a single player was left, and the final scores were counted.
+    GameOver(GameResult),
+}
+
+/// Points given to a player, as reported by `GameEvent::PointsScored`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScoreEvent {
+    /// The player receiving the points.
+    pub player: usize,
+    /// The points received.
+    pub points: u32,
+    /// What the points were scored for.
+    pub feature: ScoreCategory,
+    /// The turn the points were scored during, starting from 1.
+    pub turn: usize,
+}
@@ -0,0 +1,240 @@
+use crate::model::board::Coord;
+use crate::model::game::event::GameEvent;
+use crate::model::game::moves::Move;
+use crate::model::game::turn_engine::TurnEngine;
+use crate::model::game::{Game, GameResult};
+use crate::model::meeple::Meeple;
+use crate::model::tile::{Tile, TileId};
+use crate::scoring::score_board::ScoreBoard;
+
+/// The counters of a game a move may change, besides the board and the tile bag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Counters {
+    engine: TurnEngine,
+    meeples: Vec<usize>,
+    score_board: ScoreBoard,
+    placed_at: Option<Coord>,
+    result: Option<GameResult>,
+}
+
+impl Counters {
+    /// Records the counters of `game`.
+    pub(crate) fn of(game: &Game) -> Self {
+        Self {
+            engine: game.engine.clone(),
+            meeples: game.meeples.clone(),
+            score_board: game.score_board.clone(),
+            placed_at: game.placed_at,
+            result: game.result.clone(),
+        }
+    }
+
+    /// Sets the counters of `game` back to the recorded ones.
+    fn restore(&self, game: &mut Game) {
+        game.engine = self.engine.clone();
+        game.meeples = self.meeples.clone();
+        game.score_board = self.score_board.clone();
+        game.placed_at = self.placed_at;
+        game.result = self.result.clone();
+    }
+}
+
+/// A move applied to a game, with everything it changed, so it can be undone and
+/// redone.
+///
+/// The events of the move tell how the board and the tile bag changed, and the
+/// counters of the game, such as the scores, are recorded before and after it. Redoing
+/// a move plays its events again rather than the move itself, so the same tiles are
+/// drawn. The tiles taken out of the bag go back where they were on undo, so the bag
+/// is left in the same order once the move is redone.
+#[derive(Debug, Clone)]
+pub struct Command {
+    player_move: Move,
+    events: Vec<GameEvent>,
+    bag_indices: Vec<usize>,
+    before: Counters,
+    after: Counters,
+}
+
+impl Command {
+    /// Creates the command of `player_move`, which turned the `before` counters into
+    /// the `after` ones, emitting `events` and leaving `bag_len` tiles in the bag.
+    ///
+    /// Tiles are drawn from the end of the bag, so the tiles drawn or discarded during
+    /// the move were taken, one after the other, from just past the tiles left.
+    pub(crate) fn new(
+        player_move: Move,
+        events: Vec<GameEvent>,
+        bag_len: usize,
+        before: Counters,
+        after: Counters,
+    ) -> Self {
+        let taken = events.iter().filter(|event| takes_tile(event)).count();
+        Self {
+            player_move,
+            events,
+            bag_indices: (bag_len..bag_len + taken).rev().collect(),
+            before,
+            after,
+        }
+    }
+
+    /// Returns the move the command applied.
+    pub fn player_move(&self) -> Move {
+        self.player_move
+    }
+
+    /// Returns what happened when the move was applied.
+    pub fn events(&self) -> &[GameEvent] {
+        &self.events
+    }
+
+    /// Puts `game` back the way it was before the move.
+    pub(crate) fn undo(&self, game: &mut Game) {
+        let mut bag_indices = self.bag_indices.iter().rev();
+        for event in self.events.iter().rev() {
+            match event {
+                GameEvent::TileDiscarded { .. } => {
+                    let tile = game
+                        .discarded_tiles
+                        .pop()
+                        .expect("the discarded tile is the last one");
+                    let index = bag_indices.next().expect("the tile was taken from the bag");
+                    game.tiles.available_tiles.insert(*index, tile);
+                }
+                GameEvent::TileDrawn { .. } => {
+                    let tile = game.drawn_tile.take().expect("the tile drawn is kept");
+                    let index = bag_indices.next().expect("the tile was taken from the bag");
+                    game.tiles.available_tiles.insert(*index, tile);
+                }
+                GameEvent::TilePlaced { .. } => {
+                    let removed = game
+                        .board
+                        .remove_last()
+                        .expect("the tile placed is the last one");
+                    game.drawn_tile = Some(removed.tile);
+                }
+                GameEvent::MeeplePlaced { position, .. } => {
+                    game.board.set_meeple(*position, None);
+                }
+                GameEvent::MeepleReturned(returned) => {
+                    game.board
+                        .set_meeple(returned.position, Some(returned.meeple));
+                }
+                GameEvent::MeepleSkipped { .. }
+                | GameEvent::TimedOut { .. }
+                | GameEvent::PlayerResigned { .. }
+                | GameEvent::FeatureScored(_)
+                | GameEvent::PointsScored(_)
+                | GameEvent::GameOver(_) => {}
+            }
+        }
+        self.before.restore(game);
+    }
+
+    /// Plays the move again on `game`, as it was played the first time.
+    pub(crate) fn redo(&self, game: &mut Game) {
+        let mut bag_indices = self.bag_indices.iter();
+        for event in &self.events {
+            match event {
+                GameEvent::TileDiscarded { tile } => {
+                    let index = bag_indices.next().expect("the tile was taken from the bag");
+                    let tile = take_tile(game, *index, *tile);
+                    game.discarded_tiles.push(tile);
+                }
+                GameEvent::TileDrawn { tile, .. } => {
+                    let index = bag_indices.next().expect("the tile was taken from the bag");
+                    game.drawn_tile = Some(take_tile(game, *index, *tile));
+                }
+                GameEvent::TilePlaced {
+                    position, rotation, ..
+                } => {
+                    let tile = game.drawn_tile.take().expect("the placed tile was drawn");
+                    game.board
+                        .place_tile(*position, tile, *rotation)
+                        .expect("the tile was placed there before");
+                }
+                GameEvent::MeeplePlaced {
+                    player,
+                    position,
+                    slot,
+                } => {
+                    game.board
+                        .set_meeple(*position, Some(Meeple::new(*player, *slot)));
+                }
+                GameEvent::MeepleReturned(returned) => {
+                    game.board.set_meeple(returned.position, None);
+                }
+                GameEvent::MeepleSkipped { .. }
+                | GameEvent::TimedOut { .. }
+                | GameEvent::PlayerResigned { .. }
+                | GameEvent::FeatureScored(_)
+                | GameEvent::PointsScored(_)
+                | GameEvent::GameOver(_) => {}
+            }
+        }
+        self.after.restore(game);
+    }
+}
+
+/// Returns whether `event` took a tile out of the bag.
+fn takes_tile(event: &GameEvent) -> bool {
+    matches!(
+        event,
+        GameEvent::TileDiscarded { .. } | GameEvent::TileDrawn { .. }
+    )
+}
+
+/// Takes the tile at `index` out of the bag of `game`, keeping the order of the
+/// others. The tile is expected to have the given id.
+fn take_tile(game: &mut Game, index: usize, id: TileId) -> Tile {
+    let tile = game.tiles.available_tiles.remove(index);
+    debug_assert_eq!(tile.id, id, "the tile is back where it was in the bag");
+    tile
+}
+
+/// The moves applied to a game, which can be undone and redone in turn.
+#[derive(Debug, Clone, Default)]
+pub struct History {
+    done: Vec<Command>,
+    undone: Vec<Command>,
+}
+
+impl History {
+    /// Returns the commands applied, the next one to undo last.
+    pub fn done(&self) -> &[Command] {
+        &self.done
+    }
+
+    /// Returns the commands undone, the next one to redo last.
+    pub fn undone(&self) -> &[Command] {
+        &self.undone
+    }
+
+    /// Records a command just applied. The commands undone before cannot be redone
+    /// anymore.
+    pub(crate) fn record(&mut self, command: Command) {
+        self.done.push(command);
+        self.undone.clear();
+    }
+
+    /// Takes the next command to undo, and keeps it to be redone.
+    pub(crate) fn undo(&mut self) -> Option<&Command> {
+        let command = self.done.pop()?;
+        self.undone.push(command);
+        self.undone.last()
+    }
+
+    /// Takes the next command to redo, and keeps it to be undone again.
+    pub(crate) fn redo(&mut self) -> Option<&Command> {
+        let command = self.undone.pop()?;
+        self.done.push(command);
+        self.done.last()
+    }
+
+    /// Forgets every command.
+    pub(crate) fn clear(&mut self) {
+        self.done.clear();
+        self.undone.clear();
+    }
+}
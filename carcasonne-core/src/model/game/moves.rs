@@ -0,0 +1,44 @@
+use crate::model::board::Coord;
+use crate::model::meeple::SlotId;
+use crate::model::tile::{Rotation, TileId};
+use serde::{Deserialize, Serialize};
+
+/// A decision of the active player, played with `Game::apply`.
+///
+/// Drawing tiles and scoring need no decision, and are played by the game itself.
+///
+/// Unlike the `Action` of the user interface, which follows the keys pressed, a move
+/// holds everything needed to play it again, so games can be logged, replayed or sent
+/// over the network.
+///
+/// # Examples
+///
+/// ```
+/// use carcasonne_core::model::board::Coord;
+/// use carcasonne_core::model::game::moves::Move;
+/// use carcasonne_core::model::tile::{Rotation, TileId};
+///
+/// let placement = Move::PlaceTile {
+///     tile: TileId('D'),
+///     position: Coord::new(1, 0),
+///     rotation: Rotation::Deg90,
+/// };
+/// let json = serde_json::to_string(&placement).unwrap();
+/// assert_eq!(serde_json::from_str::<Move>(&json).unwrap(), placement);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Move {
+    /// Places the drawn tile at `position`, turned by `rotation`.
+    PlaceTile {
+        /// The drawn tile, checked against the one actually drawn.
+        tile: TileId,
+        /// Where the tile is placed.
+        position: Coord,
+        /// How the tile is turned.
+        rotation: Rotation,
+    },
+    /// Puts a meeple on a slot of the tile just placed.
+    PlaceMeeple(SlotId),
+    /// Leaves the tile just placed without a meeple.
+    SkipMeeple,
+}
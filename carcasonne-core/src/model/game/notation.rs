@@ -0,0 +1,427 @@
+use crate::model::board::Coord;
+use crate::model::game::moves::Move;
+use crate::model::meeple::SlotId;
+use crate::model::tile::{Rotation, Tile, TileId};
+use std::fmt::{Display, Formatter};
+
+/// The reasons a game cannot be written in, or read from, the text notation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NotationError {
+    /// The text does not follow the notation; holds the offending part.
+    Malformed(String),
+    /// No known tile has this id.
+    UnknownTile(TileId),
+    /// The tile has no slot of this name.
+    UnknownSlot {
+        /// The tile the meeple is put on.
+        tile: TileId,
+        /// The name of the slot.
+        slot: String,
+    },
+    /// The tile has no such slot.
+    InvalidSlot {
+        /// The tile the meeple is put on.
+        tile: TileId,
+        /// The slot.
+        slot: SlotId,
+    },
+    /// The move at this index does not belong to a turn: a tile placement followed
+    /// by a meeple decision.
+    UnpairedMove(usize),
+}
+
+impl Display for NotationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NotationError::Malformed(text) => write!(f, "malformed notation: {text:?}"),
+            NotationError::UnknownTile(tile) => write!(f, "unknown tile {tile}"),
+            NotationError::UnknownSlot { tile, slot } => {
+                write!(f, "tile {tile} has no slot named {slot:?}")
+            }
+            NotationError::InvalidSlot { tile, slot } => {
+                write!(f, "tile {tile} has no {slot:?} slot")
+            }
+            NotationError::UnpairedMove(index) => {
+                write!(f, "move {index} is not part of a whole turn")
+            }
+        }
+    }
+}
+
+impl std::error::Error for NotationError {}
+
+/// A whole turn of a player, as written in the text notation: where the drawn tile
+/// was placed, and the slot the player put a meeple on, if any.
+///
+/// A turn is written `D@+1,0 r90 m:road`: the tile id and its position, its clockwise
+/// rotation in degrees, then the slot of the meeple. The meeple part is left out when
+/// the player kept their meeples. Slots are named after the kind of their feature,
+/// numbered from 1 when the tile has several features of that kind (`m:field2`), or
+/// after the tile extension (`m:abbey`).
+///
+/// # Examples
+///
+/// ```
+/// use carcasonne_core::factory::game_factory::GameTilesFactory;
+/// use carcasonne_core::model::board::Coord;
+/// use carcasonne_core::model::game::notation::TurnRecord;
+/// use carcasonne_core::model::meeple::SlotId;
+/// use carcasonne_core::model::tile::{Rotation, TileId};
+///
+/// let tiles = GameTilesFactory::build_base_game().available_tiles;
+/// let turn = TurnRecord::parse("D@+1,0 r90 m:road", &tiles).unwrap();
+/// assert_eq!(turn.position, Coord::new(1, 0));
+/// assert_eq!(turn.rotation, Rotation::Deg90);
+/// assert_eq!(turn.meeple, Some(SlotId::Feature(1)));
+/// assert_eq!(turn.to_notation(&tiles).unwrap(), "D@+1,0 r90 m:road");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TurnRecord {
+    /// The tile placed.
+    pub tile: TileId,
+    /// Where the tile was placed.
+    pub position: Coord,
+    /// How the tile was turned.
+    pub rotation: Rotation,
+    /// The slot of the tile a meeple was put on, or `None` if the player skipped.
+    pub meeple: Option<SlotId>,
+}
+
+impl TurnRecord {
+    /// Returns the two moves played during the turn.
+    pub fn moves(&self) -> [Move; 2] {
+        [
+            Move::PlaceTile {
+                tile: self.tile,
+                position: self.position,
+                rotation: self.rotation,
+            },
+            self.meeple.map_or(Move::SkipMeeple, Move::PlaceMeeple),
+        ]
+    }
+
+    /// Groups `moves`, as applied to a game, into whole turns.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NotationError::UnpairedMove` if a tile placement is not followed by a
+    /// meeple decision, or a meeple decision does not follow a tile placement.
+    pub fn from_moves(moves: &[Move]) -> Result<Vec<TurnRecord>, NotationError> {
+        moves
+            .chunks(2)
+            .enumerate()
+            .map(|(turn, pair)| match pair {
+                [
+                    Move::PlaceTile {
+                        tile,
+                        position,
+                        rotation,
+                    },
+                    meeple,
+                ] => {
+                    let meeple = match meeple {
+                        Move::PlaceMeeple(slot) => Some(*slot),
+                        Move::SkipMeeple => None,
+                        Move::PlaceTile { .. } => {
+                            return Err(NotationError::UnpairedMove(turn * 2 + 1));
+                        }
+                    };
+                    Ok(TurnRecord {
+                        tile: *tile,
+                        position: *position,
+                        rotation: *rotation,
+                        meeple,
+                    })
+                }
+                _ => Err(NotationError::UnpairedMove(turn * 2)),
+            })
+            .collect()
+    }
+
+    /// Writes the turn in the text notation, naming the slot of the meeple after the
+    /// definition of the tile found in `tiles`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NotationError::UnknownTile` if `tiles` has no tile with the id of the
+    /// turn, and `NotationError::InvalidSlot` if that tile has no such slot.
+    pub fn to_notation(&self, tiles: &[Tile]) -> Result<String, NotationError> {
+        let mut text = format!(
+            "{}@{},{} r{}",
+            self.tile,
+            signed(self.position.x),
+            signed(self.position.y),
+            self.rotation.quarter_turns() * 90
+        );
+        if let Some(slot) = self.meeple {
+            let tile = find_tile(tiles, self.tile)?;
+            let name = slot_name(tile, slot).ok_or(NotationError::InvalidSlot {
+                tile: self.tile,
+                slot,
+            })?;
+            text.push_str(" m:");
+            text.push_str(&name);
+        }
+        Ok(text)
+    }
+
+    /// Reads a turn written in the text notation, finding the slots of the meeple in
+    /// the definition of the tile found in `tiles`.
+    ///
+    /// The signs of the coordinates may be left out, and the number of a slot too
+    /// when the tile has a single feature of its kind.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NotationError::Malformed` if the text does not follow the notation,
+    /// `NotationError::UnknownTile` if `tiles` has no tile with its id, and
+    /// `NotationError::UnknownSlot` if that tile has no slot of the name given.
+    pub fn parse(text: &str, tiles: &[Tile]) -> Result<Self, NotationError> {
+        let malformed = |part: &str| NotationError::Malformed(part.to_string());
+        let mut parts = text.split_whitespace();
+
+        let placement = parts.next().ok_or_else(|| malformed(text))?;
+        let (id, coordinates) = placement
+            .split_once('@')
+            .ok_or_else(|| malformed(placement))?;
+        let mut letters = id.chars();
+        let tile = match (letters.next(), letters.next()) {
+            (Some(letter), None) => TileId(letter),
+            _ => return Err(malformed(id)),
+        };
+        let definition = find_tile(tiles, tile)?;
+        let (x, y) = coordinates
+            .split_once(',')
+            .ok_or_else(|| malformed(coordinates))?;
+        let position = Coord::new(
+            x.parse().map_err(|_| malformed(x))?,
+            y.parse().map_err(|_| malformed(y))?,
+        );
+
+        let rotation = parts
+            .next()
+            .and_then(|part| part.strip_prefix('r'))
+            .and_then(|degrees| degrees.parse::<usize>().ok())
+            .and_then(|degrees| {
+                Rotation::ALL
+                    .into_iter()
+                    .find(|rotation| rotation.quarter_turns() * 90 == degrees)
+            })
+            .ok_or_else(|| malformed(text))?;
+
+        let meeple = match parts.next() {
+            None => None,
+            Some(part) => {
+                let name = part.strip_prefix("m:").ok_or_else(|| malformed(part))?;
+                // A slot alone of its kind has no number, but may still be written
+                // with a 1.
+                let slot = definition
+                    .placement_slots()
+                    .into_iter()
+                    .find(|slot| {
+                        slot_name(definition, *slot).is_some_and(|slot_name| {
+                            let numbered = slot_name.ends_with(|c: char| c.is_ascii_digit());
+                            slot_name == name
+                                || (!numbered && name.strip_suffix('1') == Some(&slot_name))
+                        })
+                    })
+                    .ok_or_else(|| NotationError::UnknownSlot {
+                        tile,
+                        slot: name.to_string(),
+                    })?;
+                Some(slot)
+            }
+        };
+        if let Some(extra) = parts.next() {
+            return Err(malformed(extra));
+        }
+
+        Ok(TurnRecord {
+            tile,
+            position,
+            rotation,
+            meeple,
+        })
+    }
+}
+
+/// Writes the moves of a game in the text notation, one turn per line.
+///
+/// # Errors
+///
+/// Returns a `NotationError` if the moves do not form whole turns, or cannot be
+/// written with `tiles`, see `TurnRecord::to_notation`.
+pub fn write_game(moves: &[Move], tiles: &[Tile]) -> Result<String, NotationError> {
+    let mut text = String::new();
+    for turn in TurnRecord::from_moves(moves)? {
+        text.push_str(&turn.to_notation(tiles)?);
+        text.push('\n');
+    }
+    Ok(text)
+}
+
+/// Reads the moves of a game written in the text notation, one turn per line.
+///
+/// Blank lines and lines starting with `#` are ignored, so that comments can be
+/// added to a recorded game.
+///
+/// # Errors
+///
+/// Returns the `NotationError` of the first line that cannot be read, see
+/// `TurnRecord::parse`.
+///
+/// # Examples
+///
+/// ```
+/// use carcasonne_core::factory::game_factory::GameTilesFactory;
+/// use carcasonne_core::model::game::notation::{read_game, write_game};
+///
+/// let tiles = GameTilesFactory::build_base_game().available_tiles;
+/// let moves = read_game("# a short game\nU@0,+1 r90\nB@-1,0 r0 m:abbey\n", &tiles).unwrap();
+/// assert_eq!(moves.len(), 4);
+/// assert_eq!(write_game(&moves, &tiles).unwrap(), "U@0,+1 r90\nB@-1,0 r0 m:abbey\n");
+/// ```
+pub fn read_game(text: &str, tiles: &[Tile]) -> Result<Vec<Move>, NotationError> {
+    let mut moves = Vec::new();
+    for line in text.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        moves.extend(TurnRecord::parse(line, tiles)?.moves());
+    }
+    Ok(moves)
+}
+
+/// Returns the tile of `tiles` with the given id.
+fn find_tile(tiles: &[Tile], id: TileId) -> Result<&Tile, NotationError> {
+    tiles
+        .iter()
+        .find(|tile| tile.id == id)
+        .ok_or(NotationError::UnknownTile(id))
+}
+
+/// Returns the name of `slot` in the notation, or `None` if `tile` has no such slot.
+pub fn slot_name(tile: &Tile, slot: SlotId) -> Option<String> {
+    match slot {
+        SlotId::Feature(index) => {
+            let kind = tile.tile_features.get(index)?.kind();
+            let same_kind: Vec<usize> = (0..tile.tile_features.len())
+                .filter(|other| tile.tile_features[*other].kind() == kind)
+                .collect();
+            let name = format!("{kind:?}").to_lowercase();
+            if same_kind.len() == 1 {
+                Some(name)
+            } else {
+                let number = same_kind.iter().position(|other| *other == index)? + 1;
+                Some(format!("{name}{number}"))
+            }
+        }
+        SlotId::Extension => tile
+            .tile_extension
+            .map(|extension| format!("{extension:?}").to_lowercase()),
+    }
+}
+
+/// Writes a coordinate with its sign, except for zero.
+fn signed(value: i32) -> String {
+    if value == 0 {
+        "0".to_string()
+    } else {
+        format!("{value:+}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factory::game_factory::GameTilesFactory;
+
+    fn base_tiles() -> Vec<Tile> {
+        GameTilesFactory::build_base_game().available_tiles
+    }
+
+    #[test]
+    fn test_numbered_slots() {
+        let tiles = base_tiles();
+        let turn = TurnRecord {
+            tile: TileId('L'),
+            position: Coord::new(-2, 3),
+            rotation: Rotation::Deg270,
+            meeple: Some(SlotId::Feature(6)),
+        };
+        let text = turn.to_notation(&tiles).unwrap();
+        assert_eq!(text, "L@-2,+3 r270 m:field3");
+        assert_eq!(TurnRecord::parse(&text, &tiles), Ok(turn));
+        assert_eq!(
+            TurnRecord::parse("L@-2,3 r270 m:road1", &tiles)
+                .unwrap()
+                .meeple,
+            Some(SlotId::Feature(1))
+        );
+        assert_eq!(
+            TurnRecord::parse("L@-2,3 r270 m:town1", &tiles)
+                .unwrap()
+                .meeple,
+            Some(SlotId::Feature(0))
+        );
+        for slot in ["field", "field11"] {
+            assert_eq!(
+                TurnRecord::parse(&format!("L@-2,3 r270 m:{slot}"), &tiles),
+                Err(NotationError::UnknownSlot {
+                    tile: TileId('L'),
+                    slot: slot.to_string(),
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn test_malformed_turns() {
+        let tiles = base_tiles();
+        for text in [
+            "",
+            "D",
+            "D@1",
+            "D@1,x r0",
+            "D@1,0",
+            "D@1,0 r45",
+            "D@1,0 r0 road",
+            "D@1,0 r0 m:road x",
+        ] {
+            assert!(
+                matches!(
+                    TurnRecord::parse(text, &tiles),
+                    Err(NotationError::Malformed(_))
+                ),
+                "{text:?} should be malformed"
+            );
+        }
+        for text in ["Z@1,0 r0 m:road", "Z@1,0 r0"] {
+            assert_eq!(
+                TurnRecord::parse(text, &tiles),
+                Err(NotationError::UnknownTile(TileId('Z')))
+            );
+        }
+    }
+
+    #[test]
+    fn test_moves_must_form_turns() {
+        let placement = Move::PlaceTile {
+            tile: TileId('U'),
+            position: Coord::new(1, 0),
+            rotation: Rotation::Deg0,
+        };
+        assert_eq!(
+            TurnRecord::from_moves(&[placement, Move::SkipMeeple, placement]),
+            Err(NotationError::UnpairedMove(2))
+        );
+        assert_eq!(
+            TurnRecord::from_moves(&[placement, placement]),
+            Err(NotationError::UnpairedMove(1))
+        );
+        assert_eq!(
+            TurnRecord::from_moves(&[Move::SkipMeeple, Move::SkipMeeple]),
+            Err(NotationError::UnpairedMove(0))
+        );
+    }
+}
@@ -0,0 +1,291 @@
+use crate::model::board::Board;
+use crate::model::game::event::GameEvent;
+use crate::model::game::moves::Move;
+use crate::model::game::turn_engine::TurnPhase;
+use crate::model::game::{Game, GameTiles, RuleError};
+use crate::model::player::Player;
+use crate::model::tile::TileId;
+use crate::scoring::score_board::ScoreBoard;
+use std::fmt::{Display, Formatter};
+
+/// A move of a recorded game refused when it was replayed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReplayError {
+    /// The index of the refused move in the recorded moves.
+    pub index: usize,
+    /// Why the move was refused.
+    pub error: RuleError,
+}
+
+impl Display for ReplayError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "move {} cannot be replayed: {}", self.index, self.error)
+    }
+}
+
+impl std::error::Error for ReplayError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// The state of a replayed game after one of its moves.
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    /// The number of moves replayed so far.
+    pub moves: usize,
+    /// The tiles placed, with the meeples standing on them.
+    pub board: Board,
+    /// The points of each player, in turn order, split by category, with how they
+    /// were scored.
+    pub score_board: ScoreBoard,
+    /// The meeples left in the supply of each player, in turn order.
+    pub meeples: Vec<usize>,
+    /// The player whose turn it is.
+    pub active_player: usize,
+    /// The step the turn stands at.
+    pub phase: TurnPhase,
+    /// The tile waiting to be placed, if any.
+    pub drawn_tile: Option<TileId>,
+}
+
+/// A recorded game played again move by move, yielding a snapshot after each move.
+///
+/// Created with `Game::replay_steps`. The game can be looked at between two moves
+/// with `game`, for instance by a replay viewer.
+pub struct Replay<'a> {
+    game: Game,
+    moves: &'a [Move],
+    played: usize,
+}
+
+impl<'a> Replay<'a> {
+    /// Returns the game as it stands after the moves replayed so far.
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+
+    /// Stops the replay, and returns the game as it stands.
+    pub fn into_game(self) -> Game {
+        self.game
+    }
+
+    /// Returns the moves left to replay.
+    pub fn remaining(&self) -> &'a [Move] {
+        &self.moves[self.played..]
+    }
+
+    /// Replays the next move, and returns what happened, or `None` once every move
+    /// was replayed.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ReplayError` if the move is refused: the recorded game was played
+    /// with other tiles, players or seed.
+    pub fn step(&mut self) -> Option<Result<Vec<GameEvent>, ReplayError>> {
+        let player_move = *self.moves.get(self.played)?;
+        let result = self.game.apply(player_move).map_err(|error| ReplayError {
+            index: self.played,
+            error,
+        });
+        self.played += 1;
+        Some(result)
+    }
+
+    /// Returns the state of the game after the moves replayed so far.
+    pub fn snapshot(&self) -> Snapshot {
+        let players = self.game.players().len();
+        Snapshot {
+            moves: self.played,
+            board: self.game.board().snapshot(),
+            score_board: self.game.score_board().clone(),
+            meeples: (0..players)
+                .map(|player| self.game.meeples_left(player))
+                .collect(),
+            active_player: self.game.active_player(),
+            phase: self.game.phase(),
+            drawn_tile: self.game.drawn_tile().map(|tile| tile.id),
+        }
+    }
+}
+
+impl Iterator for Replay<'_> {
+    type Item = Result<Snapshot, ReplayError>;
+
+    /// Replays the next move, and returns the state of the game after it. A refused
+    /// move ends the replay.
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.step()? {
+            Ok(_) => Some(Ok(self.snapshot())),
+            Err(error) => {
+                self.played = self.moves.len();
+                Some(Err(error))
+            }
+        }
+    }
+}
+
+impl Game {
+    /// Plays a recorded game again from its moves, and returns the game once they are
+    /// all replayed.
+    ///
+    /// The game starts from `tiles` with `players`, and draws its tiles with a
+    /// generator seeded with `seed`: with the seed the game was recorded with, the
+    /// same tiles are drawn in the same order.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ReplayError` telling which move was refused.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use carcasonne_core::factory::game_factory::GameTilesFactory;
+    /// use carcasonne_core::model::game::moves::Move;
+    /// use carcasonne_core::model::game::Game;
+    /// use carcasonne_core::model::player::Player;
+    ///
+    /// let mut tiles = GameTilesFactory::build_base_game();
+    /// tiles.seed = Some(7);
+    /// let mut game = Game::new(tiles, Player::local_players(2));
+    /// game.advance();
+    /// let mut moves = vec![];
+    /// for _ in 0..3 {
+    ///     let tile = game.drawn_tile().unwrap().clone();
+    ///     let (position, rotation) = game.board().legal_placements(&tile)[0];
+    ///     for player_move in [Move::PlaceTile { tile: tile.id, position, rotation }, Move::SkipMeeple] {
+    ///         game.apply(player_move).unwrap();
+    ///         moves.push(player_move);
+    ///     }
+    /// }
+    ///
+    /// let replayed = Game::replay(
+    ///     GameTilesFactory::build_base_game(),
+    ///     Player::local_players(2),
+    ///     &moves,
+    ///     7,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(replayed.board().hash(), game.board().hash());
+    /// ```
+    pub fn replay(
+        tiles: GameTiles,
+        players: Vec<Player>,
+        moves: &[Move],
+        seed: u64,
+    ) -> Result<Game, ReplayError> {
+        let mut replay = Game::replay_steps(tiles, players, moves, seed);
+        while let Some(result) = replay.step() {
+            result?;
+        }
+        Ok(replay.into_game())
+    }
+
+    /// Starts replaying a recorded game, one move at a time, as `replay` does.
+    ///
+    /// The first tile is drawn right away; each move is then replayed when the
+    /// returned `Replay` is advanced.
+    pub fn replay_steps(
+        mut tiles: GameTiles,
+        players: Vec<Player>,
+        moves: &[Move],
+        seed: u64,
+    ) -> Replay<'_> {
+        tiles.seed = Some(seed);
+        let mut game = Game::new(tiles, players);
+        game.advance();
+        Replay {
+            game,
+            moves,
+            played: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factory::game_factory::GameTilesFactory;
+
+    fn recorded_game(seed: u64, turns: usize) -> (Game, Vec<Move>) {
+        let mut tiles = GameTilesFactory::build_base_game();
+        tiles.seed = Some(seed);
+        let mut game = Game::new(tiles, Player::local_players(3));
+        game.advance();
+        let mut moves = Vec::new();
+        for _ in 0..turns {
+            let tile = game.drawn_tile().unwrap().clone();
+            let (position, rotation) = game.board().legal_placements(&tile)[0];
+            let placement = Move::PlaceTile {
+                tile: tile.id,
+                position,
+                rotation,
+            };
+            game.apply(placement).unwrap();
+            let meeple = match game.legal_meeple_placements(position).first() {
+                Some(slot) if game.meeples_left(game.active_player()) > 0 => {
+                    Move::PlaceMeeple(*slot)
+                }
+                _ => Move::SkipMeeple,
+            };
+            game.apply(meeple).unwrap();
+            moves.extend([placement, meeple]);
+        }
+        (game, moves)
+    }
+
+    #[test]
+    fn test_replay_rebuilds_the_game() {
+        let (game, moves) = recorded_game(11, 20);
+        let replayed = Game::replay(
+            GameTilesFactory::build_base_game(),
+            Player::local_players(3),
+            &moves,
+            11,
+        )
+        .unwrap();
+        assert_eq!(replayed.board().hash(), game.board().hash());
+        assert_eq!(replayed.scores(), game.scores());
+        assert_eq!(replayed.score_board(), game.score_board());
+        assert_eq!(game.score_board().check_consistency(), Ok(()));
+        assert_eq!(replayed.turn(), game.turn());
+        assert_eq!(
+            replayed.drawn_tile().map(|tile| tile.id),
+            game.drawn_tile().map(|tile| tile.id)
+        );
+    }
+
+    #[test]
+    fn test_snapshots_follow_each_move() {
+        let (_, moves) = recorded_game(5, 4);
+        let snapshots: Vec<Snapshot> = Game::replay_steps(
+            GameTilesFactory::build_base_game(),
+            Player::local_players(3),
+            &moves,
+            5,
+        )
+        .collect::<Result<_, _>>()
+        .unwrap();
+        assert_eq!(snapshots.len(), 8);
+        assert_eq!(snapshots[0].moves, 1);
+        assert_eq!(snapshots[0].board.len(), 2);
+        assert_eq!(snapshots[0].phase, TurnPhase::PlaceMeeple);
+        assert_eq!(snapshots[1].active_player, 1);
+        assert_eq!(snapshots[7].board.len(), 5);
+        assert_eq!(snapshots[7].score_board.scores().len(), 3);
+    }
+
+    #[test]
+    fn test_replay_with_another_seed_is_refused() {
+        let (_, moves) = recorded_game(5, 6);
+        let error = Game::replay(
+            GameTilesFactory::build_base_game(),
+            Player::local_players(3),
+            &moves,
+            6,
+        )
+        .err()
+        .unwrap();
+        assert!(matches!(error.error, RuleError::WrongTile { .. }));
+    }
+}
@@ -0,0 +1,401 @@
+use crate::model::game::RuleError;
+use crate::model::game::clock::{Clocks, TimeControl};
+use crate::model::game::moves::Move;
+use std::time::Duration;
+
+/// The steps of a turn, in the order they are played.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TurnPhase {
+    /// The active player draws a tile from the bag.
+    DrawTile,
+    /// The active player places the drawn tile on the board.
+    PlaceTile,
+    /// The active player may put a meeple on the tile just placed.
+    PlaceMeeple,
+    /// The features completed by the placement are scored.
+    Score,
+    /// The bag is empty, or a single player is left: no more turns are played.
+    GameOver,
+}
+
+/// Drives the sequence of turns: draw → place tile → place meeple → score → next player.
+///
+/// The engine only tracks where the game stands; `Game` performs the steps and
+/// asks the engine whether they are allowed. Keeping the sequence in one place lets
+/// every front end, whether the text UI, the API or an AI player, follow the same rules.
+///
+/// # Examples
+///
+/// ```
+/// use carcasonne_core::model::game::turn_engine::{TurnEngine, TurnPhase};
+///
+/// let mut engine = TurnEngine::new(2);
+/// assert_eq!(engine.phase(), TurnPhase::DrawTile);
+/// for _ in 0..4 {
+///     engine.advance();
+/// }
+/// assert_eq!(engine.phase(), TurnPhase::DrawTile);
+/// assert_eq!(engine.active_player(), 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TurnEngine {
+    phase: TurnPhase,
+    player_count: usize,
+    active_player: usize,
+    turn: usize,
+    resigned: Vec<bool>,
+    pending: Vec<Move>,
+    clocks: Option<Clocks>,
+}
+
+impl TurnEngine {
+    /// Creates an engine where the first of `player_count` players is about to draw.
+    pub fn new(player_count: usize) -> Self {
+        Self {
+            phase: TurnPhase::DrawTile,
+            player_count,
+            active_player: 0,
+            turn: 1,
+            resigned: vec![false; player_count],
+            pending: Vec::new(),
+            clocks: None,
+        }
+    }
+
+    /// Gives each player a clock following `control`: the time they take to decide is
+    /// counted with `spend_time`.
+    pub fn with_clocks(mut self, control: TimeControl) -> Self {
+        self.clocks = Some(Clocks::new(control, self.player_count));
+        self
+    }
+
+    /// Returns the clocks of the players, if the game is timed.
+    pub fn clocks(&self) -> Option<&Clocks> {
+        self.clocks.as_ref()
+    }
+
+    /// Returns the time `player` has left, if the game is timed.
+    pub fn remaining_time(&self, player: usize) -> Option<Duration> {
+        self.clocks.as_ref().map(|clocks| clocks.remaining(player))
+    }
+
+    /// Takes `elapsed` off the clock of the active player while they have a decision
+    /// to make, and returns whether their clock ran out.
+    ///
+    /// Time is not counted in an untimed game, nor while no decision is awaited.
+    pub fn spend_time(&mut self, elapsed: Duration) -> bool {
+        let player = self.active_player;
+        match (&mut self.clocks, self.phase) {
+            (Some(clocks), TurnPhase::PlaceTile | TurnPhase::PlaceMeeple) => {
+                clocks.spend(player, elapsed)
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the current step of the turn.
+    pub fn phase(&self) -> TurnPhase {
+        self.phase
+    }
+
+    /// Returns the index, in turn order, of the player whose turn it is.
+    pub fn active_player(&self) -> usize {
+        self.active_player
+    }
+
+    /// Returns the number of the current turn, starting from 1.
+    pub fn turn(&self) -> usize {
+        self.turn
+    }
+
+    /// Returns whether `player` resigned, leaving the rotation.
+    pub fn has_resigned(&self, player: usize) -> bool {
+        self.resigned.get(player).copied().unwrap_or(false)
+    }
+
+    /// Returns the number of players still in the rotation.
+    pub fn players_left(&self) -> usize {
+        self.resigned.iter().filter(|resigned| !**resigned).count()
+    }
+
+    /// Returns the moves played during the current turn, which can still be undone
+    /// until the turn is scored.
+    pub fn pending(&self) -> &[Move] {
+        &self.pending
+    }
+
+    /// Records a move the active player just played, to be undone if they change
+    /// their mind before the turn is scored.
+    pub fn push_pending(&mut self, player_move: Move) {
+        self.pending.push(player_move);
+    }
+
+    /// Takes back the last move of the turn, going back to the step it was played at.
+    ///
+    /// Returns `None` when no move is pending: the turn was already scored, or
+    /// nothing was played yet.
+    pub fn undo(&mut self) -> Option<Move> {
+        let player_move = self.pending.pop()?;
+        self.phase = match player_move {
+            Move::PlaceTile { .. } => TurnPhase::PlaceTile,
+            Move::PlaceMeeple(_) | Move::SkipMeeple => TurnPhase::PlaceMeeple,
+        };
+        Some(player_move)
+    }
+
+    /// Checks that the game stands at the given step.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RuleError::WrongPhase` if the current step is another one.
+    pub fn expect(&self, phase: TurnPhase) -> Result<(), RuleError> {
+        if self.phase == phase {
+            Ok(())
+        } else {
+            Err(RuleError::WrongPhase {
+                expected: phase,
+                actual: self.phase,
+            })
+        }
+    }
+
+    /// Moves on to the next step, passing the turn to the next player after scoring.
+    ///
+    /// Nothing happens once the game is over.
+    pub fn advance(&mut self) {
+        self.phase = match self.phase {
+            TurnPhase::DrawTile => TurnPhase::PlaceTile,
+            TurnPhase::PlaceTile => TurnPhase::PlaceMeeple,
+            TurnPhase::PlaceMeeple => TurnPhase::Score,
+            TurnPhase::Score => {
+                self.pass_turn();
+                TurnPhase::DrawTile
+            }
+            TurnPhase::GameOver => TurnPhase::GameOver,
+        };
+    }
+
+    /// Declines to put a meeple on the tile just placed, moving straight on to scoring.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RuleError::WrongPhase` unless a meeple may be placed.
+    pub fn skip_meeple(&mut self) -> Result<(), RuleError> {
+        self.expect(TurnPhase::PlaceMeeple)?;
+        self.advance();
+        Ok(())
+    }
+
+    /// Removes `player` from the rotation: their turns are skipped from now on.
+    ///
+    /// If it is their turn and their tile is not placed yet, the turn passes to the
+    /// next player. A tile they already placed is still scored, and can no longer be
+    /// undone.
+    pub fn resign(&mut self, player: usize) {
+        let Some(resigned) = self.resigned.get_mut(player) else {
+            return;
+        };
+        *resigned = true;
+        if player == self.active_player {
+            self.skip_turn();
+        }
+    }
+
+    /// Ends the turn of the active player early, as when their clock runs out.
+    ///
+    /// If their tile is not placed yet, the turn passes to the next player. A tile they
+    /// already placed is left without a meeple and still scored, and can no longer be
+    /// undone.
+    pub fn skip_turn(&mut self) {
+        match self.phase {
+            TurnPhase::DrawTile | TurnPhase::PlaceTile => {
+                self.pass_turn();
+                self.phase = TurnPhase::DrawTile;
+            }
+            TurnPhase::PlaceMeeple => {
+                self.pending.clear();
+                self.phase = TurnPhase::Score;
+            }
+            TurnPhase::Score | TurnPhase::GameOver => {}
+        }
+    }
+
+    /// Gives the turn to the next player still in the rotation, confirming the moves
+    /// of the turn and adding the increment to the clock of the player who ends it.
+    fn pass_turn(&mut self) {
+        self.pending.clear();
+        if let Some(clocks) = &mut self.clocks {
+            clocks.add_increment(self.active_player);
+        }
+        for _ in 0..self.player_count {
+            self.active_player = (self.active_player + 1) % self.player_count;
+            if !self.resigned[self.active_player] {
+                break;
+            }
+        }
+        self.turn += 1;
+    }
+
+    /// Ends the game: no step is played anymore.
+    pub fn end_game(&mut self) {
+        self.pending.clear();
+        self.phase = TurnPhase::GameOver;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::board::Coord;
+    use crate::model::tile::{Rotation, TileId};
+
+    #[test]
+    fn test_phases_follow_each_other() {
+        let mut engine = TurnEngine::new(3);
+        let mut phases = vec![];
+        for _ in 0..5 {
+            phases.push((engine.phase(), engine.active_player(), engine.turn()));
+            engine.advance();
+        }
+        assert_eq!(
+            phases,
+            vec![
+                (TurnPhase::DrawTile, 0, 1),
+                (TurnPhase::PlaceTile, 0, 1),
+                (TurnPhase::PlaceMeeple, 0, 1),
+                (TurnPhase::Score, 0, 1),
+                (TurnPhase::DrawTile, 1, 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_turn_wraps_around_players() {
+        let mut engine = TurnEngine::new(2);
+        for _ in 0..8 {
+            engine.advance();
+        }
+        assert_eq!(engine.active_player(), 0);
+        assert_eq!(engine.turn(), 3);
+    }
+
+    #[test]
+    fn test_expect_and_end_game() {
+        let mut engine = TurnEngine::new(2);
+        assert_eq!(engine.expect(TurnPhase::DrawTile), Ok(()));
+        assert_eq!(
+            engine.expect(TurnPhase::Score),
+            Err(RuleError::WrongPhase {
+                expected: TurnPhase::Score,
+                actual: TurnPhase::DrawTile,
+            })
+        );
+
+        engine.end_game();
+        engine.advance();
+        assert_eq!(engine.phase(), TurnPhase::GameOver);
+    }
+
+    #[test]
+    fn test_resigned_players_are_skipped() {
+        let mut engine = TurnEngine::new(3);
+        engine.resign(1);
+        assert!(engine.has_resigned(1));
+        assert_eq!(engine.players_left(), 2);
+        for _ in 0..4 {
+            engine.advance();
+        }
+        assert_eq!(engine.active_player(), 2);
+    }
+
+    #[test]
+    fn test_resigning_during_own_turn() {
+        let mut engine = TurnEngine::new(3);
+        engine.advance();
+        engine.resign(0);
+        assert_eq!(engine.phase(), TurnPhase::DrawTile);
+        assert_eq!(engine.active_player(), 1);
+        assert_eq!(engine.turn(), 2);
+
+        engine.advance();
+        engine.advance();
+        engine.resign(1);
+        assert_eq!(engine.phase(), TurnPhase::Score);
+        engine.advance();
+        assert_eq!(engine.active_player(), 2);
+    }
+
+    #[test]
+    fn test_undo_goes_back_until_scoring() {
+        let mut engine = TurnEngine::new(2);
+        assert_eq!(engine.undo(), None);
+        engine.advance();
+        engine.advance();
+        engine.push_pending(Move::PlaceTile {
+            tile: TileId('D'),
+            position: Coord::new(1, 0),
+            rotation: Rotation::Deg0,
+        });
+        engine.skip_meeple().unwrap();
+        engine.push_pending(Move::SkipMeeple);
+        assert_eq!(engine.pending().len(), 2);
+
+        assert_eq!(engine.undo(), Some(Move::SkipMeeple));
+        assert_eq!(engine.phase(), TurnPhase::PlaceMeeple);
+        assert!(matches!(engine.undo(), Some(Move::PlaceTile { .. })));
+        assert_eq!(engine.phase(), TurnPhase::PlaceTile);
+
+        engine.push_pending(Move::SkipMeeple);
+        for _ in 0..3 {
+            engine.advance();
+        }
+        assert!(engine.pending().is_empty());
+        assert_eq!(engine.undo(), None);
+    }
+
+    #[test]
+    fn test_clock_runs_only_during_decisions() {
+        let control = TimeControl::new(Duration::from_secs(30), Duration::from_secs(10));
+        let mut engine = TurnEngine::new(2).with_clocks(control);
+        assert_eq!(TurnEngine::new(2).remaining_time(0), None);
+
+        assert!(!engine.spend_time(Duration::from_secs(20)));
+        assert_eq!(engine.remaining_time(0), Some(Duration::from_secs(30)));
+        engine.advance();
+        assert!(!engine.spend_time(Duration::from_secs(20)));
+        for _ in 0..3 {
+            engine.advance();
+        }
+        assert_eq!(engine.remaining_time(0), Some(Duration::from_secs(20)));
+        engine.advance();
+        assert!(engine.spend_time(Duration::from_secs(40)));
+        assert_eq!(engine.remaining_time(1), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn test_skip_turn_passes_or_scores() {
+        let mut engine = TurnEngine::new(2);
+        engine.advance();
+        engine.skip_turn();
+        assert_eq!(engine.phase(), TurnPhase::DrawTile);
+        assert_eq!(engine.active_player(), 1);
+
+        engine.advance();
+        engine.advance();
+        engine.skip_turn();
+        assert_eq!(engine.phase(), TurnPhase::Score);
+        assert_eq!(engine.active_player(), 1);
+    }
+
+    #[test]
+    fn test_skip_meeple_moves_on_to_scoring() {
+        let mut engine = TurnEngine::new(2);
+        assert!(engine.skip_meeple().is_err());
+
+        engine.advance();
+        engine.advance();
+        assert_eq!(engine.skip_meeple(), Ok(()));
+        assert_eq!(engine.phase(), TurnPhase::Score);
+        assert_eq!(engine.active_player(), 0);
+    }
+}
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+/// The number of meeples each player starts the game with.
+pub const MEEPLES_PER_PLAYER: usize = 7;
+
+/// Identifies a place of a tile where a meeple can stand.
+///
+/// Identifiers only depend on the tile definition, not on its rotation, so they
+/// can be logged and replayed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum SlotId {
+    /// The feature at the given index in `Tile::tile_features`.
+    Feature(usize),
+    /// The tile extension, such as an abbey.
+    Extension,
+}
+
+/// A follower placed by a player on one of the slots of a tile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Meeple {
+    /// The index, in turn order, of the player owning the meeple.
+    pub player: usize,
+    /// The slot of the tile the meeple stands on.
+    pub slot: SlotId,
+}
+
+impl Meeple {
+    /// Creates a meeple owned by `player`, standing on `slot`.
+    pub fn new(player: usize, slot: SlotId) -> Self {
+        Self { player, slot }
+    }
+}
@@ -0,0 +1,60 @@
+/// The minimum number of players in a game.
+pub const MIN_PLAYERS: usize = 2;
+
+/// The maximum number of players in a game.
+pub const MAX_PLAYERS: usize = 5;
+
+/// A player taking part in a game.
+///
+/// Players are identified by their index in turn order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Player {
+    /// The name displayed for the player.
+    pub name: String,
+}
+
+impl Player {
+    /// Creates a new player with the given name.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into() }
+    }
+
+    /// Creates `count` local players named `Player 1`, `Player 2`, ...
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use carcasonne_core::model::player::Player;
+    ///
+    /// let players = Player::local_players(2);
+    /// assert_eq!(players[1].name, "Player 2");
+    /// ```
+    pub fn local_players(count: usize) -> Vec<Player> {
+        (1..=count)
+            .map(|i| Player::new(format!("Player {i}")))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_local_players() {
+        let players = Player::local_players(3);
+        assert_eq!(
+            players,
+            vec![
+                Player::new("Player 1"),
+                Player::new("Player 2"),
+                Player::new("Player 3")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_no_local_players() {
+        assert!(Player::local_players(0).is_empty());
+    }
+}
@@ -0,0 +1,126 @@
+use serde::{Deserialize, Serialize};
+
+/// How fields are scored at the end of the game.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum FieldScoring {
+    /// Each field is worth `FIELD_TOWN_POINTS` for every completed town it borders,
+    /// as in the current edition.
+    #[default]
+    PerCompletedTown,
+    /// As in the first edition, each completed town is scored once, worth
+    /// `FIRST_EDITION_FIELD_TOWN_POINTS` to the players with the most farmers in all
+    /// the fields bordering it.
+    FirstEdition,
+}
+
+/// When abbeys are scored.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AbbeyCompletion {
+    /// An abbey is scored as soon as eight tiles surround it, and its monk goes back
+    /// to its owner.
+    #[default]
+    Surrounded,
+    /// Abbeys are only scored at the end of the game, one point for the abbey and each
+    /// tile around it: monks stay on the board for the whole game.
+    GameEndOnly,
+}
+
+/// How the points of a feature are given to players tied for its majority.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SharedMajority {
+    /// Each tied player receives the full points.
+    #[default]
+    FullPoints,
+    /// The points are split between the tied players, rounded down.
+    SplitPoints,
+}
+
+/// How players tied for points are ranked.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TieBreak {
+    /// Tied players share their place, and the win.
+    #[default]
+    SharedWin,
+    /// The player who completed the most features ranks first.
+    MostCompletedFeatures,
+    /// The player with the fewest meeples left on the board at the end of the game
+    /// ranks first.
+    FewestStrandedMeeples,
+}
+
+/// The rules a game is played with, where house rules and editions differ.
+///
+/// The default follows the rules of the base game.
+///
+/// # Examples
+///
+/// ```
+/// use carcasonne_core::model::rules::{RulesConfig, SharedMajority};
+///
+/// let rules = RulesConfig {
+///     farmers: false,
+///     shared_majority: SharedMajority::SplitPoints,
+///     ..RulesConfig::default()
+/// };
+/// assert!(RulesConfig::default().farmers);
+/// assert_ne!(rules, RulesConfig::default());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RulesConfig {
+    /// How fields are scored.
+    pub field_scoring: FieldScoring,
+    /// Whether meeples may lie in fields as farmers. Fields are not scored without them.
+    pub farmers: bool,
+    /// When abbeys are scored.
+    pub abbey_completion: AbbeyCompletion,
+    /// How features are scored when several players are tied for their majority.
+    pub shared_majority: SharedMajority,
+    /// How players tied for points are ranked.
+    pub tie_break: TieBreak,
+}
+
+impl Default for RulesConfig {
+    fn default() -> Self {
+        Self {
+            field_scoring: FieldScoring::default(),
+            farmers: true,
+            abbey_completion: AbbeyCompletion::default(),
+            shared_majority: SharedMajority::default(),
+            tie_break: TieBreak::default(),
+        }
+    }
+}
+
+impl RulesConfig {
+    /// Returns the points each of `players` receives for a feature worth `points`.
+    pub fn points_per_player(&self, points: u32, players: usize) -> u32 {
+        match self.shared_majority {
+            SharedMajority::SplitPoints if players > 1 => points / players as u32,
+            _ => points,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_points_round_down() {
+        let rules = RulesConfig {
+            shared_majority: SharedMajority::SplitPoints,
+            ..RulesConfig::default()
+        };
+        assert_eq!(rules.points_per_player(9, 1), 9);
+        assert_eq!(rules.points_per_player(9, 2), 4);
+        assert_eq!(RulesConfig::default().points_per_player(9, 2), 9);
+    }
+
+    #[test]
+    fn test_missing_rules_use_defaults() {
+        let rules: RulesConfig = serde_json::from_str(r#"{"farmers": false}"#).unwrap();
+        assert!(!rules.farmers);
+        assert_eq!(rules.abbey_completion, AbbeyCompletion::Surrounded);
+    }
+}
@@ -1,18 +1,823 @@
+use crate::model::meeple::SlotId;
 use crate::model::tile_extension::TileExtension;
-use crate::model::tile_feature::TileFeature;
+use crate::model::tile_feature::{
+    Edge, EdgeSegment, FeatureKind, SegmentSet, Side, TileFeature, TileFeatureEnhancement,
+};
+use crate::model::tile_meta::TileMeta;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// The identifier of a tile definition.
+///
+/// Base game tiles use their official letter code, from `A` to `X`. Every copy of
+/// the same tile definition shares the same identifier, so games can be logged
+/// and replayed by tile id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct TileId(pub char);
+
+impl Display for TileId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
 
 /// Represents a tile in the game, composed of visual and behavioral elements.
 ///
 /// A `Tile` combines a set of structural features (like roads or cities)
-/// with optional extended behavior through a `TileExtension` trait object.
-#[derive(Debug, Clone)]
+/// with an optional `TileExtension`, such as an abbey.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Tile {
+    /// The identifier of the tile definition this tile was built from.
+    pub id: TileId,
+
     /// The features present on the tile (e.g., roads, cities).
     pub tile_features: Vec<TileFeature>,
 
-    /// An optional extension providing additional behavior or metadata.
+    /// An optional extension providing additional behavior or metadata
+    /// (e.g., an abbey in the middle of the tile).
+    pub tile_extension: Option<TileExtension>,
+
+    /// The expansion and print run of the tile, set by the tile factories.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub meta: Option<TileMeta>,
+}
+
+/// A description of a tile layout that does not depend on its orientation.
+///
+/// Two tiles have the same canonical form when one can be rotated onto the other,
+/// whatever their identifiers. See `Tile::canonical_form`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CanonicalForm {
+    /// The kind, edge segments and enhancement of each feature, sorted.
+    features: Vec<(FeatureKind, SegmentSet, Option<TileFeatureEnhancement>)>,
+    /// The tile extension, if any.
+    extension: Option<TileExtension>,
+}
+
+/// The width and height, in characters, of the text representation of a tile.
+pub const TILE_ASCII_SIZE: usize = 5;
+
+/// A clockwise rotation applied to a tile, in quarter turns.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Rotation {
+    /// The canonical orientation of the tile.
+    #[default]
+    Deg0,
+    /// A quarter turn clockwise.
+    Deg90,
+    /// A half turn.
+    Deg180,
+    /// Three quarter turns clockwise (a quarter turn counterclockwise).
+    Deg270,
+}
+
+impl Rotation {
+    /// All rotations, from the canonical orientation onward.
+    pub const ALL: [Rotation; 4] = [
+        Rotation::Deg0,
+        Rotation::Deg90,
+        Rotation::Deg180,
+        Rotation::Deg270,
+    ];
+
+    /// Returns the number of clockwise quarter turns of this rotation.
+    pub fn quarter_turns(self) -> usize {
+        match self {
+            Rotation::Deg0 => 0,
+            Rotation::Deg90 => 1,
+            Rotation::Deg180 => 2,
+            Rotation::Deg270 => 3,
+        }
+    }
+
+    /// Returns the rotation obtained by applying `self` then `other`.
+    pub fn then(self, other: Rotation) -> Rotation {
+        Rotation::ALL[(self.quarter_turns() + other.quarter_turns()) % Rotation::ALL.len()]
+    }
+
+    /// Returns the rotation undoing this one.
+    pub fn inverse(self) -> Rotation {
+        Rotation::ALL[(Rotation::ALL.len() - self.quarter_turns()) % Rotation::ALL.len()]
+    }
+}
+
+impl Tile {
+    /// Rotates the tile clockwise, remapping the edges and segments of all its features.
+    ///
+    /// The tile extension is not tied to any edge and is left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use carcasonne_core::model::tile::{Rotation, Tile, TileId};
+    /// use carcasonne_core::model::tile_feature::{Edge, EdgeSet, SegmentSet, TileFeature, Town};
+    ///
+    /// let mut tile = Tile {
+    ///     id: TileId('E'),
+    ///     tile_features: vec![TileFeature {
+    ///         feature_type: Box::new(Town {}),
+    ///         edges: EdgeSet::from([Edge::North]),
+    ///         segments: SegmentSet::from([Edge::North]),
+    ///         enhancement: None,
+    ///     }],
+    ///     tile_extension: None,
+    ///     meta: None,
+    /// };
+    /// tile.rotate(Rotation::Deg90);
+    /// assert_eq!(tile.tile_features[0].edges, EdgeSet::from([Edge::East]));
+    /// ```
+    pub fn rotate(&mut self, rotation: Rotation) {
+        for feature in &mut self.tile_features {
+            feature.edges = feature.edges.rotated(rotation);
+            feature.segments = feature.segments.rotated(rotation);
+        }
+    }
+
+    /// Returns the index in `tile_features` of the town or road reaching the given edge, if any.
+    ///
+    /// Each feature is one connected segment: edges listed in the same feature are
+    /// linked through the tile, while edges of two features are not. Fields are
+    /// ignored, as they may only cover part of an edge.
+    pub fn feature_index_at(&self, edge: Edge) -> Option<usize> {
+        self.tile_features
+            .iter()
+            .position(|feature| !feature.is_field() && feature.edges.contains(edge))
+    }
+
+    /// Returns the index in `tile_features` of the field covering the given edge segment, if any.
+    ///
+    /// Unlike towns and roads, fields are told apart by segment: the two fields on
+    /// either side of a road share its edge, but not the left and right parts of it.
+    pub fn field_index_at(&self, segment: EdgeSegment) -> Option<usize> {
+        self.tile_features
+            .iter()
+            .position(|feature| feature.is_field() && feature.segments.contains(segment))
+    }
+
+    /// Returns the index in `tile_features` of the town, road or field covering the
+    /// given edge segment, if any.
+    ///
+    /// Towns cover all the segments of their edges and roads the center one only.
+    pub fn feature_index_covering(&self, segment: EdgeSegment) -> Option<usize> {
+        self.tile_features
+            .iter()
+            .position(|feature| feature.segments.contains(segment))
+    }
+
+    /// Returns the kind of terrain found at the given edge segment.
+    ///
+    /// Segments no town or road reaches are fields, even when the tile does not
+    /// define its fields. Two tiles can lie side by side when the kinds of all
+    /// their touching segments match.
+    pub fn kind_at(&self, segment: EdgeSegment) -> FeatureKind {
+        self.tile_features
+            .iter()
+            .find(|feature| !feature.is_field() && feature.segments.contains(segment))
+            .map_or(FeatureKind::Field, TileFeature::kind)
+    }
+
+    /// Returns `true` if a single town or road of the tile links the two edges.
+    ///
+    /// This tells apart a town spanning two edges (tile F) from two separate towns
+    /// on those edges (tile H). An edge is always connected to itself when a
+    /// town or road reaches it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use carcasonne_core::model::tile::{Tile, TileId};
+    /// use carcasonne_core::model::tile_feature::{Edge, EdgeSet, TileFeature, Town};
+    ///
+    /// let town = |edges: EdgeSet| TileFeature {
+    ///     feature_type: Box::new(Town {}),
+    ///     edges,
+    ///     segments: edges.into(),
+    ///     enhancement: None,
+    /// };
+    /// let h = Tile {
+    ///     id: TileId('H'),
+    ///     tile_features: vec![town(EdgeSet::from([Edge::West])), town(EdgeSet::from([Edge::East]))],
+    ///     tile_extension: None,
+    ///     meta: None,
+    /// };
+    /// assert!(!h.connected(Edge::West, Edge::East));
+    /// ```
+    pub fn connected(&self, a: Edge, b: Edge) -> bool {
+        self.tile_features.iter().any(|feature| {
+            !feature.is_field() && feature.edges.contains(a) && feature.edges.contains(b)
+        })
+    }
+
+    /// Returns the indices in `tile_features` of the towns bordering the field at
+    /// `field`, in ascending order, or none if there is no field at `field`.
+    ///
+    /// Going around the tile, a town borders a field when one of its segments comes
+    /// right before or after a segment of the field. A road running between them
+    /// keeps them apart.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use carcasonne_core::builder::tile_builder::TileBuilder;
+    /// use carcasonne_core::model::tile::TileId;
+    /// use carcasonne_core::model::tile_feature::Edge::{East, North, South, West};
+    /// use carcasonne_core::model::tile_feature::{SegmentSet, Side};
+    ///
+    /// let d = TileBuilder::new(TileId('D'))
+    ///     .add_town(vec![North])
+    ///     .add_road(vec![West, East])
+    ///     .add_field([West.segment(Side::Right), East.segment(Side::Left)])
+    ///     .add_field(SegmentSet::from([South]).with(East.segment(Side::Right)).with(West.segment(Side::Left)))
+    ///     .build();
+    /// assert_eq!(d.towns_bordering(2), vec![0]);
+    /// assert!(d.towns_bordering(3).is_empty());
+    /// ```
+    pub fn towns_bordering(&self, field: usize) -> Vec<usize> {
+        let Some(field) = self
+            .tile_features
+            .get(field)
+            .filter(|field| field.is_field())
+        else {
+            return Vec::new();
+        };
+        let all = EdgeSegment::ALL;
+        let mut towns: Vec<usize> = (0..all.len())
+            .filter(|i| field.segments.contains(all[*i]))
+            .flat_map(|i| {
+                [
+                    all[(i + all.len() - 1) % all.len()],
+                    all[(i + 1) % all.len()],
+                ]
+            })
+            .filter_map(|segment| {
+                self.tile_features.iter().position(|feature| {
+                    feature.kind() == FeatureKind::Town && feature.segments.contains(segment)
+                })
+            })
+            .collect();
+        towns.sort_unstable();
+        towns.dedup();
+        towns
+    }
+
+    /// Returns the slots of the tile where a meeple could be placed.
+    ///
+    /// Every town, road and field segment is a slot of its own, followed by the
+    /// tile extension if it accepts meeples. Gardens are skipped, as only the abbot
+    /// may stand on them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use carcasonne_core::builder::tile_builder::TileBuilder;
+    /// use carcasonne_core::model::meeple::SlotId;
+    /// use carcasonne_core::model::tile::TileId;
+    /// use carcasonne_core::model::tile_feature::Edge::{East, North, South, West};
+    ///
+    /// let tile = TileBuilder::new(TileId('A'))
+    ///     .add_road(vec![South])
+    ///     .add_field(vec![North, East, South, West])
+    ///     .add_abbey()
+    ///     .build();
+    /// assert_eq!(
+    ///     tile.placement_slots(),
+    ///     vec![SlotId::Feature(0), SlotId::Feature(1), SlotId::Extension]
+    /// );
+    /// ```
+    pub fn placement_slots(&self) -> Vec<SlotId> {
+        let mut slots: Vec<SlotId> = (0..self.tile_features.len()).map(SlotId::Feature).collect();
+        if self
+            .tile_extension
+            .is_some_and(|extension| extension.accepts_meeples())
+        {
+            slots.push(SlotId::Extension);
+        }
+        slots
+    }
+
+    /// Returns the layout of the tile in a form that is identical for all its rotations.
+    ///
+    /// Among the four orientations of the tile, the one with the smallest sorted list
+    /// of features is kept. The tile identifier is not part of the form.
+    pub fn canonical_form(&self) -> CanonicalForm {
+        let features = Rotation::ALL
+            .into_iter()
+            .map(|rotation| {
+                let mut features: Vec<_> = self
+                    .tile_features
+                    .iter()
+                    .map(|feature| {
+                        (
+                            feature.kind(),
+                            feature.segments.rotated(rotation),
+                            feature.enhancement,
+                        )
+                    })
+                    .collect();
+                features.sort();
+                features
+            })
+            .min()
+            .unwrap_or_default();
+
+        CanonicalForm {
+            features,
+            extension: self.tile_extension,
+        }
+    }
+
+    /// Returns `true` if `other` has the same layout as this tile, up to a rotation.
+    ///
+    /// Tile identifiers are ignored, so two mis-built definitions describing the
+    /// same tile are reported as equivalent.
+    pub fn equivalent_to(&self, other: &Tile) -> bool {
+        self.canonical_form() == other.canonical_form()
+    }
+}
+
+impl Tile {
+    /// Draws the tile, as currently oriented, into a square block of characters.
     ///
-    /// This is implemented as a trait object, allowing different tile types
-    /// (e.g., monastery) to extend base functionality.
-    pub tile_extension: Option<Box<dyn TileExtension>>,
+    /// Rows are returned from top to bottom. Fields are drawn with `.`, towns cover
+    /// the rows or columns of their edges with `#`, roads run from each of their
+    /// edges towards a `+` in the center with `|` and `-`, shields are marked with
+    /// `*`, abbeys and monasteries with `A` and gardens with `G`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use carcasonne_core::builder::tile_builder::TileBuilder;
+    /// use carcasonne_core::model::tile::TileId;
+    /// use carcasonne_core::model::tile_feature::Edge::{East, North, West};
+    ///
+    /// let tile = TileBuilder::new(TileId('D'))
+    ///     .add_town(vec![North])
+    ///     .add_road(vec![West, East])
+    ///     .build();
+    /// assert_eq!(tile.to_string(), "#####\n.....\n--+--\n.....\n.....");
+    /// ```
+    pub fn to_ascii(&self) -> [[char; TILE_ASCII_SIZE]; TILE_ASCII_SIZE] {
+        let mut cells = [['.'; TILE_ASCII_SIZE]; TILE_ASCII_SIZE];
+        let mut draw = |(x, y): (usize, usize), c: char| cells[y][x] = c;
+        let mid = TILE_ASCII_SIZE / 2;
+
+        for feature in &self.tile_features {
+            match feature.kind() {
+                FeatureKind::Town => {
+                    for edge in feature.edges.iter() {
+                        for i in 0..TILE_ASCII_SIZE {
+                            draw(Self::town_cell(edge, i), '#');
+                        }
+                    }
+                    if let (Some(_), Some(edge)) =
+                        (feature.enhancement, feature.edges.iter().next())
+                    {
+                        draw(Self::shield_cell(edge), '*');
+                    }
+                }
+                FeatureKind::Road => {
+                    for edge in feature.edges.iter() {
+                        let c = match edge {
+                            Edge::North | Edge::South => '|',
+                            Edge::East | Edge::West => '-',
+                        };
+                        for i in 0..mid {
+                            draw(Self::road_cell(edge, i), c);
+                        }
+                    }
+                    draw((mid, mid), '+');
+                }
+                FeatureKind::Field => {}
+            }
+        }
+
+        match self.tile_extension {
+            Some(TileExtension::Garden) => draw((mid, mid), 'G'),
+            Some(TileExtension::Abbey | TileExtension::Monastery(_)) => draw((mid, mid), 'A'),
+            None => {}
+        }
+        cells
+    }
+
+    /// Returns the cell of `to_ascii`, as `(x, y)`, where a meeple standing on `slot`
+    /// is drawn, or `None` if the tile has no such slot.
+    ///
+    /// Meeples are drawn on the border of their town, along their road, in a free
+    /// cell of their field next to one of its edges, or up and left of the abbey.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use carcasonne_core::builder::tile_builder::TileBuilder;
+    /// use carcasonne_core::model::meeple::SlotId;
+    /// use carcasonne_core::model::tile::TileId;
+    /// use carcasonne_core::model::tile_feature::Edge::{East, North, West};
+    ///
+    /// let tile = TileBuilder::new(TileId('D'))
+    ///     .add_town(vec![North])
+    ///     .add_road(vec![West, East])
+    ///     .build();
+    /// assert_eq!(tile.slot_cell(SlotId::Feature(0)), Some((1, 0)));
+    /// assert_eq!(tile.slot_cell(SlotId::Feature(1)), Some((3, 2)));
+    /// assert_eq!(tile.slot_cell(SlotId::Extension), None);
+    /// ```
+    pub fn slot_cell(&self, slot: SlotId) -> Option<(usize, usize)> {
+        let mid = TILE_ASCII_SIZE / 2;
+        let feature = match slot {
+            SlotId::Feature(index) => self.tile_features.get(index)?,
+            SlotId::Extension => return self.tile_extension.map(|_| (mid - 1, mid - 1)),
+        };
+        match feature.kind() {
+            FeatureKind::Town => feature
+                .edges
+                .iter()
+                .next()
+                .map(|edge| Self::town_cell(edge, 1)),
+            FeatureKind::Road => feature
+                .edges
+                .iter()
+                .next()
+                .map(|edge| Self::road_cell(edge, 1)),
+            FeatureKind::Field => {
+                let ascii = self.to_ascii();
+                let cells: Vec<(usize, usize)> =
+                    feature.segments.iter().map(Self::field_cell).collect();
+                cells
+                    .iter()
+                    .find(|(x, y)| ascii[*y][*x] == '.')
+                    .or(cells.first())
+                    .copied()
+            }
+        }
+    }
+
+    /// Returns the `i`-th cell, as `(x, y)`, of the border row or column of the given edge.
+    fn town_cell(edge: Edge, i: usize) -> (usize, usize) {
+        match edge {
+            Edge::North => (i, 0),
+            Edge::South => (i, TILE_ASCII_SIZE - 1),
+            Edge::West => (0, i),
+            Edge::East => (TILE_ASCII_SIZE - 1, i),
+        }
+    }
+
+    /// Returns the `i`-th cell, as `(x, y)`, of a road going from the given edge to the center.
+    fn road_cell(edge: Edge, i: usize) -> (usize, usize) {
+        let mid = TILE_ASCII_SIZE / 2;
+        match edge {
+            Edge::North => (mid, i),
+            Edge::South => (mid, TILE_ASCII_SIZE - 1 - i),
+            Edge::West => (i, mid),
+            Edge::East => (TILE_ASCII_SIZE - 1 - i, mid),
+        }
+    }
+
+    /// Returns the cell, as `(x, y)`, just inside the tile from the given edge segment.
+    fn field_cell(segment: EdgeSegment) -> (usize, usize) {
+        let last = TILE_ASCII_SIZE - 1;
+        // The position along the edge, going clockwise around the tile.
+        let along = match segment.side {
+            Side::Left => 1,
+            Side::Center => TILE_ASCII_SIZE / 2,
+            Side::Right => last - 1,
+        };
+        match segment.edge {
+            Edge::North => (along, 1),
+            Edge::East => (last - 1, along),
+            Edge::South => (last - along, last - 1),
+            Edge::West => (1, last - along),
+        }
+    }
+
+    /// Returns the cell, as `(x, y)`, where a shield is drawn for a town on the given edge.
+    fn shield_cell(edge: Edge) -> (usize, usize) {
+        let mid = TILE_ASCII_SIZE / 2;
+        match edge {
+            Edge::North => (mid, 1),
+            Edge::South => (mid, TILE_ASCII_SIZE - 2),
+            Edge::West => (1, mid),
+            Edge::East => (TILE_ASCII_SIZE - 2, mid),
+        }
+    }
+}
+
+impl Display for Tile {
+    /// Writes the tile as `TILE_ASCII_SIZE` lines of characters, see `Tile::to_ascii`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let rows: Vec<String> = self
+            .to_ascii()
+            .iter()
+            .map(|row| row.iter().collect())
+            .collect();
+        f.write_str(&rows.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::tile_builder::TileBuilder;
+    use crate::factory::game_factory::GameTilesFactory;
+    use crate::model::tile_feature::Edge::{East, North, South, West};
+    use crate::model::tile_feature::{EdgeSet, SegmentSet, Side};
+
+    #[test]
+    fn test_tile_id_display() {
+        assert_eq!(TileId('D').to_string(), "D");
+    }
+
+    #[test]
+    fn test_rotate_keeps_id() {
+        let mut tile = TileBuilder::new(TileId('E')).add_town(vec![North]).build();
+        tile.rotate(Rotation::Deg180);
+        assert_eq!(tile.id, TileId('E'));
+    }
+
+    #[test]
+    fn test_rotated_tile_is_equivalent() {
+        let tile = TileBuilder::new(TileId('J'))
+            .add_town(vec![North])
+            .add_road(vec![East, South])
+            .build();
+        for rotation in Rotation::ALL {
+            let mut rotated = tile.clone();
+            rotated.rotate(rotation);
+            assert!(tile.equivalent_to(&rotated));
+            assert_eq!(tile.canonical_form(), rotated.canonical_form());
+        }
+    }
+
+    #[test]
+    fn test_mirrored_tile_is_not_equivalent() {
+        let j = TileBuilder::new(TileId('J'))
+            .add_town(vec![North])
+            .add_road(vec![East, South])
+            .build();
+        let k = TileBuilder::new(TileId('K'))
+            .add_town(vec![North])
+            .add_road(vec![South, West])
+            .build();
+        assert!(!j.equivalent_to(&k));
+    }
+
+    #[test]
+    fn test_equivalence_checks_enhancements_and_extension() {
+        let town = TileBuilder::new(TileId('N'))
+            .add_town(vec![North, West])
+            .build();
+        let shielded = TileBuilder::new(TileId('M'))
+            .add_shielded_town(vec![North, West])
+            .build();
+        let abbey = TileBuilder::new(TileId('B')).add_abbey().build();
+        let empty = TileBuilder::new(TileId('Z')).build();
+
+        assert!(!town.equivalent_to(&shielded));
+        assert!(!abbey.equivalent_to(&empty));
+    }
+
+    #[test]
+    fn test_feature_index_at() {
+        let tile = TileBuilder::new(TileId('D'))
+            .add_town(vec![North])
+            .add_road(vec![West, East])
+            .build();
+        assert_eq!(tile.feature_index_at(North), Some(0));
+        assert_eq!(tile.feature_index_at(East), Some(1));
+        assert_eq!(tile.feature_index_at(South), None);
+    }
+
+    #[test]
+    fn test_field_index_at_tells_road_sides_apart() {
+        let mut tile = TileBuilder::new(TileId('U'))
+            .add_road(vec![North, South])
+            .add_field(SegmentSet::from([West]).with(North.segment(Side::Left)))
+            .add_field(SegmentSet::from([East]).with(North.segment(Side::Right)))
+            .build();
+        assert_eq!(tile.field_index_at(North.segment(Side::Left)), Some(1));
+        assert_eq!(tile.field_index_at(North.segment(Side::Right)), Some(2));
+        assert_eq!(tile.field_index_at(North.segment(Side::Center)), None);
+
+        tile.rotate(Rotation::Deg90);
+        assert_eq!(tile.field_index_at(East.segment(Side::Left)), Some(1));
+        assert_eq!(tile.field_index_at(South.segment(Side::Left)), Some(2));
+        assert_eq!(tile.field_index_at(West.segment(Side::Left)), None);
+    }
+
+    #[test]
+    fn test_kind_at() {
+        let tile = TileBuilder::new(TileId('D'))
+            .add_town(vec![North])
+            .add_road(vec![West, East])
+            .build();
+        assert_eq!(tile.kind_at(North.segment(Side::Left)), FeatureKind::Town);
+        assert_eq!(tile.kind_at(East.segment(Side::Center)), FeatureKind::Road);
+        assert_eq!(tile.kind_at(East.segment(Side::Right)), FeatureKind::Field);
+        assert_eq!(
+            tile.kind_at(South.segment(Side::Center)),
+            FeatureKind::Field
+        );
+    }
+
+    #[test]
+    fn test_connected_tells_one_town_from_two() {
+        let f = TileBuilder::new(TileId('F'))
+            .add_shielded_town(vec![West, East])
+            .build();
+        let h = TileBuilder::new(TileId('H'))
+            .add_town(vec![West])
+            .add_town(vec![East])
+            .add_field(vec![North, South])
+            .build();
+
+        assert!(f.connected(West, East));
+        assert!(!h.connected(West, East));
+        assert!(h.connected(West, West));
+        assert!(!h.connected(North, North));
+        assert!(!h.connected(North, South));
+    }
+
+    #[test]
+    fn test_towns_bordering_fields() {
+        let h = TileBuilder::new(TileId('H'))
+            .add_town(vec![West])
+            .add_town(vec![East])
+            .add_field(vec![North, South])
+            .build();
+        assert_eq!(h.towns_bordering(2), vec![0, 1]);
+        assert!(h.towns_bordering(0).is_empty());
+        assert!(h.towns_bordering(7).is_empty());
+
+        // The field inside the curve of the road does not reach the town.
+        let j = TileBuilder::new(TileId('J'))
+            .add_town(vec![North])
+            .add_road(vec![East, South])
+            .add_field(
+                SegmentSet::from([West])
+                    .with(East.segment(Side::Left))
+                    .with(South.segment(Side::Right)),
+            )
+            .add_field([East.segment(Side::Right), South.segment(Side::Left)])
+            .build();
+        assert_eq!(j.towns_bordering(2), vec![0]);
+        assert!(j.towns_bordering(3).is_empty());
+    }
+
+    #[test]
+    fn test_placement_slots_of_base_tiles() {
+        let l = TileBuilder::new(TileId('L'))
+            .add_town(vec![North])
+            .add_road(vec![West])
+            .add_road(vec![South])
+            .add_road(vec![East])
+            .add_field(vec![West, East])
+            .build();
+        assert_eq!(
+            l.placement_slots(),
+            (0..5).map(SlotId::Feature).collect::<Vec<_>>()
+        );
+
+        let b = TileBuilder::new(TileId('B')).add_abbey().build();
+        assert_eq!(b.placement_slots(), vec![SlotId::Extension]);
+    }
+
+    #[test]
+    fn test_placement_slots_skip_gardens() {
+        let tile = TileBuilder::new(TileId('Z'))
+            .add_field(vec![North, East, South, West])
+            .add_garden()
+            .build();
+        assert_eq!(tile.placement_slots(), vec![SlotId::Feature(0)]);
+    }
+
+    #[test]
+    fn test_placement_slots_are_stable_under_rotation() {
+        let mut tile = TileBuilder::new(TileId('D'))
+            .add_town(vec![North])
+            .add_road(vec![West, East])
+            .build();
+        let slots = tile.placement_slots();
+        tile.rotate(Rotation::Deg270);
+        assert_eq!(tile.placement_slots(), slots);
+    }
+
+    #[test]
+    fn test_tile_round_trip() {
+        let tile = TileBuilder::new(TileId('A'))
+            .add_road(vec![South])
+            .add_field(vec![North, East, South, West])
+            .add_abbey()
+            .build();
+
+        let json = serde_json::to_string(&tile).unwrap();
+        let decoded: Tile = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(decoded.id, TileId('A'));
+        assert_eq!(decoded.tile_extension, Some(TileExtension::Abbey));
+        assert!(tile.equivalent_to(&decoded));
+    }
+
+    #[test]
+    fn test_tile_id_serializes_as_letter() {
+        assert_eq!(serde_json::to_string(&TileId('D')).unwrap(), r#""D""#);
+    }
+
+    #[test]
+    fn test_display_shielded_town_and_abbey() {
+        let tile = TileBuilder::new(TileId('Z'))
+            .add_shielded_town(vec![North, West])
+            .add_abbey()
+            .build();
+        assert_eq!(tile.to_string(), "#####\n#.*..\n#.A..\n#....\n#....");
+    }
+
+    #[test]
+    fn test_display_crossroads() {
+        let tile = TileBuilder::new(TileId('X'))
+            .add_road(vec![North])
+            .add_road(vec![West])
+            .add_road(vec![South])
+            .add_road(vec![East])
+            .build();
+        assert_eq!(tile.to_string(), "..|..\n..|..\n--+--\n..|..\n..|..");
+    }
+
+    #[test]
+    fn test_display_follows_rotation() {
+        let mut tile = TileBuilder::new(TileId('A'))
+            .add_road(vec![South])
+            .add_abbey()
+            .build();
+        assert_eq!(tile.to_string(), ".....\n.....\n..A..\n..|..\n..|..");
+        tile.rotate(Rotation::Deg90);
+        assert_eq!(tile.to_ascii()[2], ['-', '-', 'A', '.', '.']);
+    }
+
+    #[test]
+    fn test_slot_cells_lie_on_their_features() {
+        for tile in GameTilesFactory::build_base_game().available_tiles {
+            let ascii = tile.to_ascii();
+            for slot in tile.placement_slots() {
+                let (x, y) = tile.slot_cell(slot).unwrap();
+                let expected: &[char] = match slot {
+                    SlotId::Feature(index) => match tile.tile_features[index].kind() {
+                        FeatureKind::Town => &['#'],
+                        FeatureKind::Road => &['|', '-'],
+                        FeatureKind::Field => &['.'],
+                    },
+                    SlotId::Extension => &['.'],
+                };
+                assert!(
+                    expected.contains(&ascii[y][x]),
+                    "slot {slot:?} of tile {} is drawn on {:?}",
+                    tile.id,
+                    ascii[y][x]
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_rotation_composition() {
+        assert_eq!(Rotation::Deg90.then(Rotation::Deg180), Rotation::Deg270);
+        assert_eq!(Rotation::Deg270.then(Rotation::Deg90), Rotation::Deg0);
+        for rotation in Rotation::ALL {
+            assert_eq!(rotation.then(rotation.inverse()), Rotation::Deg0);
+        }
+    }
+
+    #[test]
+    fn test_rotate_remaps_every_feature() {
+        let mut tile = TileBuilder::new(TileId('Z'))
+            .add_town(vec![North])
+            .add_road(vec![East, West])
+            .build();
+
+        tile.rotate(Rotation::Deg90);
+
+        assert_eq!(tile.tile_features[0].edges, EdgeSet::from([East]));
+        assert_eq!(tile.tile_features[1].edges, EdgeSet::from([North, South]));
+    }
+
+    #[test]
+    fn test_full_turn_restores_tile() {
+        let mut tile = TileBuilder::new(TileId('Z'))
+            .add_town(vec![North, West])
+            .add_road(vec![East, South])
+            .build();
+        let original: Vec<EdgeSet> = tile.tile_features.iter().map(|f| f.edges).collect();
+
+        for _ in 0..4 {
+            tile.rotate(Rotation::Deg90);
+        }
+
+        let edges: Vec<EdgeSet> = tile.tile_features.iter().map(|f| f.edges).collect();
+        assert_eq!(edges, original);
+    }
+
+    #[test]
+    fn test_rotate_zero_keeps_edges() {
+        let mut tile = TileBuilder::new(TileId('Z')).add_town(vec![South]).build();
+        tile.rotate(Rotation::Deg0);
+        assert_eq!(tile.tile_features[0].edges, EdgeSet::from([South]));
+    }
 }
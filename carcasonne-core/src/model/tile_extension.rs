@@ -1,36 +1,77 @@
-use dyn_clone::{clone_trait_object, DynClone};
-use std::any::Any;
-use std::fmt::Debug;
+use serde::{Deserialize, Serialize};
 
-/// A trait for extending the behavior or metadata of a tile.
+/// Extends a tile beyond its basic feature layout (e.g., special scoring rules,
+/// placement constraints).
 ///
-/// `TileExtension` allows different tile types to provide custom logic
-/// beyond the basic feature layout (e.g., special scoring rules, placement constraints).
-///
-/// It is a trait object (`dyn TileExtension`) that supports:
-/// - dynamic dispatch,
-/// - cloning (via [`DynClone`](https://docs.rs/dyn-clone)),
-/// - and runtime type inspection (via [`Any`]).
+/// A tile has at most one extension, which usually sits in the middle of the tile.
+/// Extensions are plain values, so they can be compared, matched on and serialized
+/// without any runtime type inspection.
 ///
 /// # Example
 ///
 /// ```
-/// use carcasonne_core::model::tile_extension::TileExtension;
-///
-/// #[derive(Debug, Clone)]
-/// struct Abbey;
+/// use carcasonne_core::model::tile_extension::{MonasteryKind, TileExtension};
 ///
-/// impl TileExtension for Abbey {}
+/// let shrine = TileExtension::Monastery(MonasteryKind::Shrine);
+/// assert!(shrine.accepts_meeples());
+/// assert!(!TileExtension::Garden.accepts_meeples());
 /// ```
-pub trait TileExtension: Debug + DynClone + Any + Sync {}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum TileExtension {
+    /// An abbey, the cloister of the base game.
+    ///
+    /// It is scored once surrounded by eight tiles.
+    Abbey,
+    /// A garden, from the Abbot mini-expansion.
+    ///
+    /// Only the abbot may be placed on a garden, which is scored like an abbey.
+    Garden,
+    /// A monastery of the given kind.
+    ///
+    /// Unlike `Abbey`, which is the plain cloister of the base game, a monastery
+    /// carries its kind so expansion tiles can be told apart. The abbot of the
+    /// Abbot mini-expansion may be placed on any monastery.
+    Monastery(MonasteryKind),
+}
 
-// Enables cloning of trait objects for `TileExtension`.
-clone_trait_object!(TileExtension);
+impl TileExtension {
+    /// Returns `true` if a regular meeple may be placed on the extension.
+    pub fn accepts_meeples(&self) -> bool {
+        !matches!(self, TileExtension::Garden)
+    }
+}
 
-/// A concrete implementation of `TileExtension` representing an Abbey tile.
-///
-/// This tile may have specific scoring or placement behavior,
-/// which can be handled dynamically at runtime.
-#[derive(Debug, Clone)]
-pub struct Abbey {}
-impl TileExtension for Abbey {}
+/// The kind of a monastery, which decides the rules applying to it.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize,
+)]
+pub enum MonasteryKind {
+    /// A regular cloister, scored once surrounded by eight tiles.
+    #[default]
+    Cloister,
+    /// A shrine, which may challenge a neighboring cloister.
+    Shrine,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accepts_meeples() {
+        assert!(TileExtension::Abbey.accepts_meeples());
+        assert!(TileExtension::Monastery(MonasteryKind::Cloister).accepts_meeples());
+        assert!(!TileExtension::Garden.accepts_meeples());
+    }
+
+    #[test]
+    fn test_serialize_extension() {
+        let extension = TileExtension::Monastery(MonasteryKind::Shrine);
+        let json = serde_json::to_string(&extension).unwrap();
+        assert_eq!(json, r#"{"Monastery":"Shrine"}"#);
+        assert_eq!(
+            serde_json::from_str::<TileExtension>(&json).unwrap(),
+            extension
+        );
+    }
+}
@@ -1,9 +1,11 @@
+use crate::model::tile::Rotation;
 use dyn_clone::{clone_trait_object, DynClone};
+use serde::{Deserialize, Serialize};
 use std::any::Any;
-use std::fmt::Debug;
+use std::fmt::{Debug, Formatter};
 
 /// Represents one of the four edges of a tile.
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub enum Edge {
     /// Top edge of the tile.
     North,
@@ -15,26 +17,473 @@ pub enum Edge {
     South,
 }
 
+impl Edge {
+    /// All edges, in clockwise order starting from the top.
+    pub const ALL: [Edge; 4] = [Edge::North, Edge::East, Edge::South, Edge::West];
+
+    /// Returns the edge this edge ends up on after the given clockwise rotation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use carcasonne_core::model::tile::Rotation;
+    /// use carcasonne_core::model::tile_feature::Edge;
+    ///
+    /// assert_eq!(Edge::North.rotated(Rotation::Deg90), Edge::East);
+    /// assert_eq!(Edge::West.rotated(Rotation::Deg180), Edge::East);
+    /// ```
+    pub fn rotated(self, rotation: Rotation) -> Edge {
+        let index = Edge::ALL
+            .iter()
+            .position(|edge| *edge == self)
+            .expect("Every edge is listed in Edge::ALL");
+        Edge::ALL[(index + rotation.quarter_turns()) % Edge::ALL.len()]
+    }
+
+    /// Returns the edge on the other side of the tile, which faces this edge on a neighbor.
+    pub fn opposite(self) -> Edge {
+        self.rotated(Rotation::Deg180)
+    }
+
+    /// Returns the segment of this edge on the given side.
+    pub fn segment(self, side: Side) -> EdgeSegment {
+        EdgeSegment { edge: self, side }
+    }
+
+    /// Returns the three segments of this edge, in clockwise order.
+    pub fn segments(self) -> [EdgeSegment; 3] {
+        Side::ALL.map(|side| self.segment(side))
+    }
+
+    /// Returns the position of this edge in `Edge::ALL`.
+    const fn index(self) -> usize {
+        match self {
+            Edge::North => 0,
+            Edge::East => 1,
+            Edge::South => 2,
+            Edge::West => 3,
+        }
+    }
+
+    /// Returns the bit representing this edge in an `EdgeSet`.
+    const fn bit(self) -> u8 {
+        match self {
+            Edge::North => 1,
+            Edge::East => 1 << 1,
+            Edge::South => 1 << 2,
+            Edge::West => 1 << 3,
+        }
+    }
+}
+
+/// A set of tile edges, stored as bit flags in a single byte.
+///
+/// Iteration always yields edges in clockwise order starting from `North`,
+/// whatever the insertion order.
+///
+/// # Examples
+///
+/// ```
+/// use carcasonne_core::model::tile_feature::{Edge, EdgeSet};
+///
+/// let edges = EdgeSet::from([Edge::West, Edge::North]);
+/// assert!(edges.contains(Edge::North));
+/// assert_eq!(edges.iter().collect::<Vec<_>>(), vec![Edge::North, Edge::West]);
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(from = "Vec<Edge>", into = "Vec<Edge>")]
+pub struct EdgeSet(u8);
+
+impl EdgeSet {
+    /// Returns an empty set.
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    /// Adds an edge to the set.
+    pub fn insert(&mut self, edge: Edge) {
+        self.0 |= edge.bit();
+    }
+
+    /// Returns `true` if the set contains the given edge.
+    pub fn contains(&self, edge: Edge) -> bool {
+        self.0 & edge.bit() != 0
+    }
+
+    /// Returns the number of edges in the set.
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Returns `true` if the set contains no edge.
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns the set with every edge rotated clockwise by `rotation`.
+    pub fn rotated(self, rotation: Rotation) -> Self {
+        self.iter().map(|edge| edge.rotated(rotation)).collect()
+    }
+
+    /// Iterates over the edges of the set in clockwise order starting from `North`.
+    pub fn iter(&self) -> impl Iterator<Item = Edge> + '_ {
+        Edge::ALL.into_iter().filter(|edge| self.contains(*edge))
+    }
+}
+
+impl Debug for EdgeSet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl FromIterator<Edge> for EdgeSet {
+    fn from_iter<T: IntoIterator<Item = Edge>>(iter: T) -> Self {
+        let mut set = EdgeSet::empty();
+        iter.into_iter().for_each(|edge| set.insert(edge));
+        set
+    }
+}
+
+impl From<Vec<Edge>> for EdgeSet {
+    fn from(edges: Vec<Edge>) -> Self {
+        edges.into_iter().collect()
+    }
+}
+
+impl From<EdgeSet> for Vec<Edge> {
+    fn from(edges: EdgeSet) -> Self {
+        edges.iter().collect()
+    }
+}
+
+impl<const N: usize> From<[Edge; N]> for EdgeSet {
+    fn from(edges: [Edge; N]) -> Self {
+        edges.into_iter().collect()
+    }
+}
+
+impl From<&[Edge]> for EdgeSet {
+    fn from(edges: &[Edge]) -> Self {
+        edges.iter().copied().collect()
+    }
+}
+
+/// One of the three parts a tile edge is split into.
+///
+/// Sides are named as seen from the center of the tile looking out through the edge,
+/// so going clockwise around the tile visits the `Left`, `Center` and `Right` part
+/// of each edge in turn. A road ends in the center of an edge, leaving the left and
+/// right parts to the fields on either side of it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Side {
+    /// The part of the edge preceding its center, going clockwise.
+    Left,
+    /// The middle of the edge, where roads end.
+    Center,
+    /// The part of the edge following its center, going clockwise.
+    Right,
+}
+
+impl Side {
+    /// All sides, in clockwise order.
+    pub const ALL: [Side; 3] = [Side::Left, Side::Center, Side::Right];
+
+    /// Returns the side this side faces on the neighboring tile.
+    ///
+    /// Both tiles look at the shared edge from opposite directions, so their left
+    /// and right are swapped.
+    pub fn mirrored(self) -> Side {
+        match self {
+            Side::Left => Side::Right,
+            Side::Center => Side::Center,
+            Side::Right => Side::Left,
+        }
+    }
+}
+
+/// A third of a tile edge, used to tell apart the fields lying on both sides of a road.
+///
+/// # Examples
+///
+/// ```
+/// use carcasonne_core::model::tile_feature::{Edge, Side};
+///
+/// let segment = Edge::North.segment(Side::Left);
+/// assert_eq!(segment.facing(), Edge::South.segment(Side::Right));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct EdgeSegment {
+    /// The edge the segment belongs to.
+    pub edge: Edge,
+    /// The part of the edge covered by the segment.
+    pub side: Side,
+}
+
+impl EdgeSegment {
+    /// All segments, in clockwise order starting from the left part of the top edge.
+    pub const ALL: [EdgeSegment; 12] = {
+        let mut all = [EdgeSegment {
+            edge: Edge::North,
+            side: Side::Left,
+        }; 12];
+        let mut i = 0;
+        while i < 12 {
+            all[i] = EdgeSegment {
+                edge: Edge::ALL[i / 3],
+                side: Side::ALL[i % 3],
+            };
+            i += 1;
+        }
+        all
+    };
+
+    /// Returns the segment this segment ends up on after the given clockwise rotation.
+    pub fn rotated(self, rotation: Rotation) -> EdgeSegment {
+        self.edge.rotated(rotation).segment(self.side)
+    }
+
+    /// Returns the segment of the neighboring tile touching this segment.
+    pub fn facing(self) -> EdgeSegment {
+        self.edge.opposite().segment(self.side.mirrored())
+    }
+
+    /// Returns the bit representing this segment in a `SegmentSet`.
+    const fn bit(self) -> u16 {
+        let side = match self.side {
+            Side::Left => 0,
+            Side::Center => 1,
+            Side::Right => 2,
+        };
+        1 << (self.edge.index() * 3 + side)
+    }
+}
+
+/// A set of edge segments, stored as bit flags.
+///
+/// Converting a list of edges into a `SegmentSet` covers those edges entirely.
+/// Iteration always yields segments in clockwise order, like `EdgeSegment::ALL`.
+///
+/// # Examples
+///
+/// ```
+/// use carcasonne_core::model::tile_feature::{Edge, EdgeSet, SegmentSet, Side};
+///
+/// let segments = SegmentSet::from([Edge::West]).with(Edge::North.segment(Side::Left));
+/// assert_eq!(segments.len(), 4);
+/// assert_eq!(segments.edges(), EdgeSet::from([Edge::North, Edge::West]));
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(from = "Vec<EdgeSegment>", into = "Vec<EdgeSegment>")]
+pub struct SegmentSet(u16);
+
+impl SegmentSet {
+    /// Returns an empty set.
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    /// Adds a segment to the set.
+    pub fn insert(&mut self, segment: EdgeSegment) {
+        self.0 |= segment.bit();
+    }
+
+    /// Returns the set with the given segment added.
+    pub fn with(mut self, segment: EdgeSegment) -> Self {
+        self.insert(segment);
+        self
+    }
+
+    /// Returns `true` if the set contains the given segment.
+    pub fn contains(&self, segment: EdgeSegment) -> bool {
+        self.0 & segment.bit() != 0
+    }
+
+    /// Returns the number of segments in the set.
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Returns `true` if the set contains no segment.
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns the edges touched, even partially, by the segments of the set.
+    pub fn edges(&self) -> EdgeSet {
+        self.iter().map(|segment| segment.edge).collect()
+    }
+
+    /// Returns the set with every segment rotated clockwise by `rotation`.
+    pub fn rotated(self, rotation: Rotation) -> Self {
+        self.iter()
+            .map(|segment| segment.rotated(rotation))
+            .collect()
+    }
+
+    /// Iterates over the segments of the set in clockwise order.
+    pub fn iter(&self) -> impl Iterator<Item = EdgeSegment> + '_ {
+        EdgeSegment::ALL
+            .into_iter()
+            .filter(|segment| self.contains(*segment))
+    }
+}
+
+impl Debug for SegmentSet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl FromIterator<EdgeSegment> for SegmentSet {
+    fn from_iter<T: IntoIterator<Item = EdgeSegment>>(iter: T) -> Self {
+        let mut set = SegmentSet::empty();
+        iter.into_iter().for_each(|segment| set.insert(segment));
+        set
+    }
+}
+
+impl From<Vec<EdgeSegment>> for SegmentSet {
+    fn from(segments: Vec<EdgeSegment>) -> Self {
+        segments.into_iter().collect()
+    }
+}
+
+impl From<SegmentSet> for Vec<EdgeSegment> {
+    fn from(segments: SegmentSet) -> Self {
+        segments.iter().collect()
+    }
+}
+
+impl<const N: usize> From<[EdgeSegment; N]> for SegmentSet {
+    fn from(segments: [EdgeSegment; N]) -> Self {
+        segments.into_iter().collect()
+    }
+}
+
+impl From<EdgeSet> for SegmentSet {
+    fn from(edges: EdgeSet) -> Self {
+        edges.iter().flat_map(Edge::segments).collect()
+    }
+}
+
+impl From<Vec<Edge>> for SegmentSet {
+    fn from(edges: Vec<Edge>) -> Self {
+        EdgeSet::from(edges).into()
+    }
+}
+
+impl<const N: usize> From<[Edge; N]> for SegmentSet {
+    fn from(edges: [Edge; N]) -> Self {
+        EdgeSet::from(edges).into()
+    }
+}
+
 /// A feature present on a tile (e.g., town, road), possibly with enhancements.
 ///
 /// A `TileFeature` defines:
 /// - The type of the feature (such as a `Town` or `Road`)
-/// - The edges of the tile that the feature touches
+/// - The edges of the tile that the feature touches, and the segments of those edges it covers
 /// - An optional enhancement (like a `Shield`) that modifies scoring or rules
-#[derive(Debug, Clone)]
+///
+/// Edges, segments and enhancement are stored inline so that cloning a tile stays cheap.
+///
+/// A feature is serialized with the `FeatureKind` of its type in place of the
+/// trait object. Segments are only written when they differ from the ones the
+/// kind covers by default, see `FeatureKind::segments_on`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "TileFeatureData", into = "TileFeatureData")]
 pub struct TileFeature {
     /// The core type of the feature (e.g., town, road).
     pub feature_type: Box<dyn TileFeatureType>,
-    /// The edges of the tile this feature spans.
-    pub edges: Vec<Edge>,
+    /// The edges of the tile this feature spans, even partially.
+    pub edges: EdgeSet,
+    /// The edge segments covered by this feature; `edges` are the edges they belong to.
+    pub segments: SegmentSet,
     /// An optional enhancement that provides additional functionality or scoring.
-    pub enhancement: Option<Box<dyn TileFeatureEnhancement>>,
+    pub enhancement: Option<TileFeatureEnhancement>,
+}
+
+/// The serialized form of a `TileFeature`.
+#[derive(Serialize, Deserialize)]
+struct TileFeatureData {
+    kind: FeatureKind,
+    edges: EdgeSet,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    segments: Option<SegmentSet>,
+    enhancement: Option<TileFeatureEnhancement>,
+}
+
+impl From<TileFeature> for TileFeatureData {
+    fn from(feature: TileFeature) -> Self {
+        let kind = feature.kind();
+        Self {
+            kind,
+            edges: feature.edges,
+            segments: Some(feature.segments)
+                .filter(|segments| *segments != kind.segments_on(feature.edges)),
+            enhancement: feature.enhancement,
+        }
+    }
+}
+
+impl From<TileFeatureData> for TileFeature {
+    fn from(data: TileFeatureData) -> Self {
+        let segments = data
+            .segments
+            .unwrap_or_else(|| data.kind.segments_on(data.edges));
+        Self {
+            feature_type: data.kind.feature_type(),
+            edges: segments.edges(),
+            segments,
+            enhancement: data.enhancement,
+        }
+    }
+}
+
+/// The kinds of tile features, one for each implementation of `TileFeatureType`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum FeatureKind {
+    /// A town, see `Town`.
+    Town,
+    /// A road, see `Road`.
+    Road,
+    /// A field, see `Field`.
+    Field,
+}
+
+impl FeatureKind {
+    /// Returns a new feature type of this kind.
+    pub fn feature_type(self) -> Box<dyn TileFeatureType> {
+        match self {
+            FeatureKind::Town => Box::new(Town {}),
+            FeatureKind::Road => Box::new(Road {}),
+            FeatureKind::Field => Box::new(Field {}),
+        }
+    }
+
+    /// Returns the segments a feature of this kind covers by default on the given edges.
+    ///
+    /// Roads only reach the center of their edges, while towns and fields cover them
+    /// entirely. Fields split by a road must list their segments explicitly.
+    pub fn segments_on(self, edges: EdgeSet) -> SegmentSet {
+        match self {
+            FeatureKind::Road => edges
+                .iter()
+                .map(|edge| edge.segment(Side::Center))
+                .collect(),
+            FeatureKind::Town | FeatureKind::Field => edges.into(),
+        }
+    }
 }
 
 /// Trait representing a type of tile feature (e.g., road, town, field).
 ///
 /// This trait allows for dynamic dispatch and cloning of feature types.
-pub trait TileFeatureType: Debug + DynClone + Any + Sync {}
+pub trait TileFeatureType: Debug + DynClone + Any + Send + Sync {
+    /// Returns the kind of the feature type, used to serialize and compare features.
+    fn kind(&self) -> FeatureKind;
+}
 
 // Enables cloning of `TileFeatureType` trait objects.
 clone_trait_object!(TileFeatureType);
@@ -42,25 +491,229 @@ clone_trait_object!(TileFeatureType);
 /// A concrete implementation of a tile feature: a town.
 #[derive(Debug, Clone)]
 pub struct Town {}
-impl TileFeatureType for Town {}
+impl TileFeatureType for Town {
+    fn kind(&self) -> FeatureKind {
+        FeatureKind::Town
+    }
+}
 
 /// A concrete implementation of a tile feature: a road.
 #[derive(Debug, Clone)]
 pub struct Road {}
-impl TileFeatureType for Road {}
+impl TileFeatureType for Road {
+    fn kind(&self) -> FeatureKind {
+        FeatureKind::Road
+    }
+}
 
-/// Trait representing an optional enhancement on a tile feature,
-/// such as a shield in a town.
+/// A concrete implementation of a tile feature: a field.
 ///
-/// Enhancements may affect scoring or gameplay behavior.
-pub trait TileFeatureEnhancement: Debug + DynClone + Any + Sync {}
+/// Fields cover the parts of a tile left free by towns and roads. A road splits
+/// a side between two fields, so two fields of a tile may touch the same edge:
+/// for a field, `edges` lists every side it touches, even partially, and
+/// `segments` tells which side of the road it lies on.
+#[derive(Debug, Clone)]
+pub struct Field {}
+impl TileFeatureType for Field {
+    fn kind(&self) -> FeatureKind {
+        FeatureKind::Field
+    }
+}
+
+impl TileFeature {
+    /// Returns the kind of the feature.
+    pub fn kind(&self) -> FeatureKind {
+        self.feature_type.kind()
+    }
 
-// Enables cloning of `TileFeatureEnhancement` trait objects.
-clone_trait_object!(TileFeatureEnhancement);
+    /// Returns `true` if the feature is a field.
+    pub fn is_field(&self) -> bool {
+        self.kind() == FeatureKind::Field
+    }
+}
 
-/// A specific enhancement that can be applied to a town: a shield.
+/// An optional enhancement on a tile feature, such as a shield in a town.
 ///
-/// Shields typically grant bonus points when features are scored.
-#[derive(Debug, Clone)]
-pub struct Shield {}
-impl TileFeatureEnhancement for Shield {}
+/// Enhancements may affect scoring or gameplay behavior.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum TileFeatureEnhancement {
+    /// A shield on a town, typically granting bonus points when the town is scored.
+    Shield,
+    /// A cathedral in a town, from Inns & Cathedrals: the town is worth more once
+    /// completed, and nothing if left unfinished.
+    Cathedral,
+    /// An inn by a lake on a road, from Inns & Cathedrals: the road is worth more once
+    /// completed, and nothing if left unfinished.
+    Inn,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edge_set_insert_and_contains() {
+        let mut edges = EdgeSet::empty();
+        assert!(edges.is_empty());
+
+        edges.insert(Edge::South);
+        edges.insert(Edge::South);
+
+        assert!(edges.contains(Edge::South));
+        assert!(!edges.contains(Edge::North));
+        assert_eq!(edges.len(), 1);
+    }
+
+    #[test]
+    fn test_edge_set_iterates_clockwise() {
+        let edges = EdgeSet::from([Edge::West, Edge::South, Edge::East, Edge::North]);
+        assert_eq!(edges.iter().collect::<Vec<_>>(), Edge::ALL.to_vec());
+    }
+
+    #[test]
+    fn test_edge_set_ignores_insertion_order() {
+        assert_eq!(
+            EdgeSet::from(vec![Edge::North, Edge::West]),
+            EdgeSet::from(vec![Edge::West, Edge::North])
+        );
+    }
+
+    #[test]
+    fn test_edge_set_debug() {
+        let edges = EdgeSet::from([Edge::East, Edge::North]);
+        assert_eq!(format!("{edges:?}"), "{North, East}");
+    }
+
+    #[test]
+    fn test_edge_rotation() {
+        assert_eq!(Edge::North.rotated(Rotation::Deg0), Edge::North);
+        assert_eq!(Edge::North.rotated(Rotation::Deg90), Edge::East);
+        assert_eq!(Edge::East.rotated(Rotation::Deg180), Edge::West);
+        assert_eq!(Edge::South.rotated(Rotation::Deg270), Edge::East);
+        assert_eq!(Edge::West.rotated(Rotation::Deg90), Edge::North);
+    }
+
+    #[test]
+    fn test_edge_set_rotation() {
+        let edges = EdgeSet::from([Edge::North, Edge::East]);
+        assert_eq!(
+            edges.rotated(Rotation::Deg90),
+            EdgeSet::from([Edge::East, Edge::South])
+        );
+    }
+
+    #[test]
+    fn test_is_field() {
+        let feature = |feature_type: Box<dyn TileFeatureType>| TileFeature {
+            feature_type,
+            edges: EdgeSet::from([Edge::North]),
+            segments: SegmentSet::from([Edge::North]),
+            enhancement: None,
+        };
+        assert!(feature(Box::new(Field {})).is_field());
+        assert!(!feature(Box::new(Road {})).is_field());
+    }
+
+    #[test]
+    fn test_edge_set_serializes_as_edge_list() {
+        let edges = EdgeSet::from([Edge::West, Edge::North]);
+        let json = serde_json::to_string(&edges).unwrap();
+        assert_eq!(json, r#"["North","West"]"#);
+        assert_eq!(serde_json::from_str::<EdgeSet>(&json).unwrap(), edges);
+    }
+
+    #[test]
+    fn test_tile_feature_round_trip() {
+        let feature = TileFeature {
+            feature_type: Box::new(Town {}),
+            edges: EdgeSet::from([Edge::North, Edge::East]),
+            segments: SegmentSet::from([Edge::North, Edge::East]),
+            enhancement: Some(TileFeatureEnhancement::Shield),
+        };
+        let json = serde_json::to_string(&feature).unwrap();
+        assert_eq!(
+            json,
+            r#"{"kind":"Town","edges":["North","East"],"enhancement":"Shield"}"#
+        );
+
+        let decoded: TileFeature = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.kind(), FeatureKind::Town);
+        assert_eq!(decoded.edges, feature.edges);
+        assert_eq!(decoded.segments, feature.segments);
+        assert_eq!(decoded.enhancement, feature.enhancement);
+    }
+
+    #[test]
+    fn test_split_field_round_trip() {
+        let segments = SegmentSet::from([
+            Edge::North.segment(Side::Right),
+            Edge::East.segment(Side::Left),
+        ]);
+        let feature = TileFeature {
+            feature_type: Box::new(Field {}),
+            edges: segments.edges(),
+            segments,
+            enhancement: None,
+        };
+        let json = serde_json::to_string(&feature).unwrap();
+        assert!(json.contains(
+            r#""segments":[{"edge":"North","side":"Right"},{"edge":"East","side":"Left"}]"#
+        ));
+
+        let decoded: TileFeature = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.segments, segments);
+        assert_eq!(decoded.edges, EdgeSet::from([Edge::North, Edge::East]));
+    }
+
+    #[test]
+    fn test_segments_are_clockwise() {
+        let all: Vec<_> = SegmentSet::from(Edge::ALL).iter().collect();
+        assert_eq!(all, EdgeSegment::ALL.to_vec());
+        assert_eq!(EdgeSegment::ALL[0], Edge::North.segment(Side::Left));
+        assert_eq!(EdgeSegment::ALL[11], Edge::West.segment(Side::Right));
+    }
+
+    #[test]
+    fn test_segment_rotation_keeps_side() {
+        let segment = Edge::North.segment(Side::Left);
+        assert_eq!(
+            segment.rotated(Rotation::Deg90),
+            Edge::East.segment(Side::Left)
+        );
+        assert_eq!(
+            SegmentSet::from([segment]).rotated(Rotation::Deg270),
+            SegmentSet::from([Edge::West.segment(Side::Left)])
+        );
+    }
+
+    #[test]
+    fn test_facing_segment_swaps_sides() {
+        for segment in EdgeSegment::ALL {
+            assert_eq!(segment.facing().facing(), segment);
+        }
+        assert_eq!(
+            Edge::East.segment(Side::Left).facing(),
+            Edge::West.segment(Side::Right)
+        );
+        assert_eq!(
+            Edge::South.segment(Side::Center).facing(),
+            Edge::North.segment(Side::Center)
+        );
+    }
+
+    #[test]
+    fn test_default_segments_of_kinds() {
+        let edges = EdgeSet::from([Edge::South]);
+        assert_eq!(FeatureKind::Town.segments_on(edges).len(), 3);
+        assert_eq!(
+            FeatureKind::Road.segments_on(edges),
+            SegmentSet::from([Edge::South.segment(Side::Center)])
+        );
+    }
+
+    #[test]
+    fn test_tile_feature_is_compact() {
+        // A boxed feature type plus one byte of edges, two of segments and one of enhancement.
+        assert!(size_of::<TileFeature>() <= 3 * size_of::<usize>());
+    }
+}
@@ -0,0 +1,74 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Identifies the box a tile was printed in.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize,
+)]
+pub enum ExpansionId {
+    /// The base game.
+    #[default]
+    Base,
+    /// The Inns & Cathedrals expansion.
+    InnsAndCathedrals,
+    /// The Traders & Builders expansion.
+    TradersAndBuilders,
+    /// The Abbot mini-expansion, which brings gardens.
+    Abbot,
+}
+
+impl ExpansionId {
+    /// Returns the name of the expansion as printed on its box.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ExpansionId::Base => "Base game",
+            ExpansionId::InnsAndCathedrals => "Inns & Cathedrals",
+            ExpansionId::TradersAndBuilders => "Traders & Builders",
+            ExpansionId::Abbot => "The Abbot",
+        }
+    }
+}
+
+impl Display for ExpansionId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Describes where a tile definition comes from and how many copies of it are printed.
+///
+/// The metadata does not affect the rules; it lets the composition of a deck be
+/// inspected, filtered by expansion and displayed.
+///
+/// # Examples
+///
+/// ```
+/// use carcasonne_core::model::tile_meta::{ExpansionId, TileMeta};
+///
+/// let meta = TileMeta {
+///     expansion: ExpansionId::Base,
+///     copies: 4,
+///     letter: 'D',
+/// };
+/// assert_eq!(meta.to_string(), "D (Base game, 4 copies)");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TileMeta {
+    /// The expansion the tile belongs to.
+    pub expansion: ExpansionId,
+    /// The number of copies of the tile in the expansion.
+    pub copies: usize,
+    /// The letter code of the tile in the rules of its expansion.
+    pub letter: char,
+}
+
+impl Display for TileMeta {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let plural = if self.copies == 1 { "copy" } else { "copies" };
+        write!(
+            f,
+            "{} ({}, {} {plural})",
+            self.letter, self.expansion, self.copies
+        )
+    }
+}
@@ -0,0 +1,665 @@
+pub mod estimator;
+pub mod modifier;
+pub mod score_board;
+pub mod scorer;
+
+use crate::model::board::{Board, CompletedAbbey, Coord, FeatureSummary};
+use crate::model::meeple::Meeple;
+use crate::model::rules::RulesConfig;
+use crate::scoring::scorer::Scorers;
+use serde::{Deserialize, Serialize};
+
+/// The points a completed town is worth for each of its tiles.
+pub const TOWN_TILE_POINTS: u32 = 2;
+/// The points a completed town is worth for each of its shields.
+pub const TOWN_SHIELD_POINTS: u32 = 2;
+/// The points a completed road is worth for each of its tiles.
+pub const ROAD_TILE_POINTS: u32 = 1;
+/// The points an abbey surrounded by eight tiles is worth.
+pub const ABBEY_POINTS: u32 = 9;
+/// The points an unfinished town is worth at the end of the game for each of its tiles.
+pub const UNFINISHED_TOWN_TILE_POINTS: u32 = 1;
+/// The points an unfinished town is worth at the end of the game for each of its shields.
+pub const UNFINISHED_TOWN_SHIELD_POINTS: u32 = 1;
+/// The points an unfinished abbey is worth at the end of the game for itself and each
+/// tile around it.
+pub const UNFINISHED_ABBEY_TILE_POINTS: u32 = 1;
+/// The extra points a completed town with a cathedral is worth for each of its tiles
+/// and shields.
+pub const CATHEDRAL_BONUS_POINTS: u32 = 1;
+/// The extra points a completed road with an inn is worth for each of its tiles.
+pub const INN_BONUS_POINTS: u32 = 1;
+/// The points a field is worth at the end of the game for each completed town it borders.
+pub const FIELD_TOWN_POINTS: u32 = 3;
+/// The points a completed town is worth at the end of the game to the farmers supplying
+/// it, under the rules of the first edition.
+pub const FIRST_EDITION_FIELD_TOWN_POINTS: u32 = 4;
+
+/// What points were scored for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ScoreCategory {
+    /// A town.
+    Town,
+    /// A road.
+    Road,
+    /// An abbey, or another tile extension scored like one.
+    Abbey,
+    /// A field, scored by its farmers at the end of the game.
+    Field,
+}
+
+/// The points of a player, split by what they were scored for.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScoreBreakdown {
+    /// The points scored for towns.
+    pub towns: u32,
+    /// The points scored for roads.
+    pub roads: u32,
+    /// The points scored for abbeys.
+    pub abbeys: u32,
+    /// The points scored for fields.
+    pub fields: u32,
+    /// Of the points above, the ones scored when the game ended, for unfinished
+    /// features and fields.
+    pub end_game: u32,
+}
+
+impl ScoreBreakdown {
+    /// Adds `points` scored for `category`.
+    pub fn add(&mut self, category: ScoreCategory, points: u32) {
+        match category {
+            ScoreCategory::Town => self.towns += points,
+            ScoreCategory::Road => self.roads += points,
+            ScoreCategory::Abbey => self.abbeys += points,
+            ScoreCategory::Field => self.fields += points,
+        }
+    }
+
+    /// Returns the points scored in all categories. The points scored when the game
+    /// ended are counted once, in their category.
+    pub fn total(&self) -> u32 {
+        self.towns + self.roads + self.abbeys + self.fields
+    }
+}
+
+/// The points scored for a feature, and the players they go to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeatureScore {
+    /// What the points were scored for.
+    pub category: ScoreCategory,
+    /// The points each scoring player receives.
+    pub points: u32,
+    /// The players receiving the points, in turn order: those with the most meeples on
+    /// the feature. Empty when nobody stood on it.
+    pub players: Vec<usize>,
+}
+
+/// Scores the features completed by the tile placed at `position`.
+///
+/// Meant to be called right after a placement, before the meeples of the completed
+/// features are taken off the board. A completed town is worth
+/// `TOWN_TILE_POINTS` per tile and `TOWN_SHIELD_POINTS` per shield, and a completed
+/// road `ROAD_TILE_POINTS` per tile. Abbeys surrounded by the placement, whoever
+/// placed the last tile around them, are worth `ABBEY_POINTS`, unless `rules` keep
+/// them for the end of the game.
+///
+/// # Examples
+///
+/// ```
+/// use carcasonne_core::builder::tile_builder::TileBuilder;
+/// use carcasonne_core::model::board::{Board, Coord};
+/// use carcasonne_core::model::meeple::{Meeple, SlotId};
+/// use carcasonne_core::model::rules::RulesConfig;
+/// use carcasonne_core::model::tile::{Rotation, TileId};
+/// use carcasonne_core::model::tile_feature::Edge;
+/// use carcasonne_core::scoring::{score_placement, ScoreCategory};
+///
+/// let town = TileBuilder::new(TileId('E')).add_town(vec![Edge::North]).build();
+/// let mut board = Board::with_start_tile(town.clone());
+/// board.set_meeple(Coord::ORIGIN, Some(Meeple::new(1, SlotId::Feature(0))));
+/// board.place_tile(Coord::new(0, -1), town, Rotation::Deg180).unwrap();
+///
+/// let scores = score_placement(&board, Coord::new(0, -1), &RulesConfig::default());
+/// assert_eq!(scores.len(), 1);
+/// assert_eq!(scores[0].category, ScoreCategory::Town);
+/// assert_eq!(scores[0].points, 4);
+/// assert_eq!(scores[0].players, vec![1]);
+/// ```
+pub fn score_placement(board: &Board, position: Coord, rules: &RulesConfig) -> Vec<FeatureScore> {
+    scorers_for(board, rules).score_placement(board, position)
+}
+
+/// Scores a completed town.
+pub fn score_completed_town(town: &FeatureSummary) -> FeatureScore {
+    FeatureScore {
+        category: ScoreCategory::Town,
+        points: town.tile_count as u32 * TOWN_TILE_POINTS
+            + town.shields as u32 * TOWN_SHIELD_POINTS,
+        players: majority_players(&town.meeples),
+    }
+}
+
+/// Scores a completed road.
+///
+/// A road closes either at both of its ends, on a crossing, a town or an abbey, or
+/// by looping back on itself. Either way, each tile counts once, even when the road
+/// goes through it twice.
+pub fn score_completed_road(road: &FeatureSummary) -> FeatureScore {
+    FeatureScore {
+        category: ScoreCategory::Road,
+        points: road.tile_count as u32 * ROAD_TILE_POINTS,
+        players: majority_players(&road.meeples),
+    }
+}
+
+/// Scores an abbey surrounded by eight tiles.
+pub fn score_completed_abbey(abbey: &CompletedAbbey) -> FeatureScore {
+    FeatureScore {
+        category: ScoreCategory::Abbey,
+        points: ABBEY_POINTS,
+        players: abbey.meeple.iter().map(|meeple| meeple.player).collect(),
+    }
+}
+
+/// Scores the towns, roads and abbeys left unfinished when the game ends.
+///
+/// This is the end-game counterpart of `score_placement`, and is called once, when
+/// the bag runs out. An unfinished town is worth `UNFINISHED_TOWN_TILE_POINTS` per
+/// tile and `UNFINISHED_TOWN_SHIELD_POINTS` per shield, an unfinished road
+/// `ROAD_TILE_POINTS` per tile, and an unfinished abbey `UNFINISHED_ABBEY_TILE_POINTS`
+/// for itself and each tile around it. Only the features where a meeple stands are
+/// listed, as the others score for nobody: towns and roads first, in the order their
+/// first tile was placed, then abbeys from the top-left of the board. Abbeys kept for
+/// the end of the game by `rules` are scored the same way, surrounded or not.
+///
+/// # Examples
+///
+/// ```
+/// use carcasonne_core::builder::tile_builder::TileBuilder;
+/// use carcasonne_core::model::board::{Board, Coord};
+/// use carcasonne_core::model::meeple::{Meeple, SlotId};
+/// use carcasonne_core::model::rules::RulesConfig;
+/// use carcasonne_core::model::tile::{Rotation, TileId};
+/// use carcasonne_core::model::tile_feature::Edge;
+/// use carcasonne_core::scoring::{score_game_end, ScoreCategory};
+///
+/// let road = TileBuilder::new(TileId('U')).add_road(vec![Edge::West, Edge::East]).build();
+/// let mut board = Board::with_start_tile(road.clone());
+/// board.place_tile(Coord::new(1, 0), road, Rotation::Deg0).unwrap();
+/// board.set_meeple(Coord::ORIGIN, Some(Meeple::new(0, SlotId::Feature(0))));
+///
+/// let scores = score_game_end(&board, &RulesConfig::default());
+/// assert_eq!(scores.len(), 1);
+/// assert_eq!(scores[0].category, ScoreCategory::Road);
+/// assert_eq!(scores[0].points, 2);
+/// ```
+pub fn score_game_end(board: &Board, rules: &RulesConfig) -> Vec<FeatureScore> {
+    scorers_for(board, rules).score_game_end(board)
+}
+
+/// Scores a town left unfinished at the end of the game.
+pub fn score_unfinished_town(town: &FeatureSummary) -> FeatureScore {
+    FeatureScore {
+        category: ScoreCategory::Town,
+        points: town.tile_count as u32 * UNFINISHED_TOWN_TILE_POINTS
+            + town.shields as u32 * UNFINISHED_TOWN_SHIELD_POINTS,
+        players: majority_players(&town.meeples),
+    }
+}
+
+/// Scores a road left unfinished at the end of the game, which is worth as much as a
+/// completed one.
+pub fn score_unfinished_road(road: &FeatureSummary) -> FeatureScore {
+    score_completed_road(road)
+}
+
+/// Scores the abbey of the tile placed at `position`, where `meeple` stands, left
+/// unfinished at the end of the game.
+pub fn score_unfinished_abbey(board: &Board, position: Coord, meeple: Meeple) -> FeatureScore {
+    FeatureScore {
+        category: ScoreCategory::Abbey,
+        points: (1 + board.surrounding_count(position) as u32) * UNFINISHED_ABBEY_TILE_POINTS,
+        players: vec![meeple.player],
+    }
+}
+
+/// Scores the farmers lying in the fields when the game ends.
+///
+/// Each field is worth `FIELD_TOWN_POINTS` for every completed town it borders, and
+/// goes to the players with the most farmers in it. Only fields with a farmer are
+/// listed, in the order their first tile was placed. No field is scored when `rules`
+/// play without farmers.
+///
+/// # Examples
+///
+/// ```
+/// use carcasonne_core::builder::tile_builder::TileBuilder;
+/// use carcasonne_core::model::board::{Board, Coord};
+/// use carcasonne_core::model::meeple::{Meeple, SlotId};
+/// use carcasonne_core::model::rules::RulesConfig;
+/// use carcasonne_core::model::tile::{Rotation, TileId};
+/// use carcasonne_core::model::tile_feature::Edge::{East, North, South, West};
+/// use carcasonne_core::scoring::score_farmers;
+///
+/// let e = TileBuilder::new(TileId('E'))
+///     .add_town(vec![North])
+///     .add_field(vec![East, South, West])
+///     .build();
+/// let mut board = Board::with_start_tile(e.clone());
+/// board.place_tile(Coord::new(0, -1), e, Rotation::Deg180).unwrap();
+/// board.set_meeple(Coord::ORIGIN, Some(Meeple::new(0, SlotId::Feature(1))));
+///
+/// let scores = score_farmers(&board, &RulesConfig::default());
+/// assert_eq!(scores[0].points, 3);
+/// assert_eq!(scores[0].players, vec![0]);
+/// ```
+pub fn score_farmers(board: &Board, rules: &RulesConfig) -> Vec<FeatureScore> {
+    scorers_for(board, rules).score_farmers(board)
+}
+
+/// Returns the scorers following `rules`, with the modifiers of the expansions the
+/// tiles of `board` come from.
+fn scorers_for(board: &Board, rules: &RulesConfig) -> Scorers {
+    Scorers::for_tiles(
+        *rules,
+        board.iter().map(|(_, placed_tile)| &placed_tile.tile),
+    )
+}
+
+/// Returns the players owning the most of `meeples`, in turn order.
+///
+/// Features spread over several tiles may end up with meeples of several players
+/// once separate parts are joined. Only the players with the most meeples on the
+/// feature score it, and tied players each receive the full points unless the rules
+/// split them. No player is returned when there is no meeple.
+pub fn majority_players(meeples: &[Meeple]) -> Vec<usize> {
+    let mut counts: Vec<(usize, usize)> = Vec::new();
+    for meeple in meeples {
+        match counts
+            .iter_mut()
+            .find(|(player, _)| *player == meeple.player)
+        {
+            Some((_, count)) => *count += 1,
+            None => counts.push((meeple.player, 1)),
+        }
+    }
+    let most = counts.iter().map(|(_, count)| *count).max().unwrap_or(0);
+    let mut players: Vec<usize> = counts
+        .into_iter()
+        .filter(|(_, count)| *count == most)
+        .map(|(player, _)| player)
+        .collect();
+    players.sort_unstable();
+    players
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::tile_builder::TileBuilder;
+    use crate::model::board::PlacedTile;
+    use crate::model::board::feature_graph::FeatureRef;
+    use crate::model::meeple::SlotId;
+    use crate::model::rules::{AbbeyCompletion, FieldScoring, SharedMajority};
+    use crate::model::tile::{Rotation, Tile, TileId};
+    use crate::model::tile_feature::Edge::{East, North, South, West};
+    use crate::model::tile_feature::FeatureKind;
+
+    #[test]
+    fn test_town_points_count_tiles_and_shields() {
+        let town = FeatureSummary {
+            kind: FeatureKind::Town,
+            members: vec![FeatureRef::new(Coord::ORIGIN, 0)],
+            tile_count: 3,
+            shields: 2,
+            meeples: vec![],
+        };
+        let score = score_completed_town(&town);
+        assert_eq!(score.points, 10);
+        assert!(score.players.is_empty());
+    }
+
+    #[test]
+    fn test_shielded_town_completed_on_board() {
+        let shielded = TileBuilder::new(TileId('F'))
+            .add_shielded_town(vec![North, South])
+            .build();
+        let cap = TileBuilder::new(TileId('E')).add_town(vec![North]).build();
+
+        let mut board = Board::with_start_tile(cap.clone());
+        board.set_meeple(Coord::ORIGIN, Some(Meeple::new(0, SlotId::Feature(0))));
+        board
+            .place_tile(Coord::new(0, -1), shielded, Rotation::Deg0)
+            .unwrap();
+        assert!(score_placement(&board, Coord::new(0, -1), &RulesConfig::default()).is_empty());
+
+        board
+            .place_tile(Coord::new(0, -2), cap, Rotation::Deg180)
+            .unwrap();
+        assert_eq!(
+            score_placement(&board, Coord::new(0, -2), &RulesConfig::default()),
+            vec![FeatureScore {
+                category: ScoreCategory::Town,
+                points: 8,
+                players: vec![0],
+            }]
+        );
+    }
+
+    #[test]
+    fn test_road_closed_by_crossing() {
+        let road_end = TileBuilder::new(TileId('V')).add_road(vec![East]).build();
+        let crossing = TileBuilder::new(TileId('W'))
+            .add_road(vec![West])
+            .add_road(vec![South])
+            .add_road(vec![East])
+            .build();
+
+        let mut board = Board::with_start_tile(road_end);
+        board.set_meeple(Coord::ORIGIN, Some(Meeple::new(1, SlotId::Feature(0))));
+        board
+            .place_tile(Coord::new(1, 0), crossing, Rotation::Deg0)
+            .unwrap();
+        assert_eq!(
+            score_placement(&board, Coord::new(1, 0), &RulesConfig::default()),
+            vec![FeatureScore {
+                category: ScoreCategory::Road,
+                points: 2,
+                players: vec![1],
+            }]
+        );
+    }
+
+    #[test]
+    fn test_road_loop_counts_each_tile_once() {
+        let curve = TileBuilder::new(TileId('V'))
+            .add_road(vec![South, East])
+            .build();
+        let mut board = Board::with_start_tile(curve.clone());
+        board
+            .place_tile(Coord::new(1, 0), curve.clone(), Rotation::Deg90)
+            .unwrap();
+        board
+            .place_tile(Coord::new(1, 1), curve.clone(), Rotation::Deg180)
+            .unwrap();
+        assert!(score_placement(&board, Coord::new(1, 1), &RulesConfig::default()).is_empty());
+
+        board
+            .place_tile(Coord::new(0, 1), curve, Rotation::Deg270)
+            .unwrap();
+        let scores = score_placement(&board, Coord::new(0, 1), &RulesConfig::default());
+        assert_eq!(scores.len(), 1);
+        assert_eq!(scores[0].category, ScoreCategory::Road);
+        assert_eq!(scores[0].points, 4);
+        assert!(scores[0].players.is_empty());
+    }
+
+    #[test]
+    fn test_surrounded_abbey() {
+        let abbey = TileBuilder::new(TileId('B')).add_abbey().build();
+        let mut board = Board::with_start_tile(abbey.clone());
+        board.set_meeple(Coord::ORIGIN, Some(Meeple::new(0, SlotId::Extension)));
+        // From the north, clockwise, so each tile is placed next to another one.
+        let mut around = Coord::ORIGIN.surrounding();
+        around.rotate_left(1);
+        for position in &around[..7] {
+            board
+                .place_tile(*position, abbey.clone(), Rotation::Deg0)
+                .unwrap();
+            assert!(score_placement(&board, *position, &RulesConfig::default()).is_empty());
+        }
+
+        board.place_tile(around[7], abbey, Rotation::Deg0).unwrap();
+        assert_eq!(
+            score_placement(&board, around[7], &RulesConfig::default()),
+            vec![FeatureScore {
+                category: ScoreCategory::Abbey,
+                points: 9,
+                players: vec![0],
+            }]
+        );
+
+        let game_end_only = RulesConfig {
+            abbey_completion: AbbeyCompletion::GameEndOnly,
+            ..RulesConfig::default()
+        };
+        assert!(score_placement(&board, around[7], &game_end_only).is_empty());
+        assert_eq!(score_game_end(&board, &game_end_only)[0].points, 9);
+    }
+
+    #[test]
+    fn test_unfinished_features_at_game_end() {
+        let town = TileBuilder::new(TileId('F'))
+            .add_shielded_town(vec![North, South])
+            .build();
+        let abbey = TileBuilder::new(TileId('B')).add_abbey().build();
+        let mut board = Board::with_start_tile(town.clone());
+        board
+            .place_tile(Coord::new(0, 1), town, Rotation::Deg0)
+            .unwrap();
+        board
+            .place_tile(Coord::new(1, 0), abbey.clone(), Rotation::Deg0)
+            .unwrap();
+        board
+            .place_tile(Coord::new(-1, 0), abbey, Rotation::Deg0)
+            .unwrap();
+        assert!(score_game_end(&board, &RulesConfig::default()).is_empty());
+
+        board.set_meeple(Coord::new(0, 1), Some(Meeple::new(1, SlotId::Feature(0))));
+        board.set_meeple(Coord::new(1, 0), Some(Meeple::new(0, SlotId::Extension)));
+        assert_eq!(
+            score_game_end(&board, &RulesConfig::default()),
+            vec![
+                FeatureScore {
+                    category: ScoreCategory::Town,
+                    points: 4,
+                    players: vec![1],
+                },
+                FeatureScore {
+                    category: ScoreCategory::Abbey,
+                    points: 3,
+                    players: vec![0],
+                },
+            ]
+        );
+    }
+
+    fn town_tile() -> Tile {
+        TileBuilder::new(TileId('E'))
+            .add_town(vec![North])
+            .add_field(vec![East, South, West])
+            .build()
+    }
+
+    fn field_tile() -> Tile {
+        TileBuilder::new(TileId('B'))
+            .add_abbey()
+            .add_field(vec![North, East, South, West])
+            .build()
+    }
+
+    #[test]
+    fn test_farmers_score_completed_towns_only() {
+        let mut board = Board::with_start_tile(town_tile());
+        board
+            .place_tile(Coord::new(1, 0), field_tile(), Rotation::Deg0)
+            .unwrap();
+        board.set_meeple(Coord::new(1, 0), Some(Meeple::new(1, SlotId::Feature(0))));
+        assert_eq!(
+            score_farmers(&board, &RulesConfig::default()),
+            vec![FeatureScore {
+                category: ScoreCategory::Field,
+                points: 0,
+                players: vec![1],
+            }]
+        );
+
+        board
+            .place_tile(Coord::new(0, -1), town_tile(), Rotation::Deg180)
+            .unwrap();
+        assert_eq!(score_farmers(&board, &RulesConfig::default())[0].points, 3);
+
+        let no_farmers = RulesConfig {
+            farmers: false,
+            ..RulesConfig::default()
+        };
+        assert!(score_farmers(&board, &no_farmers).is_empty());
+    }
+
+    #[test]
+    fn test_farmer_majority_takes_the_field() {
+        let mut board = Board::with_start_tile(town_tile());
+        board
+            .place_tile(Coord::new(0, -1), town_tile(), Rotation::Deg180)
+            .unwrap();
+        board
+            .place_tile(Coord::new(1, 0), field_tile(), Rotation::Deg0)
+            .unwrap();
+        board
+            .place_tile(Coord::new(-1, 0), field_tile(), Rotation::Deg0)
+            .unwrap();
+        board.set_meeple(Coord::ORIGIN, Some(Meeple::new(0, SlotId::Feature(1))));
+        board.set_meeple(Coord::new(1, 0), Some(Meeple::new(1, SlotId::Feature(0))));
+        assert_eq!(
+            score_farmers(&board, &RulesConfig::default())[0].players,
+            vec![0, 1]
+        );
+
+        board.set_meeple(Coord::new(-1, 0), Some(Meeple::new(0, SlotId::Feature(0))));
+        let scores = score_farmers(&board, &RulesConfig::default());
+        assert_eq!(scores.len(), 1);
+        assert_eq!(scores[0].players, vec![0]);
+        assert_eq!(scores[0].points, 3);
+    }
+
+    #[test]
+    fn test_first_edition_scores_each_town_once() {
+        let mut board = Board::with_start_tile(town_tile());
+        board
+            .place_tile(Coord::new(0, -1), town_tile(), Rotation::Deg180)
+            .unwrap();
+        board
+            .place_tile(Coord::new(1, -1), field_tile(), Rotation::Deg0)
+            .unwrap();
+        board.set_meeple(Coord::ORIGIN, Some(Meeple::new(0, SlotId::Feature(1))));
+        board.set_meeple(Coord::new(0, -1), Some(Meeple::new(1, SlotId::Feature(1))));
+        board.set_meeple(Coord::new(1, -1), Some(Meeple::new(1, SlotId::Feature(0))));
+
+        let per_town = score_farmers(&board, &RulesConfig::default());
+        assert_eq!(per_town.len(), 2);
+        assert_eq!(per_town[0].points, 3);
+        assert_eq!(per_town[0].players, vec![0]);
+
+        let first_edition = RulesConfig {
+            field_scoring: FieldScoring::FirstEdition,
+            ..RulesConfig::default()
+        };
+        assert_eq!(
+            score_farmers(&board, &first_edition),
+            vec![FeatureScore {
+                category: ScoreCategory::Field,
+                points: 4,
+                players: vec![1],
+            }]
+        );
+    }
+
+    #[test]
+    fn test_joined_road_goes_to_the_majority() {
+        let road_end = TileBuilder::new(TileId('V')).add_road(vec![East]).build();
+        let road = TileBuilder::new(TileId('U'))
+            .add_road(vec![West, East])
+            .build();
+        let mut board = Board::with_start_tile(road_end.clone());
+        board.set_meeple(Coord::ORIGIN, Some(Meeple::new(0, SlotId::Feature(0))));
+        for position in [Coord::new(2, 0), Coord::new(4, 0)] {
+            board.place(PlacedTile::new(road.clone(), Rotation::Deg0, position));
+            board.set_meeple(position, Some(Meeple::new(1, SlotId::Feature(0))));
+        }
+        board.place(PlacedTile::new(
+            road_end,
+            Rotation::Deg180,
+            Coord::new(5, 0),
+        ));
+
+        // Joining both roads of the second player does not close them yet.
+        board
+            .place_tile(Coord::new(3, 0), road.clone(), Rotation::Deg0)
+            .unwrap();
+        assert!(score_placement(&board, Coord::new(3, 0), &RulesConfig::default()).is_empty());
+
+        board
+            .place_tile(Coord::new(1, 0), road, Rotation::Deg0)
+            .unwrap();
+        assert_eq!(
+            score_placement(&board, Coord::new(1, 0), &RulesConfig::default()),
+            vec![FeatureScore {
+                category: ScoreCategory::Road,
+                points: 6,
+                players: vec![1],
+            }]
+        );
+    }
+
+    #[test]
+    fn test_tied_town_scores_full_points_for_each_player() {
+        let cap = TileBuilder::new(TileId('E')).add_town(vec![North]).build();
+        let middle = TileBuilder::new(TileId('F'))
+            .add_town(vec![North, South])
+            .build();
+        let mut board = Board::with_start_tile(cap.clone());
+        board.set_meeple(Coord::ORIGIN, Some(Meeple::new(2, SlotId::Feature(0))));
+        board.place(PlacedTile::new(
+            cap.clone(),
+            Rotation::Deg180,
+            Coord::new(0, -2),
+        ));
+        board.set_meeple(Coord::new(0, -2), Some(Meeple::new(0, SlotId::Feature(0))));
+
+        board
+            .place_tile(Coord::new(0, -1), middle, Rotation::Deg0)
+            .unwrap();
+        assert_eq!(
+            score_placement(&board, Coord::new(0, -1), &RulesConfig::default()),
+            vec![FeatureScore {
+                category: ScoreCategory::Town,
+                points: 6,
+                players: vec![0, 2],
+            }]
+        );
+
+        let split = RulesConfig {
+            shared_majority: SharedMajority::SplitPoints,
+            ..RulesConfig::default()
+        };
+        assert_eq!(
+            score_placement(&board, Coord::new(0, -1), &split)[0].points,
+            3
+        );
+    }
+
+    #[test]
+    fn test_breakdown_adds_up() {
+        let mut breakdown = ScoreBreakdown::default();
+        breakdown.add(ScoreCategory::Town, 4);
+        breakdown.add(ScoreCategory::Field, 6);
+        breakdown.add(ScoreCategory::Town, 2);
+        assert_eq!(breakdown.towns, 6);
+        assert_eq!(breakdown.fields, 6);
+        assert_eq!(breakdown.total(), 12);
+    }
+
+    #[test]
+    fn test_majority_players() {
+        let meeples = [
+            Meeple::new(2, SlotId::Feature(0)),
+            Meeple::new(1, SlotId::Feature(0)),
+            Meeple::new(2, SlotId::Feature(1)),
+            Meeple::new(1, SlotId::Feature(1)),
+            Meeple::new(0, SlotId::Feature(1)),
+        ];
+        assert_eq!(majority_players(&meeples), vec![1, 2]);
+        assert_eq!(majority_players(&meeples[..3]), vec![2]);
+        assert!(majority_players(&[]).is_empty());
+    }
+}
@@ -0,0 +1,254 @@
+use crate::model::board::feature_graph::FeatureRef;
+use crate::model::board::{Board, Coord, PlacedTile};
+use crate::model::meeple::SlotId;
+use crate::model::rules::RulesConfig;
+use crate::model::tile::{Rotation, Tile, TileId};
+use crate::model::tile_feature::FeatureKind;
+use crate::scoring::{
+    ABBEY_POINTS, FeatureScore, ScoreCategory, score_completed_road, score_completed_town,
+};
+
+/// What an unfinished town, road or abbey could still be worth.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Potential {
+    /// The points the feature would be worth if it were completed without growing any
+    /// further, and the players they would go to.
+    pub score: FeatureScore,
+    /// The empty positions left to fill before the feature is completed, row by row
+    /// from the north-west.
+    pub open_positions: Vec<Coord>,
+    /// The number of tiles left in the bag that could fill one of the open positions
+    /// without leaving the feature any new one. While a single position is left open,
+    /// these are the tiles that would complete the feature.
+    pub completing_tiles: usize,
+}
+
+/// Estimates what the features of a board could still be worth, given the tiles left
+/// in the bag.
+///
+/// The estimates are meant for hints, evaluators and overlays: they do not change the
+/// board, and do not take the players' turns into account.
+///
+/// # Examples
+///
+/// ```
+/// use carcasonne_core::builder::tile_builder::TileBuilder;
+/// use carcasonne_core::model::board::feature_graph::FeatureRef;
+/// use carcasonne_core::model::board::{Board, Coord};
+/// use carcasonne_core::model::rules::RulesConfig;
+/// use carcasonne_core::model::tile::TileId;
+/// use carcasonne_core::model::tile_feature::Edge;
+/// use carcasonne_core::scoring::estimator::Estimator;
+///
+/// let town = TileBuilder::new(TileId('E')).add_town(vec![Edge::North]).build();
+/// let road = TileBuilder::new(TileId('U')).add_road(vec![Edge::West, Edge::East]).build();
+/// let board = Board::with_start_tile(town.clone());
+/// let bag = vec![town.clone(), town, road];
+///
+/// let estimator = Estimator::new(&board, &bag, RulesConfig::default());
+/// let potential = estimator.feature(FeatureRef::new(Coord::ORIGIN, 0)).unwrap();
+/// assert_eq!(potential.score.points, 2);
+/// assert_eq!(potential.open_positions, vec![Coord::new(0, -1)]);
+/// assert_eq!(potential.completing_tiles, 2);
+/// ```
+pub struct Estimator<'a> {
+    board: &'a Board,
+    bag: &'a [Tile],
+    rules: RulesConfig,
+}
+
+impl<'a> Estimator<'a> {
+    /// Creates an estimator of the features of `board`, with `bag` the tiles left to
+    /// draw, following `rules`.
+    pub fn new(board: &'a Board, bag: &'a [Tile], rules: RulesConfig) -> Self {
+        Self { board, bag, rules }
+    }
+
+    /// Estimates the town or road `feature` belongs to, or returns `None` if it is a
+    /// field, is not on the board or is already completed.
+    pub fn feature(&self, feature: FeatureRef) -> Option<Potential> {
+        let summary = self.board.feature_summary(feature)?;
+        let score = match summary.kind {
+            FeatureKind::Town => score_completed_town(&summary),
+            FeatureKind::Road => score_completed_road(&summary),
+            FeatureKind::Field => return None,
+        };
+        let open_positions = self.board.open_positions_of(feature);
+        if open_positions.is_empty() {
+            return None;
+        }
+        let completing_tiles = self.count_fitting(&open_positions, |board| {
+            let left_open = board.open_positions_of(feature);
+            left_open
+                .iter()
+                .all(|position| open_positions.contains(position))
+        });
+        Some(Potential {
+            score: self.share(score),
+            open_positions,
+            completing_tiles,
+        })
+    }
+
+    /// Estimates the abbey, or another extension scored like one, of the tile at
+    /// `position`, or returns `None` if there is none or it is already surrounded.
+    pub fn abbey(&self, position: Coord) -> Option<Potential> {
+        let placed_tile = self.board.get(position)?;
+        placed_tile.tile.tile_extension?;
+        let open_positions: Vec<Coord> = position
+            .surrounding()
+            .into_iter()
+            .filter(|around| !self.board.contains(*around))
+            .collect();
+        if open_positions.is_empty() {
+            return None;
+        }
+        let players = placed_tile
+            .meeple
+            .filter(|meeple| meeple.slot == SlotId::Extension)
+            .map(|meeple| meeple.player);
+        let score = FeatureScore {
+            category: ScoreCategory::Abbey,
+            points: ABBEY_POINTS,
+            players: players.into_iter().collect(),
+        };
+        let completing_tiles = self.count_fitting(&open_positions, |_| true);
+        Some(Potential {
+            score: self.share(score),
+            open_positions,
+            completing_tiles,
+        })
+    }
+
+    /// Estimates every unfinished town, road and abbey of the board: the towns and
+    /// roads in the order their first tile was placed, then the abbeys.
+    pub fn unfinished(&self) -> Vec<Potential> {
+        let features = self
+            .board
+            .unfinished_features()
+            .into_iter()
+            .filter_map(|summary| self.feature(summary.members[0]));
+        let mut abbeys: Vec<Coord> = self
+            .board
+            .iter()
+            .filter(|(_, placed_tile)| placed_tile.tile.tile_extension.is_some())
+            .map(|(position, _)| position)
+            .collect();
+        abbeys.sort_by_key(|position| (position.y, position.x));
+        features
+            .chain(
+                abbeys
+                    .into_iter()
+                    .filter_map(|position| self.abbey(position)),
+            )
+            .collect()
+    }
+
+    /// Counts the tiles of the bag that can be placed at one of `positions` in a way
+    /// `fits` accepts, trying each kind of tile once.
+    fn count_fitting(&self, positions: &[Coord], fits: impl Fn(&Board) -> bool) -> usize {
+        let mut kinds: Vec<(TileId, &Tile, usize)> = Vec::new();
+        for tile in self.bag {
+            match kinds.iter_mut().find(|(id, _, _)| *id == tile.id) {
+                Some((_, _, count)) => *count += 1,
+                None => kinds.push((tile.id, tile, 1)),
+            }
+        }
+
+        let mut board = self.board.snapshot();
+        let mut fitting = 0;
+        for (_, tile, count) in kinds {
+            let fits_somewhere = positions.iter().any(|position| {
+                Rotation::ALL.into_iter().any(|rotation| {
+                    let placed_tile = PlacedTile::new(tile.clone(), rotation, *position);
+                    if board.check_placement(&placed_tile).is_err() {
+                        return false;
+                    }
+                    board.place(placed_tile);
+                    let fits = fits(&board);
+                    board.remove_last();
+                    fits
+                })
+            });
+            if fits_somewhere {
+                fitting += count;
+            }
+        }
+        fitting
+    }
+
+    /// Shares the points of `score` between its players as the rules say.
+    fn share(&self, score: FeatureScore) -> FeatureScore {
+        FeatureScore {
+            points: self
+                .rules
+                .points_per_player(score.points, score.players.len()),
+            ..score
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::tile_builder::TileBuilder;
+    use crate::model::meeple::Meeple;
+    use crate::model::tile_feature::Edge::{East, North, South, West};
+
+    fn road() -> Tile {
+        TileBuilder::new(TileId('U'))
+            .add_road(vec![West, East])
+            .build()
+    }
+
+    fn road_end() -> Tile {
+        TileBuilder::new(TileId('V')).add_road(vec![West]).build()
+    }
+
+    #[test]
+    fn test_road_needs_both_ends_closed() {
+        let mut board = Board::with_start_tile(road());
+        board
+            .place_tile(Coord::new(1, 0), road(), Rotation::Deg0)
+            .unwrap();
+        board.set_meeple(
+            Coord::ORIGIN,
+            Some(Meeple {
+                player: 1,
+                slot: SlotId::Feature(0),
+            }),
+        );
+        let bag = vec![road(), road_end(), road_end()];
+        let estimator = Estimator::new(&board, &bag, RulesConfig::default());
+
+        let potential = estimator
+            .feature(FeatureRef::new(Coord::ORIGIN, 0))
+            .unwrap();
+        assert_eq!(potential.score.points, 2);
+        assert_eq!(potential.score.players, vec![1]);
+        assert_eq!(
+            potential.open_positions,
+            vec![Coord::new(-1, 0), Coord::new(2, 0)]
+        );
+        // A straight road leaves an end open; a road end closes one.
+        assert_eq!(potential.completing_tiles, 2);
+    }
+
+    #[test]
+    fn test_abbey_counts_the_tiles_fitting_around_it() {
+        let abbey = TileBuilder::new(TileId('B')).add_abbey().build();
+        let town = TileBuilder::new(TileId('C'))
+            .add_town(vec![North, East, South, West])
+            .build();
+        let board = Board::with_start_tile(abbey.clone());
+        let bag = vec![abbey, town];
+        let estimator = Estimator::new(&board, &bag, RulesConfig::default());
+
+        let potential = estimator.abbey(Coord::ORIGIN).unwrap();
+        assert_eq!(potential.score.points, ABBEY_POINTS);
+        assert_eq!(potential.open_positions.len(), 8);
+        assert_eq!(potential.completing_tiles, 1);
+        assert_eq!(estimator.unfinished(), vec![potential]);
+        assert_eq!(estimator.feature(FeatureRef::new(Coord::ORIGIN, 0)), None);
+    }
+}
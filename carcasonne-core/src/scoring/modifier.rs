@@ -0,0 +1,146 @@
+use crate::model::board::{Board, FeatureSummary};
+use crate::model::tile_feature::TileFeatureEnhancement;
+use crate::scoring::scorer::{Feature, is_complete};
+use crate::scoring::{CATHEDRAL_BONUS_POINTS, FeatureScore, INN_BONUS_POINTS};
+
+/// Changes the score of a feature after its scorer counted it.
+///
+/// Modifiers let expansions change how features are scored without replacing the
+/// scorers of the base game. `Scorers` applies them in the order they were added,
+/// each to the score left by the previous one, before tied players share the points.
+pub trait ScoreModifier: Send + Sync {
+    /// Returns the score of `feature` on `board`, given `score`, as counted so far.
+    fn modify(&self, feature: &Feature, board: &Board, score: FeatureScore) -> FeatureScore;
+}
+
+/// Scores towns with a cathedral, from Inns & Cathedrals: once completed they are
+/// worth `CATHEDRAL_BONUS_POINTS` more for each tile and shield, and nothing while
+/// unfinished.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CathedralModifier;
+
+impl ScoreModifier for CathedralModifier {
+    fn modify(&self, feature: &Feature, board: &Board, score: FeatureScore) -> FeatureScore {
+        let Feature::Joined(town) = feature else {
+            return score;
+        };
+        if !has_enhancement(town, board, TileFeatureEnhancement::Cathedral) {
+            return score;
+        }
+        let points = if is_complete(town, board) {
+            score.points + (town.tile_count + town.shields) as u32 * CATHEDRAL_BONUS_POINTS
+        } else {
+            0
+        };
+        FeatureScore { points, ..score }
+    }
+}
+
+/// Scores roads with an inn, from Inns & Cathedrals: once completed they are worth
+/// `INN_BONUS_POINTS` more for each tile, and nothing while unfinished.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct InnModifier;
+
+impl ScoreModifier for InnModifier {
+    fn modify(&self, feature: &Feature, board: &Board, score: FeatureScore) -> FeatureScore {
+        let Feature::Joined(road) = feature else {
+            return score;
+        };
+        if !has_enhancement(road, board, TileFeatureEnhancement::Inn) {
+            return score;
+        }
+        let points = if is_complete(road, board) {
+            score.points + road.tile_count as u32 * INN_BONUS_POINTS
+        } else {
+            0
+        };
+        FeatureScore { points, ..score }
+    }
+}
+
+/// Returns whether one of the tile features of `feature` bears `enhancement`.
+fn has_enhancement(
+    feature: &FeatureSummary,
+    board: &Board,
+    enhancement: TileFeatureEnhancement,
+) -> bool {
+    feature.members.iter().any(|member| {
+        board.get(member.position).is_some_and(|placed_tile| {
+            placed_tile.tile.tile_features[member.index].enhancement == Some(enhancement)
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::tile_builder::TileBuilder;
+    use crate::model::board::Coord;
+    use crate::model::meeple::{Meeple, SlotId};
+    use crate::model::rules::RulesConfig;
+    use crate::model::tile::{Rotation, TileId};
+    use crate::model::tile_feature::Edge::{East, North, West};
+    use crate::scoring::ScoreCategory;
+    use crate::scoring::scorer::Scorers;
+
+    #[test]
+    fn test_cathedral_town_is_worth_more_once_completed() {
+        let cathedral = TileBuilder::new(TileId('c'))
+            .add_cathedral_town(vec![North])
+            .build();
+        let town = TileBuilder::new(TileId('E'))
+            .add_shielded_town(vec![North])
+            .build();
+        let mut board = Board::with_start_tile(cathedral);
+        board.set_meeple(Coord::ORIGIN, Some(Meeple::new(0, SlotId::Feature(0))));
+        let scorers = Scorers::new(RulesConfig::default()).with_modifier(CathedralModifier);
+        assert_eq!(scorers.score_game_end(&board)[0].points, 0);
+
+        board
+            .place_tile(Coord::new(0, -1), town, Rotation::Deg180)
+            .unwrap();
+        let scores = scorers.score_placement(&board, Coord::new(0, -1));
+        assert_eq!(scores[0].category, ScoreCategory::Town);
+        // Two tiles and a shield, worth three points each.
+        assert_eq!(scores[0].points, 9);
+        assert_eq!(
+            Scorers::default().score_placement(&board, Coord::new(0, -1))[0].points,
+            6
+        );
+    }
+
+    #[test]
+    fn test_inn_road_is_worth_more_once_completed() {
+        let inn = TileBuilder::new(TileId('i'))
+            .add_inn_road(vec![West, East])
+            .build();
+        let end = TileBuilder::new(TileId('V')).add_road(vec![West]).build();
+        let mut board = Board::with_start_tile(inn);
+        board
+            .place_tile(Coord::new(1, 0), end.clone(), Rotation::Deg0)
+            .unwrap();
+        let road = board.unfinished_features().remove(0);
+        let unfinished = FeatureScore {
+            category: ScoreCategory::Road,
+            points: 2,
+            players: vec![],
+        };
+        assert_eq!(
+            InnModifier
+                .modify(&Feature::Joined(&road), &board, unfinished.clone())
+                .points,
+            0
+        );
+
+        board
+            .place_tile(Coord::new(-1, 0), end, Rotation::Deg180)
+            .unwrap();
+        let scorers = Scorers::default()
+            .with_modifier(CathedralModifier)
+            .with_modifier(InnModifier);
+        assert_eq!(
+            scorers.score_placement(&board, Coord::new(-1, 0))[0].points,
+            6
+        );
+    }
+}
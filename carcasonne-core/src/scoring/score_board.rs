@@ -0,0 +1,670 @@
+use crate::model::board::Coord;
+use crate::model::player::MAX_PLAYERS;
+use crate::model::rules::TieBreak;
+use crate::scoring::scorer::ScoredFeature;
+use crate::scoring::{ScoreBreakdown, ScoreCategory};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+
+/// Points given to a player at once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScoreEntry {
+    /// The turn the points were scored during, starting from 1.
+    pub turn: usize,
+    /// The player receiving the points.
+    pub player: usize,
+    /// The points received.
+    pub points: u32,
+    /// What the points were scored for.
+    pub reason: ScoreCategory,
+    /// Whether the points were scored when the game ended.
+    pub end_game: bool,
+}
+
+/// A scored feature, as recorded in the audit log of a score board.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditEntry {
+    /// The turn the feature was scored during, starting from 1.
+    pub turn: usize,
+    /// What the feature was scored as.
+    pub category: ScoreCategory,
+    /// The points each of `players` received.
+    pub points: u32,
+    /// The players the points went to, in turn order.
+    pub players: Vec<usize>,
+    /// The positions of the tiles making up the feature, row by row from the
+    /// north-west.
+    pub tiles: Vec<Coord>,
+    /// Whether the feature was scored when the game ended.
+    pub end_game: bool,
+}
+
+/// The points of a player disagreeing with the history of a score board, as found by
+/// `ScoreBoard::check_consistency`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScoreMismatch {
+    /// The player whose points disagree.
+    pub player: usize,
+    /// The points of the player, split by category, as kept by the score board.
+    pub recorded: ScoreBreakdown,
+    /// The points of the player, split by category, as counted again from the history.
+    pub recomputed: ScoreBreakdown,
+}
+
+impl Display for ScoreMismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "player {} has {} points, but their history adds up to {}",
+            self.player,
+            self.recorded.total(),
+            self.recomputed.total()
+        )
+    }
+}
+
+impl std::error::Error for ScoreMismatch {}
+
+/// A saved score board naming more players than a game can have, which is refused
+/// when it is loaded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TooManyPlayers {
+    /// The number of players named by the totals or the history of the score board.
+    pub players: usize,
+}
+
+impl Display for TooManyPlayers {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "a score board holds at most {MAX_PLAYERS} players, not {}",
+            self.players
+        )
+    }
+}
+
+impl std::error::Error for TooManyPlayers {}
+
+/// The place of a player in the ranking.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Standing {
+    /// The player.
+    pub player: usize,
+    /// The points of the player.
+    pub points: u32,
+    /// The place of the player, starting from 1. Players tied after the tie-break
+    /// share the same place.
+    pub rank: usize,
+}
+
+/// The points of each player, split by category, and the history of how they were
+/// scored.
+///
+/// Points are only given through `add_points`, which records them in the history
+/// along with the turn set by `set_turn`, and whether `end_game` was called.
+/// `score_feature` gives the points of a whole feature, and also keeps the tiles it
+/// was made of in the audit log, so a scoring can be checked from a saved game.
+///
+/// # Examples
+///
+/// ```
+/// use carcasonne_core::scoring::score_board::ScoreBoard;
+/// use carcasonne_core::scoring::ScoreCategory;
+///
+/// let mut score_board = ScoreBoard::new(3);
+/// score_board.add_points(1, 4, ScoreCategory::Town);
+/// score_board.set_turn(2);
+/// score_board.add_points(2, 3, ScoreCategory::Road);
+/// score_board.add_points(1, 1, ScoreCategory::Abbey);
+///
+/// assert_eq!(score_board.score_of(1), 5);
+/// let ranking: Vec<(usize, u32)> = score_board
+///     .ranking()
+///     .iter()
+///     .map(|standing| (standing.player, standing.points))
+///     .collect();
+/// assert_eq!(ranking, vec![(1, 5), (2, 3), (0, 0)]);
+/// assert_eq!(score_board.leader(), Some(1));
+/// assert_eq!(score_board.points_in_turn(2), vec![0, 1, 3]);
+///
+/// score_board.end_game();
+/// score_board.add_points(1, 2, ScoreCategory::Road);
+/// let breakdown = score_board.breakdown_of(1);
+/// assert_eq!((breakdown.towns, breakdown.roads, breakdown.end_game), (4, 2, 2));
+/// assert_eq!(breakdown.total(), score_board.score_of(1));
+/// ```
+///
+/// The score board serializes with its history, so saved games and replays keep the
+/// whole scoring state. A loaded score board keeps the points of every player named
+/// by its totals or its history, and is refused if they name more than `MAX_PLAYERS`
+/// players.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "SavedScoreBoard")]
+pub struct ScoreBoard {
+    scores: Vec<u32>,
+    breakdowns: Vec<ScoreBreakdown>,
+    history: Vec<ScoreEntry>,
+    audit: Vec<AuditEntry>,
+    turn: usize,
+    end_game: bool,
+    stranded_meeples: Vec<usize>,
+    tie_break: TieBreak,
+}
+
+impl Default for ScoreBoard {
+    /// Returns the score board of no players, at the first turn.
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+/// A score board as saved, whose lists may not all have the same players, for
+/// instance when it was written before some of them were kept.
+#[derive(Deserialize)]
+#[serde(default)]
+struct SavedScoreBoard {
+    scores: Vec<u32>,
+    breakdowns: Vec<ScoreBreakdown>,
+    history: Vec<ScoreEntry>,
+    audit: Vec<AuditEntry>,
+    turn: usize,
+    end_game: bool,
+    stranded_meeples: Vec<usize>,
+    tie_break: TieBreak,
+}
+
+impl Default for SavedScoreBoard {
+    fn default() -> Self {
+        let score_board = ScoreBoard::default();
+        Self {
+            scores: score_board.scores,
+            breakdowns: score_board.breakdowns,
+            history: score_board.history,
+            audit: score_board.audit,
+            turn: score_board.turn,
+            end_game: score_board.end_game,
+            stranded_meeples: score_board.stranded_meeples,
+            tie_break: score_board.tie_break,
+        }
+    }
+}
+
+impl TryFrom<SavedScoreBoard> for ScoreBoard {
+    type Error = TooManyPlayers;
+
+    fn try_from(saved: SavedScoreBoard) -> Result<Self, Self::Error> {
+        let mut score_board = Self {
+            scores: saved.scores,
+            breakdowns: saved.breakdowns,
+            history: saved.history,
+            audit: saved.audit,
+            turn: saved.turn,
+            end_game: saved.end_game,
+            stranded_meeples: saved.stranded_meeples,
+            tie_break: saved.tie_break,
+        };
+        let player_count = score_board.player_count();
+        if player_count > MAX_PLAYERS {
+            return Err(TooManyPlayers {
+                players: player_count,
+            });
+        }
+        score_board.scores.resize(player_count, 0);
+        score_board
+            .breakdowns
+            .resize(player_count, ScoreBreakdown::default());
+        score_board.stranded_meeples.resize(player_count, 0);
+        Ok(score_board)
+    }
+}
+
+impl ScoreBoard {
+    /// Creates the score board of `player_count` players, who have no points yet,
+    /// at the first turn.
+    pub fn new(player_count: usize) -> Self {
+        Self {
+            scores: vec![0; player_count],
+            breakdowns: vec![ScoreBreakdown::default(); player_count],
+            history: Vec::new(),
+            audit: Vec::new(),
+            turn: 1,
+            end_game: false,
+            stranded_meeples: vec![0; player_count],
+            tie_break: TieBreak::default(),
+        }
+    }
+
+    /// Sets how players tied for points are ranked.
+    pub fn with_tie_break(mut self, tie_break: TieBreak) -> Self {
+        self.tie_break = tie_break;
+        self
+    }
+
+    /// Sets the turn the next points are scored during.
+    pub fn set_turn(&mut self, turn: usize) {
+        self.turn = turn;
+    }
+
+    /// Marks the next points as scored when the game ended.
+    pub fn end_game(&mut self) {
+        self.end_game = true;
+    }
+
+    /// Sets the number of meeples `player` has left on the board when the game ended.
+    pub fn set_stranded_meeples(&mut self, player: usize, meeples: usize) {
+        if let Some(stranded) = self.stranded_meeples.get_mut(player) {
+            *stranded = meeples;
+        }
+    }
+
+    /// Gives `points` scored for `reason` to `player`, and records them in the history.
+    ///
+    /// Points given to an unknown player are ignored.
+    pub fn add_points(&mut self, player: usize, points: u32, reason: ScoreCategory) {
+        let Some(score) = self.scores.get_mut(player) else {
+            return;
+        };
+        *score += points;
+        let breakdown = &mut self.breakdowns[player];
+        breakdown.add(reason, points);
+        if self.end_game {
+            breakdown.end_game += points;
+        }
+        self.history.push(ScoreEntry {
+            turn: self.turn,
+            player,
+            points,
+            reason,
+            end_game: self.end_game,
+        });
+    }
+
+    /// Gives the points of the scored feature `scored` to each of its players, and
+    /// records the feature in the audit log.
+    pub fn score_feature(&mut self, scored: &ScoredFeature) {
+        let score = &scored.score;
+        for player in &score.players {
+            self.add_points(*player, score.points, score.category);
+        }
+        self.audit.push(AuditEntry {
+            turn: self.turn,
+            category: score.category,
+            points: score.points,
+            players: score.players.clone(),
+            tiles: scored.tiles.clone(),
+            end_game: self.end_game,
+        });
+    }
+
+    /// Returns the points of `player`.
+    pub fn score_of(&self, player: usize) -> u32 {
+        self.scores.get(player).copied().unwrap_or(0)
+    }
+
+    /// Returns the points of each player, in turn order.
+    pub fn scores(&self) -> &[u32] {
+        &self.scores
+    }
+
+    /// Returns the points of `player`, split by category.
+    pub fn breakdown_of(&self, player: usize) -> ScoreBreakdown {
+        self.breakdowns.get(player).copied().unwrap_or_default()
+    }
+
+    /// Returns the points of each player split by category, in turn order.
+    pub fn breakdowns(&self) -> &[ScoreBreakdown] {
+        &self.breakdowns
+    }
+
+    /// Returns the number of features `player` took part in scoring before the game
+    /// ended.
+    pub fn completed_features(&self, player: usize) -> usize {
+        self.history
+            .iter()
+            .filter(|entry| entry.player == player && !entry.end_game)
+            .count()
+    }
+
+    /// Returns the number of meeples `player` had left on the board when the game
+    /// ended, or 0 before.
+    pub fn stranded_meeples(&self, player: usize) -> usize {
+        self.stranded_meeples.get(player).copied().unwrap_or(0)
+    }
+
+    /// Returns the standings of every player, the most points first.
+    ///
+    /// Players tied for points are ordered by the tie-break. Players still tied share
+    /// their place, and are listed in turn order.
+    pub fn ranking(&self) -> Vec<Standing> {
+        let players: Vec<usize> = (0..self.scores.len()).collect();
+        self.ranking_of(&players)
+    }
+
+    /// Returns the standings of `players` alone, as `ranking` does.
+    pub fn ranking_of(&self, players: &[usize]) -> Vec<Standing> {
+        let mut players = players.to_vec();
+        players.sort_by(|a, b| self.compare(*a, *b));
+        let mut standings: Vec<Standing> = Vec::with_capacity(players.len());
+        for (index, player) in players.into_iter().enumerate() {
+            let rank = match standings.last() {
+                Some(last) if self.compare(last.player, player) == Ordering::Equal => last.rank,
+                _ => index + 1,
+            };
+            standings.push(Standing {
+                player,
+                points: self.score_of(player),
+                rank,
+            });
+        }
+        standings
+    }
+
+    /// Returns the player ranked first, or `None` while several players share the
+    /// first place.
+    pub fn leader(&self) -> Option<usize> {
+        match self.ranking()[..] {
+            [first, second, ..] if second.rank > first.rank => Some(first.player),
+            [first] => Some(first.player),
+            _ => None,
+        }
+    }
+
+    /// Returns every points given, in the order they were scored.
+    pub fn history(&self) -> &[ScoreEntry] {
+        &self.history
+    }
+
+    /// Counts the points of each player again from the history, replacing the totals
+    /// and breakdowns kept so far.
+    ///
+    /// Repairs a score board whose totals were lost or damaged, for instance when
+    /// loading a save written before the breakdowns were kept.
+    pub fn recompute_from_history(&mut self) {
+        let (scores, breakdowns) = self.totals_from_history();
+        self.scores = scores;
+        self.breakdowns = breakdowns;
+        let player_count = self.scores.len();
+        self.stranded_meeples.resize(player_count, 0);
+    }
+
+    /// Checks that the points kept for each player add up to their history.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ScoreMismatch` for the first player, in turn order, whose points
+    /// or breakdown disagree with the history.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use carcasonne_core::scoring::score_board::ScoreBoard;
+    /// use carcasonne_core::scoring::ScoreCategory;
+    ///
+    /// let mut score_board = ScoreBoard::new(2);
+    /// score_board.add_points(1, 4, ScoreCategory::Town);
+    /// assert!(score_board.check_consistency().is_ok());
+    ///
+    /// let json = r#"{"scores": [0, 9], "history": [
+    ///     {"turn": 1, "player": 1, "points": 4, "reason": "Town", "end_game": false}
+    /// ]}"#;
+    /// let mut loaded: ScoreBoard = serde_json::from_str(json).unwrap();
+    /// assert_eq!(loaded.check_consistency().unwrap_err().player, 1);
+    /// loaded.recompute_from_history();
+    /// assert_eq!(loaded.scores(), score_board.scores());
+    /// assert!(loaded.check_consistency().is_ok());
+    /// ```
+    pub fn check_consistency(&self) -> Result<(), ScoreMismatch> {
+        let (scores, breakdowns) = self.totals_from_history();
+        for player in 0..scores.len().max(self.scores.len()) {
+            let recorded = self.breakdown_of(player);
+            let recomputed = breakdowns.get(player).copied().unwrap_or_default();
+            let score = scores.get(player).copied().unwrap_or(0);
+            if recorded != recomputed || self.score_of(player) != score {
+                return Err(ScoreMismatch {
+                    player,
+                    recorded,
+                    recomputed,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns every feature scored, in the order they were scored.
+    pub fn audit(&self) -> &[AuditEntry] {
+        &self.audit
+    }
+
+    /// Returns the points each player scored during `turn`, in turn order.
+    pub fn points_in_turn(&self, turn: usize) -> Vec<u32> {
+        let mut points = vec![0; self.scores.len()];
+        for entry in self.history.iter().filter(|entry| entry.turn == turn) {
+            points[entry.player] += entry.points;
+        }
+        points
+    }
+
+    /// Adds up the points of each player from the history: their totals and their
+    /// breakdowns, for as many players as the board or its history has.
+    fn totals_from_history(&self) -> (Vec<u32>, Vec<ScoreBreakdown>) {
+        let player_count = self.player_count();
+        let mut scores = vec![0; player_count];
+        let mut breakdowns = vec![ScoreBreakdown::default(); player_count];
+        for entry in &self.history {
+            scores[entry.player] += entry.points;
+            breakdowns[entry.player].add(entry.reason, entry.points);
+            if entry.end_game {
+                breakdowns[entry.player].end_game += entry.points;
+            }
+        }
+        (scores, breakdowns)
+    }
+
+    /// Returns the number of players the score board or its history has.
+    fn player_count(&self) -> usize {
+        self.history
+            .iter()
+            .map(|entry| entry.player + 1)
+            .max()
+            .unwrap_or(0)
+            .max(self.scores.len())
+    }
+
+    /// Orders `a` before `b` if they rank higher.
+    fn compare(&self, a: usize, b: usize) -> Ordering {
+        let tie_break = match self.tie_break {
+            TieBreak::SharedWin => Ordering::Equal,
+            TieBreak::MostCompletedFeatures => {
+                self.completed_features(b).cmp(&self.completed_features(a))
+            }
+            TieBreak::FewestStrandedMeeples => {
+                self.stranded_meeples(a).cmp(&self.stranded_meeples(b))
+            }
+        };
+        self.score_of(b).cmp(&self.score_of(a)).then(tie_break)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scoring::FeatureScore;
+
+    #[test]
+    fn test_history_follows_the_turns() {
+        let mut score_board = ScoreBoard::new(2);
+        score_board.add_points(0, 2, ScoreCategory::Road);
+        score_board.set_turn(3);
+        score_board.add_points(1, 8, ScoreCategory::Town);
+        score_board.add_points(4, 8, ScoreCategory::Town);
+
+        assert_eq!(score_board.scores(), &[2, 8]);
+        assert_eq!(
+            score_board.history(),
+            &[
+                ScoreEntry {
+                    turn: 1,
+                    player: 0,
+                    points: 2,
+                    reason: ScoreCategory::Road,
+                    end_game: false,
+                },
+                ScoreEntry {
+                    turn: 3,
+                    player: 1,
+                    points: 8,
+                    reason: ScoreCategory::Town,
+                    end_game: false,
+                },
+            ]
+        );
+        assert_eq!(score_board.points_in_turn(2), vec![0, 0]);
+    }
+
+    #[test]
+    fn test_no_leader_while_tied() {
+        let mut score_board = ScoreBoard::new(3);
+        assert_eq!(score_board.leader(), None);
+        score_board.add_points(2, 3, ScoreCategory::Field);
+        assert_eq!(score_board.leader(), Some(2));
+        score_board.add_points(0, 3, ScoreCategory::Road);
+        assert_eq!(score_board.leader(), None);
+        let ranks: Vec<(usize, usize)> = score_board
+            .ranking()
+            .iter()
+            .map(|standing| (standing.player, standing.rank))
+            .collect();
+        assert_eq!(ranks, vec![(0, 1), (2, 1), (1, 3)]);
+        assert_eq!(ScoreBoard::new(1).leader(), Some(0));
+    }
+
+    #[test]
+    fn test_tie_breaks() {
+        let mut score_board = ScoreBoard::new(3);
+        score_board.add_points(1, 4, ScoreCategory::Town);
+        score_board.add_points(2, 2, ScoreCategory::Road);
+        score_board.add_points(2, 2, ScoreCategory::Road);
+        score_board.end_game();
+        score_board.add_points(0, 4, ScoreCategory::Field);
+        score_board.set_stranded_meeples(0, 1);
+        score_board.set_stranded_meeples(1, 3);
+        score_board.set_stranded_meeples(2, 2);
+        let players = |score_board: &ScoreBoard| -> Vec<(usize, usize)> {
+            score_board
+                .ranking()
+                .iter()
+                .map(|standing| (standing.player, standing.rank))
+                .collect()
+        };
+
+        assert_eq!(players(&score_board), vec![(0, 1), (1, 1), (2, 1)]);
+        let score_board = score_board.with_tie_break(TieBreak::MostCompletedFeatures);
+        assert_eq!(players(&score_board), vec![(2, 1), (1, 2), (0, 3)]);
+        assert_eq!(score_board.leader(), Some(2));
+        let score_board = score_board.with_tie_break(TieBreak::FewestStrandedMeeples);
+        assert_eq!(players(&score_board), vec![(0, 1), (2, 2), (1, 3)]);
+        assert_eq!(score_board.ranking_of(&[1, 2])[0].player, 2);
+    }
+
+    #[test]
+    fn test_serializes_with_its_history() {
+        let mut score_board = ScoreBoard::new(2).with_tie_break(TieBreak::MostCompletedFeatures);
+        score_board.add_points(1, 4, ScoreCategory::Town);
+        score_board.set_turn(2);
+        score_board.end_game();
+        score_board.add_points(0, 3, ScoreCategory::Field);
+        score_board.set_stranded_meeples(0, 2);
+
+        let json = serde_json::to_string(&score_board).unwrap();
+        let decoded: ScoreBoard = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, score_board);
+        assert_eq!(decoded.history()[1].reason, ScoreCategory::Field);
+        assert_eq!(decoded.breakdown_of(0).end_game, 3);
+    }
+
+    #[test]
+    fn test_loaded_score_board_keeps_every_player() {
+        let json = r#"{"scores": [0], "history": [
+            {"turn": 1, "player": 1, "points": 4, "reason": "Town", "end_game": false}
+        ]}"#;
+        let mut score_board: ScoreBoard = serde_json::from_str(json).unwrap();
+        assert_eq!(score_board.points_in_turn(1), vec![0, 4]);
+
+        score_board.add_points(1, 3, ScoreCategory::Road);
+        score_board.set_stranded_meeples(1, 2);
+        assert_eq!(score_board.breakdown_of(1).roads, 3);
+        assert_eq!(score_board.stranded_meeples(1), 2);
+        score_board.recompute_from_history();
+        assert_eq!(score_board.scores(), &[0, 7]);
+    }
+
+    #[test]
+    fn test_loading_refuses_too_many_players() {
+        let json = r#"{"scores": [0, 0], "history": [
+            {"turn": 1, "player": 4000000000, "points": 4, "reason": "Town", "end_game": false}
+        ]}"#;
+        let error = serde_json::from_str::<ScoreBoard>(json).unwrap_err();
+        assert!(error.to_string().contains("not 4000000001"));
+
+        let json = format!(r#"{{"scores": {:?}}}"#, vec![0; MAX_PLAYERS + 1]);
+        assert!(serde_json::from_str::<ScoreBoard>(&json).is_err());
+    }
+
+    #[test]
+    fn test_default_and_loaded_score_boards_start_at_the_first_turn() {
+        assert_eq!(ScoreBoard::default(), ScoreBoard::new(0));
+
+        let json = r#"{"scores": [0, 0]}"#;
+        let mut loaded: ScoreBoard = serde_json::from_str(json).unwrap();
+        assert_eq!(loaded, ScoreBoard::new(2));
+        loaded.add_points(1, 2, ScoreCategory::Road);
+        assert_eq!(loaded.history()[0].turn, 1);
+    }
+
+    #[test]
+    fn test_audit_keeps_the_tiles_of_scored_features() {
+        let mut score_board = ScoreBoard::new(2);
+        score_board.set_turn(4);
+        score_board.score_feature(&ScoredFeature {
+            score: FeatureScore {
+                category: ScoreCategory::Road,
+                points: 3,
+                players: vec![0, 1],
+            },
+            tiles: vec![Coord::new(-1, 0), Coord::ORIGIN, Coord::new(1, 0)],
+        });
+
+        assert_eq!(score_board.scores(), &[3, 3]);
+        assert_eq!(score_board.history().len(), 2);
+        let entry = &score_board.audit()[0];
+        assert_eq!((entry.turn, entry.players.clone()), (4, vec![0, 1]));
+        assert_eq!(entry.tiles.len(), 3);
+        let json = serde_json::to_string(&score_board).unwrap();
+        assert_eq!(
+            serde_json::from_str::<ScoreBoard>(&json).unwrap(),
+            score_board
+        );
+    }
+
+    #[test]
+    fn test_recompute_repairs_damaged_totals() {
+        let mut score_board = ScoreBoard::new(2);
+        score_board.add_points(0, 4, ScoreCategory::Town);
+        score_board.end_game();
+        score_board.add_points(0, 3, ScoreCategory::Field);
+        let expected = score_board.clone();
+
+        score_board.breakdowns[0].fields = 0;
+        let mismatch = score_board.check_consistency().unwrap_err();
+        assert_eq!(mismatch.player, 0);
+        assert_eq!(mismatch.recomputed, expected.breakdown_of(0));
+        assert_eq!(
+            mismatch.to_string(),
+            "player 0 has 4 points, but their history adds up to 7"
+        );
+
+        score_board.recompute_from_history();
+        assert_eq!(score_board, expected);
+    }
+}
@@ -0,0 +1,827 @@
+use crate::model::board::feature_graph::FeatureRef;
+use crate::model::board::{Board, Coord, FeatureSummary};
+use crate::model::meeple::{Meeple, SlotId};
+use crate::model::rules::{AbbeyCompletion, FieldScoring, RulesConfig};
+use crate::model::tile::Tile;
+use crate::model::tile_feature::FeatureKind;
+use crate::model::tile_meta::ExpansionId;
+use crate::scoring::modifier::{CathedralModifier, InnModifier, ScoreModifier};
+use crate::scoring::{
+    ABBEY_POINTS, FIELD_TOWN_POINTS, FIRST_EDITION_FIELD_TOWN_POINTS, FeatureScore, ScoreCategory,
+    majority_players, score_completed_road, score_completed_town, score_unfinished_abbey,
+    score_unfinished_road, score_unfinished_town,
+};
+use std::fmt::{Display, Formatter};
+
+/// A feature of the board to be scored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Feature<'a> {
+    /// A town, road or field, joined across the tiles it spreads over.
+    Joined(&'a FeatureSummary),
+    /// The abbey, or another tile extension scored like one, of the tile at `position`.
+    Abbey {
+        /// The position of the tile holding the extension.
+        position: Coord,
+        /// The meeple standing on the extension, if any.
+        meeple: Option<Meeple>,
+    },
+}
+
+impl Feature<'_> {
+    /// Returns what the feature is scored as.
+    pub fn category(&self) -> ScoreCategory {
+        match self {
+            Feature::Joined(summary) => match summary.kind {
+                FeatureKind::Town => ScoreCategory::Town,
+                FeatureKind::Road => ScoreCategory::Road,
+                FeatureKind::Field => ScoreCategory::Field,
+            },
+            Feature::Abbey { .. } => ScoreCategory::Abbey,
+        }
+    }
+
+    /// Returns the positions of the tiles making up the feature on `board`, row by row
+    /// from the north-west: the tiles a town, road or field spreads over, or an abbey
+    /// and the tiles around it.
+    pub fn tiles(&self, board: &Board) -> Vec<Coord> {
+        let mut tiles: Vec<Coord> = match self {
+            Feature::Joined(summary) => summary
+                .members
+                .iter()
+                .map(|member| member.position)
+                .collect(),
+            Feature::Abbey { position, .. } => std::iter::once(*position)
+                .chain(
+                    position
+                        .surrounding()
+                        .into_iter()
+                        .filter(|around| board.contains(*around)),
+                )
+                .collect(),
+        };
+        tiles.sort_by_key(|position| (position.y, position.x));
+        tiles.dedup();
+        tiles
+    }
+}
+
+/// The score of a feature, along with the tiles it was made of when it was scored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScoredFeature {
+    /// The points of the feature, and the players they go to.
+    pub score: FeatureScore,
+    /// The positions of the tiles making up the feature, as `Feature::tiles` lists them.
+    pub tiles: Vec<Coord>,
+}
+
+/// Computes the points of one kind of feature.
+///
+/// A scorer only counts the points of a feature and finds the players they go to;
+/// how tied players share them is left to `Scorers`. Rule variants and expansions
+/// score a feature differently by handing their own scorer to `Scorers`. Scorers are
+/// `Send` and `Sync`, as games are searched on several threads.
+pub trait Scorer: Send + Sync {
+    /// Scores `feature` as it stands on `board`: completed during the game, or left
+    /// unfinished when the game ends.
+    fn score(&self, feature: &Feature, board: &Board) -> FeatureScore;
+}
+
+/// Scores towns: `TOWN_TILE_POINTS` per tile and `TOWN_SHIELD_POINTS` per shield once
+/// completed, and `UNFINISHED_TOWN_TILE_POINTS` per tile and
+/// `UNFINISHED_TOWN_SHIELD_POINTS` per shield otherwise.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TownScorer;
+
+impl Scorer for TownScorer {
+    fn score(&self, feature: &Feature, board: &Board) -> FeatureScore {
+        match feature {
+            Feature::Joined(town) if is_complete(town, board) => score_completed_town(town),
+            Feature::Joined(town) => score_unfinished_town(town),
+            Feature::Abbey { .. } => nothing(feature),
+        }
+    }
+}
+
+/// Scores roads: `ROAD_TILE_POINTS` per tile, completed or not.
+///
+/// The roads meeting at a junction, such as on the `W`, `X` and `L` tiles, each end
+/// there. A road closing onto itself is complete, and each of its tiles counts once.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RoadScorer;
+
+impl Scorer for RoadScorer {
+    fn score(&self, feature: &Feature, board: &Board) -> FeatureScore {
+        match feature {
+            Feature::Joined(road) if is_complete(road, board) => score_completed_road(road),
+            Feature::Joined(road) => score_unfinished_road(road),
+            Feature::Abbey { .. } => nothing(feature),
+        }
+    }
+}
+
+/// Scores abbeys: `ABBEY_POINTS` once surrounded by eight tiles, and
+/// `UNFINISHED_ABBEY_TILE_POINTS` for the abbey and each tile around it otherwise.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AbbeyScorer;
+
+impl Scorer for AbbeyScorer {
+    fn score(&self, feature: &Feature, board: &Board) -> FeatureScore {
+        match *feature {
+            Feature::Abbey { position, meeple } => match meeple {
+                Some(meeple) if board.surrounding_count(position) < 8 => {
+                    score_unfinished_abbey(board, position, meeple)
+                }
+                _ => FeatureScore {
+                    category: ScoreCategory::Abbey,
+                    points: ABBEY_POINTS,
+                    players: meeple.iter().map(|meeple| meeple.player).collect(),
+                },
+            },
+            Feature::Joined(_) => nothing(feature),
+        }
+    }
+}
+
+impl AbbeyScorer {
+    /// Returns how far the abbey of the tile at `position` is from being surrounded,
+    /// or `None` if no tile there has an abbey or another extension.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use carcasonne_core::builder::tile_builder::TileBuilder;
+    /// use carcasonne_core::model::board::{Board, Coord};
+    /// use carcasonne_core::model::tile::{Rotation, TileId};
+    /// use carcasonne_core::scoring::scorer::AbbeyScorer;
+    ///
+    /// let abbey = TileBuilder::new(TileId('B')).add_abbey().build();
+    /// let mut board = Board::with_start_tile(abbey.clone());
+    /// board.place_tile(Coord::new(1, 0), abbey, Rotation::Deg0).unwrap();
+    ///
+    /// let progress = AbbeyScorer::progress(&board, Coord::ORIGIN).unwrap();
+    /// assert_eq!(progress.to_string(), "2/9 tiles");
+    /// assert!(!progress.is_complete());
+    /// ```
+    pub fn progress(board: &Board, position: Coord) -> Option<AbbeyProgress> {
+        board.get(position)?.tile.tile_extension?;
+        Some(AbbeyProgress {
+            tiles: 1 + board.surrounding_count(position),
+        })
+    }
+}
+
+/// How far an abbey is from being surrounded: the number of tiles among the abbey
+/// and the eight around it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AbbeyProgress {
+    /// The abbey tile and the tiles placed around it.
+    pub tiles: usize,
+}
+
+impl AbbeyProgress {
+    /// The number of tiles of a surrounded abbey, itself included.
+    pub const COMPLETE: usize = 9;
+
+    /// Returns whether the abbey is surrounded by eight tiles.
+    pub fn is_complete(&self) -> bool {
+        self.tiles >= Self::COMPLETE
+    }
+}
+
+impl Display for AbbeyProgress {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{} tiles", self.tiles, Self::COMPLETE)
+    }
+}
+
+/// Scores fields at the end of the game, as `scoring` says.
+///
+/// Under the first edition rules, the field handed to the scorer gathers all the
+/// fields bordering one completed town, and is worth the points of that town.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FieldScorer {
+    /// How the fields are scored.
+    pub scoring: FieldScoring,
+}
+
+impl Scorer for FieldScorer {
+    fn score(&self, feature: &Feature, board: &Board) -> FeatureScore {
+        let Feature::Joined(field) = feature else {
+            return nothing(feature);
+        };
+        let points = match self.scoring {
+            FieldScoring::PerCompletedTown => {
+                let completed_towns = board
+                    .towns_bordering(field.members[0])
+                    .into_iter()
+                    .filter(|town| board.is_feature_complete(*town))
+                    .count();
+                completed_towns as u32 * FIELD_TOWN_POINTS
+            }
+            FieldScoring::FirstEdition => FIRST_EDITION_FIELD_TOWN_POINTS,
+        };
+        FeatureScore {
+            category: ScoreCategory::Field,
+            points,
+            players: majority_players(&field.meeples),
+        }
+    }
+}
+
+/// Returns whether every tile feature of `feature` is closed.
+pub(crate) fn is_complete(feature: &FeatureSummary, board: &Board) -> bool {
+    feature
+        .members
+        .first()
+        .is_some_and(|member| board.is_feature_complete(*member))
+}
+
+/// Returns the score of a feature a scorer does not handle: no points for anyone.
+fn nothing(feature: &Feature) -> FeatureScore {
+    FeatureScore {
+        category: feature.category(),
+        points: 0,
+        players: Vec::new(),
+    }
+}
+
+/// The scorers a game is played with, one for each kind of feature, and the rules
+/// telling when features are scored and how tied players share their points.
+///
+/// A feature is scored through a pipeline: the scorer of its kind counts its points,
+/// then each modifier, in the order they were added, changes the score, and last the
+/// points are shared between tied players.
+///
+/// `Scorers::new` picks the scorers following the rules, with no modifier, and
+/// `Scorers::for_tiles` adds the modifiers of the expansions the tiles come from; any
+/// of the scorers can then be replaced, and more modifiers added with `with_modifier`.
+///
+/// # Examples
+///
+/// ```
+/// use carcasonne_core::builder::tile_builder::TileBuilder;
+/// use carcasonne_core::model::board::{Board, Coord};
+/// use carcasonne_core::model::rules::RulesConfig;
+/// use carcasonne_core::model::tile::{Rotation, TileId};
+/// use carcasonne_core::model::tile_feature::Edge;
+/// use carcasonne_core::scoring::scorer::{Feature, Scorer, Scorers};
+/// use carcasonne_core::scoring::{FeatureScore, ScoreCategory};
+///
+/// struct FlatRoads;
+///
+/// impl Scorer for FlatRoads {
+///     fn score(&self, _feature: &Feature, _board: &Board) -> FeatureScore {
+///         FeatureScore {
+///             category: ScoreCategory::Road,
+///             points: 5,
+///             players: vec![],
+///         }
+///     }
+/// }
+///
+/// let end = TileBuilder::new(TileId('V')).add_road(vec![Edge::East]).build();
+/// let mut board = Board::with_start_tile(end.clone());
+/// board.place_tile(Coord::new(1, 0), end, Rotation::Deg180).unwrap();
+///
+/// let scorers = Scorers::new(RulesConfig::default()).with_road(FlatRoads);
+/// assert_eq!(scorers.score_placement(&board, Coord::new(1, 0))[0].points, 5);
+/// ```
+pub struct Scorers {
+    rules: RulesConfig,
+    town: Box<dyn Scorer>,
+    road: Box<dyn Scorer>,
+    abbey: Box<dyn Scorer>,
+    field: Box<dyn Scorer>,
+    modifiers: Vec<Box<dyn ScoreModifier>>,
+}
+
+impl Scorers {
+    /// Creates the scorers following `rules`.
+    pub fn new(rules: RulesConfig) -> Self {
+        Self {
+            rules,
+            town: Box::new(TownScorer),
+            road: Box::new(RoadScorer),
+            abbey: Box::new(AbbeyScorer),
+            field: Box::new(FieldScorer {
+                scoring: rules.field_scoring,
+            }),
+            modifiers: Vec::new(),
+        }
+    }
+
+    /// Creates the scorers following `rules`, with the modifiers of every expansion
+    /// one of `tiles` is printed in. Tiles without metadata add no modifier.
+    pub fn for_tiles<'a>(rules: RulesConfig, tiles: impl IntoIterator<Item = &'a Tile>) -> Self {
+        let mut expansions: Vec<ExpansionId> = Vec::new();
+        for meta in tiles.into_iter().filter_map(|tile| tile.meta) {
+            if !expansions.contains(&meta.expansion) {
+                expansions.push(meta.expansion);
+            }
+        }
+        expansions.sort();
+        expansions
+            .into_iter()
+            .fold(Self::new(rules), Self::with_expansion)
+    }
+
+    /// Adds the modifiers of `expansion`: cathedrals and inns for Inns & Cathedrals.
+    /// The other expansions have none yet.
+    pub fn with_expansion(self, expansion: ExpansionId) -> Self {
+        match expansion {
+            ExpansionId::InnsAndCathedrals => self
+                .with_modifier(CathedralModifier)
+                .with_modifier(InnModifier),
+            ExpansionId::Base | ExpansionId::TradersAndBuilders | ExpansionId::Abbot => self,
+        }
+    }
+
+    /// Scores towns with `scorer`.
+    pub fn with_town(mut self, scorer: impl Scorer + 'static) -> Self {
+        self.town = Box::new(scorer);
+        self
+    }
+
+    /// Scores roads with `scorer`.
+    pub fn with_road(mut self, scorer: impl Scorer + 'static) -> Self {
+        self.road = Box::new(scorer);
+        self
+    }
+
+    /// Scores abbeys, and the extensions scored like them, with `scorer`.
+    pub fn with_abbey(mut self, scorer: impl Scorer + 'static) -> Self {
+        self.abbey = Box::new(scorer);
+        self
+    }
+
+    /// Scores fields with `scorer`.
+    pub fn with_field(mut self, scorer: impl Scorer + 'static) -> Self {
+        self.field = Box::new(scorer);
+        self
+    }
+
+    /// Adds `modifier` at the end of the pipeline, after the modifiers added before.
+    pub fn with_modifier(mut self, modifier: impl ScoreModifier + 'static) -> Self {
+        self.modifiers.push(Box::new(modifier));
+        self
+    }
+
+    /// Returns the rules the scorers follow.
+    pub fn rules(&self) -> &RulesConfig {
+        &self.rules
+    }
+
+    /// Scores `feature` with the scorer of its kind, then with each modifier, and
+    /// shares the points between tied players as the rules say.
+    pub fn score(&self, feature: &Feature, board: &Board) -> FeatureScore {
+        let scorer = match feature.category() {
+            ScoreCategory::Town => &self.town,
+            ScoreCategory::Road => &self.road,
+            ScoreCategory::Abbey => &self.abbey,
+            ScoreCategory::Field => &self.field,
+        };
+        let score = self
+            .modifiers
+            .iter()
+            .fold(scorer.score(feature, board), |score, modifier| {
+                modifier.modify(feature, board, score)
+            });
+        FeatureScore {
+            points: self
+                .rules
+                .points_per_player(score.points, score.players.len()),
+            ..score
+        }
+    }
+
+    /// Scores `feature` as `score` does, and notes the tiles it is made of.
+    pub fn audit(&self, feature: &Feature, board: &Board) -> ScoredFeature {
+        ScoredFeature {
+            score: self.score(feature, board),
+            tiles: feature.tiles(board),
+        }
+    }
+
+    /// Scores the features completed by the tile placed at `position`, as
+    /// `score_placement` does.
+    pub fn score_placement(&self, board: &Board, position: Coord) -> Vec<FeatureScore> {
+        scores_of(self.audit_placement(board, position))
+    }
+
+    /// Scores the towns, roads and abbeys left unfinished when the game ends, as
+    /// `score_game_end` does.
+    pub fn score_game_end(&self, board: &Board) -> Vec<FeatureScore> {
+        scores_of(self.audit_game_end(board))
+    }
+
+    /// Scores the farmers lying in the fields when the game ends, as `score_farmers`
+    /// does.
+    pub fn score_farmers(&self, board: &Board) -> Vec<FeatureScore> {
+        scores_of(self.audit_farmers(board))
+    }
+
+    /// Scores the features completed by the tile placed at `position` as
+    /// `score_placement` does, noting the tiles of each.
+    pub fn audit_placement(&self, board: &Board, position: Coord) -> Vec<ScoredFeature> {
+        let abbeys = match self.rules.abbey_completion {
+            AbbeyCompletion::Surrounded => board.completed_abbeys_after(position),
+            AbbeyCompletion::GameEndOnly => Vec::new(),
+        };
+        let features = board.completed_features_after(position);
+        features
+            .iter()
+            .filter(|feature| feature.kind != FeatureKind::Field)
+            .map(Feature::Joined)
+            .chain(abbeys.iter().map(|abbey| Feature::Abbey {
+                position: abbey.position,
+                meeple: abbey.meeple,
+            }))
+            .map(|feature| self.audit(&feature, board))
+            .collect()
+    }
+
+    /// Scores the towns, roads and abbeys left unfinished when the game ends as
+    /// `score_game_end` does, noting the tiles of each.
+    pub fn audit_game_end(&self, board: &Board) -> Vec<ScoredFeature> {
+        let features = board.unfinished_features();
+        let mut abbeys: Vec<(Coord, Meeple)> = board
+            .iter()
+            .filter_map(|(position, placed_tile)| {
+                let meeple = placed_tile.meeple?;
+                (meeple.slot == SlotId::Extension).then_some((position, meeple))
+            })
+            .collect();
+        abbeys.sort_by_key(|(position, _)| (position.y, position.x));
+        features
+            .iter()
+            .filter(|feature| feature.kind != FeatureKind::Field && !feature.meeples.is_empty())
+            .map(Feature::Joined)
+            .chain(abbeys.into_iter().map(|(position, meeple)| Feature::Abbey {
+                position,
+                meeple: Some(meeple),
+            }))
+            .map(|feature| self.audit(&feature, board))
+            .collect()
+    }
+
+    /// Scores the farmers lying in the fields when the game ends as `score_farmers`
+    /// does, noting the tiles of each field.
+    pub fn audit_farmers(&self, board: &Board) -> Vec<ScoredFeature> {
+        if !self.rules.farmers {
+            return Vec::new();
+        }
+        let fields: Vec<FeatureSummary> = board
+            .fields()
+            .into_iter()
+            .filter(|field| !field.meeples.is_empty())
+            .collect();
+        let fields = match self.rules.field_scoring {
+            FieldScoring::PerCompletedTown => fields,
+            FieldScoring::FirstEdition => fields_by_completed_town(board, &fields),
+        };
+        fields
+            .iter()
+            .map(|field| self.audit(&Feature::Joined(field), board))
+            .collect()
+    }
+}
+
+/// Keeps the scores of `scored`, leaving out their tiles.
+fn scores_of(scored: Vec<ScoredFeature>) -> Vec<FeatureScore> {
+    scored.into_iter().map(|scored| scored.score).collect()
+}
+
+/// Gathers `fields` by the completed towns they border: for each town, in the order
+/// the fields first reach it, a field made of all the fields bordering it.
+fn fields_by_completed_town(board: &Board, fields: &[FeatureSummary]) -> Vec<FeatureSummary> {
+    let mut towns: Vec<(FeatureRef, FeatureSummary)> = Vec::new();
+    for field in fields {
+        let completed_towns = board
+            .towns_bordering(field.members[0])
+            .into_iter()
+            .filter(|town| board.is_feature_complete(*town));
+        for town in completed_towns {
+            let supplying = towns
+                .iter_mut()
+                .find(|(other, _)| board.features().connected(*other, town));
+            match supplying {
+                Some((_, supplying)) => {
+                    supplying.members.extend(&field.members);
+                    supplying.meeples.extend(&field.meeples);
+                }
+                None => towns.push((town, field.clone())),
+            }
+        }
+    }
+    towns
+        .into_iter()
+        .map(|(_, mut field)| {
+            let mut positions: Vec<Coord> =
+                field.members.iter().map(|member| member.position).collect();
+            positions.sort_by_key(|position| (position.y, position.x));
+            positions.dedup();
+            field.tile_count = positions.len();
+            field
+        })
+        .collect()
+}
+
+impl Default for Scorers {
+    fn default() -> Self {
+        Self::new(RulesConfig::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::tile_builder::TileBuilder;
+    use crate::factory::tile_factory::TileFactory;
+    use crate::factory::tile_factory::road_tiles_factory::RoadTileBuilder;
+    use crate::factory::tile_factory::town_tiles_factory::TownTileBuilder;
+    use crate::model::board::PlacedTile;
+    use crate::model::board::feature_graph::FeatureRef;
+    use crate::model::tile::{Rotation, Tile, TileId};
+    use crate::model::tile_feature::Edge::{self, East, North, South, West};
+
+    struct DoubleTowns;
+
+    impl Scorer for DoubleTowns {
+        fn score(&self, feature: &Feature, board: &Board) -> FeatureScore {
+            let score = TownScorer.score(feature, board);
+            FeatureScore {
+                points: score.points * 2,
+                ..score
+            }
+        }
+    }
+
+    #[test]
+    fn test_town_scorer_tells_completed_from_unfinished() {
+        let cap = TileBuilder::new(TileId('E')).add_town(vec![North]).build();
+        let mut board = Board::with_start_tile(cap.clone());
+        let unfinished = &board.unfinished_features()[0];
+        assert_eq!(
+            TownScorer
+                .score(&Feature::Joined(unfinished), &board)
+                .points,
+            1
+        );
+
+        board
+            .place_tile(Coord::new(0, -1), cap, Rotation::Deg180)
+            .unwrap();
+        let completed = &board.completed_features_after(Coord::new(0, -1))[0];
+        assert_eq!(
+            TownScorer.score(&Feature::Joined(completed), &board).points,
+            4
+        );
+        let abbey = Feature::Abbey {
+            position: Coord::ORIGIN,
+            meeple: None,
+        };
+        assert_eq!(TownScorer.score(&abbey, &board).points, 0);
+    }
+
+    /// Lays `tile` at the origin, and closes its town with an `E` tile on each of
+    /// `edges` but the last. Returns the town points with the last edge still open,
+    /// then once it is closed too.
+    fn town_points(tile: Tile, edges: &[Edge]) -> (u32, u32) {
+        let cap = TileBuilder::new(TileId('E')).add_town(vec![North]).build();
+        let mut board = Board::with_start_tile(tile);
+        let close = |board: &mut Board, edge: Edge| {
+            let (position, rotation) = match edge {
+                North => (Coord::new(0, -1), Rotation::Deg180),
+                East => (Coord::new(1, 0), Rotation::Deg270),
+                South => (Coord::new(0, 1), Rotation::Deg0),
+                West => (Coord::new(-1, 0), Rotation::Deg90),
+            };
+            board.place_tile(position, cap.clone(), rotation).unwrap();
+            position
+        };
+        let (last, others) = edges.split_last().unwrap();
+        for edge in others {
+            close(&mut board, *edge);
+        }
+        let unfinished = board
+            .unfinished_features()
+            .into_iter()
+            .find(|feature| feature.kind == FeatureKind::Town)
+            .unwrap();
+        let unfinished_points = TownScorer
+            .score(&Feature::Joined(&unfinished), &board)
+            .points;
+
+        let position = close(&mut board, *last);
+        let completed = board
+            .completed_features_after(position)
+            .into_iter()
+            .find(|feature| feature.kind == FeatureKind::Town)
+            .unwrap();
+        let completed_points = TownScorer
+            .score(&Feature::Joined(&completed), &board)
+            .points;
+        (unfinished_points, completed_points)
+    }
+
+    #[test]
+    fn test_shielded_towns_of_the_base_game() {
+        // Tiles count 2 and shields 2 in a completed town, and 1 each when unfinished.
+        assert_eq!(
+            town_points(TileFactory::build_c_town(), &[North, East, South, West]),
+            (4 + 1, 2 * 5 + 2)
+        );
+        assert_eq!(
+            town_points(TileFactory::build_f_town(), &[West, East]),
+            (2 + 1, 2 * 3 + 2)
+        );
+        assert_eq!(
+            town_points(TileFactory::build_m_town(), &[North, West]),
+            (2 + 1, 2 * 3 + 2)
+        );
+        assert_eq!(
+            town_points(TileFactory::build_o_town(), &[North, West]),
+            (2 + 1, 2 * 3 + 2)
+        );
+        assert_eq!(
+            town_points(TileFactory::build_q_town(), &[North, West, East]),
+            (3 + 1, 2 * 4 + 2)
+        );
+        assert_eq!(
+            town_points(TileFactory::build_s_town(), &[North, West, East]),
+            (3 + 1, 2 * 4 + 2)
+        );
+    }
+
+    #[test]
+    fn test_shields_of_joined_tiles_add_up() {
+        let mut board = Board::with_start_tile(TileFactory::build_m_town());
+        board
+            .place_tile(
+                Coord::new(-1, 0),
+                TileFactory::build_f_town(),
+                Rotation::Deg0,
+            )
+            .unwrap();
+        let cap = TileBuilder::new(TileId('E')).add_town(vec![North]).build();
+        board
+            .place_tile(Coord::new(-2, 0), cap.clone(), Rotation::Deg90)
+            .unwrap();
+        let town = board
+            .unfinished_features()
+            .into_iter()
+            .find(|feature| feature.kind == FeatureKind::Town)
+            .unwrap();
+        assert_eq!(town.shields, 2);
+        assert_eq!(
+            TownScorer.score(&Feature::Joined(&town), &board).points,
+            3 + 2
+        );
+
+        board
+            .place_tile(Coord::new(0, -1), cap, Rotation::Deg180)
+            .unwrap();
+        let town = &board.completed_features_after(Coord::new(0, -1))[0];
+        assert_eq!(
+            TownScorer.score(&Feature::Joined(town), &board).points,
+            2 * 4 + 2 * 2
+        );
+    }
+
+    /// Returns the road the tile feature `index` of the tile at `position` belongs to.
+    fn road_at(board: &Board, position: Coord, index: usize) -> FeatureSummary {
+        board
+            .unfinished_features()
+            .into_iter()
+            .chain(board.completed_features_after(position))
+            .find(|feature| feature.members.contains(&FeatureRef::new(position, index)))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_road_between_junctions() {
+        let mut board = Board::with_start_tile(TileFactory::build_x_road());
+        board
+            .place_tile(
+                Coord::new(1, 0),
+                TileFactory::build_u_road(),
+                Rotation::Deg90,
+            )
+            .unwrap();
+        board.set_meeple(Coord::new(1, 0), Some(Meeple::new(1, SlotId::Feature(0))));
+        board
+            .place_tile(
+                Coord::new(2, 0),
+                TileFactory::build_w_road(),
+                Rotation::Deg0,
+            )
+            .unwrap();
+
+        let completed = board.completed_features_after(Coord::new(2, 0));
+        assert_eq!(completed.len(), 1);
+        assert_eq!(
+            RoadScorer.score(&Feature::Joined(&completed[0]), &board),
+            FeatureScore {
+                category: ScoreCategory::Road,
+                points: 3,
+                players: vec![1],
+            }
+        );
+
+        // The other roads of both junctions end there, and stay apart.
+        for (position, index) in [
+            (Coord::ORIGIN, 0),
+            (Coord::ORIGIN, 1),
+            (Coord::new(2, 0), 0),
+        ] {
+            let road = road_at(&board, position, index);
+            assert_eq!(road.tile_count, 1);
+            assert_eq!(RoadScorer.score(&Feature::Joined(&road), &board).points, 1);
+        }
+    }
+
+    #[test]
+    fn test_road_from_town_junction_to_town_junction() {
+        let mut board = Board::with_start_tile(TileFactory::build_l_town());
+        board
+            .place_tile(
+                Coord::new(1, 0),
+                TileFactory::build_u_road(),
+                Rotation::Deg90,
+            )
+            .unwrap();
+        let road = road_at(&board, Coord::new(1, 0), 0);
+        assert_eq!(RoadScorer.score(&Feature::Joined(&road), &board).points, 2);
+        assert!(!is_complete(&road, &board));
+
+        board
+            .place_tile(
+                Coord::new(2, 0),
+                TileFactory::build_l_town(),
+                Rotation::Deg0,
+            )
+            .unwrap();
+        let road = road_at(&board, Coord::new(1, 0), 0);
+        assert!(is_complete(&road, &board));
+        assert_eq!(road.members.len(), 3);
+        assert_eq!(RoadScorer.score(&Feature::Joined(&road), &board).points, 3);
+    }
+
+    #[test]
+    fn test_road_loop_of_curves() {
+        let curve = TileFactory::build_v_road;
+        let mut board = Board::new();
+        board.place(PlacedTile::new(curve(), Rotation::Deg180, Coord::ORIGIN));
+        board.set_meeple(Coord::ORIGIN, Some(Meeple::new(0, SlotId::Feature(0))));
+        board
+            .place_tile(Coord::new(1, 0), curve(), Rotation::Deg270)
+            .unwrap();
+        board
+            .place_tile(Coord::new(1, 1), curve(), Rotation::Deg0)
+            .unwrap();
+        let road = road_at(&board, Coord::ORIGIN, 0);
+        assert!(!is_complete(&road, &board));
+
+        board
+            .place_tile(Coord::new(0, 1), curve(), Rotation::Deg90)
+            .unwrap();
+        let completed = board.completed_features_after(Coord::new(0, 1));
+        let road = completed
+            .iter()
+            .find(|feature| feature.kind == FeatureKind::Road)
+            .unwrap();
+        assert_eq!(road.members.len(), 4);
+        assert_eq!(
+            RoadScorer.score(&Feature::Joined(road), &board),
+            FeatureScore {
+                category: ScoreCategory::Road,
+                points: 4,
+                players: vec![0],
+            }
+        );
+    }
+
+    #[test]
+    fn test_swapped_scorer_is_used() {
+        let cap = TileBuilder::new(TileId('E')).add_town(vec![North]).build();
+        let road = TileBuilder::new(TileId('V')).add_road(vec![East]).build();
+        let mut board = Board::with_start_tile(cap.clone());
+        board
+            .place_tile(Coord::new(0, -1), cap, Rotation::Deg180)
+            .unwrap();
+
+        let scorers = Scorers::default().with_town(DoubleTowns);
+        assert_eq!(
+            scorers.score_placement(&board, Coord::new(0, -1))[0].points,
+            8
+        );
+        let mut board = Board::with_start_tile(road.clone());
+        board
+            .place_tile(Coord::new(1, 0), road, Rotation::Deg180)
+            .unwrap();
+        assert_eq!(
+            scorers.score_placement(&board, Coord::new(1, 0))[0].points,
+            2
+        );
+    }
+}
@@ -1,6 +1,7 @@
 use crate::action::Action;
 use crate::input_handler::InputEvent;
 use crate::layout::node::Node;
+use crate::model::game::Game;
 
 pub mod game_state;
 
@@ -54,7 +55,7 @@ pub trait State {
     /// # Returns
     ///
     /// A `Node` representing the complete layout to draw.
-    fn draw(&self) -> Node;
+    fn draw(&self) -> Node<'_>;
 
     /// Handles a user input event and produces an `Action`.
     ///
@@ -81,4 +82,11 @@ pub trait State {
     fn need_input(&self) -> bool {
         false
     }
+
+    /// Returns the game being played in this state, if any.
+    ///
+    /// The application uses it to record the session, for instance in crash reports.
+    fn game(&self) -> Option<&Game> {
+        None
+    }
 }
@@ -2,12 +2,31 @@ use crate::action::Action;
 use crate::factory::game_factory::GameTilesFactory;
 use crate::input_handler::InputEvent;
 use crate::layout::node::Node;
+use crate::model::player::{Player, MAX_PLAYERS, MIN_PLAYERS};
 use crate::state::game_state::playing_state::select_tile_state::SelectTileState;
 use crate::state::game_state::playing_state::PlayingPhase;
 use crate::state::StateResult::{Continue, Skip};
 use crate::state::{State, StateResult};
 
-pub struct MenuState {}
+/// The main menu, where the number of local players is chosen before starting a game.
+pub struct MenuState {
+    player_count: usize,
+}
+
+impl MenuState {
+    /// Creates the menu with the minimum number of players selected.
+    pub fn new() -> Self {
+        Self {
+            player_count: MIN_PLAYERS,
+        }
+    }
+}
+
+impl Default for MenuState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 impl State for MenuState {
     fn update(&mut self, action: Action) -> StateResult {
@@ -15,19 +34,38 @@ impl State for MenuState {
             Action::StartGame => Continue(Box::new(PlayingPhase::new(
                 Box::new(SelectTileState {}),
                 GameTilesFactory::build_base_game(),
+                Player::local_players(self.player_count),
             ))),
+            Action::Left => {
+                self.player_count = (self.player_count - 1).max(MIN_PLAYERS);
+                Skip
+            }
+            Action::Right => {
+                self.player_count = (self.player_count + 1).min(MAX_PLAYERS);
+                Skip
+            }
             _ => Skip,
         }
     }
 
-    fn draw(&self) -> Node {
-        Node::Text("Press <Enter> to start playing")
+    fn draw(&self) -> Node<'_> {
+        let count = char::from_digit(self.player_count as u32, 10).unwrap_or('?');
+        Node::VerticalContainer(vec![
+            Box::new(Node::Text("Press <Enter> to start playing")),
+            Box::new(Node::HorizontalContainer(vec![
+                Box::new(Node::Text("Players: < ")),
+                Box::new(Node::Char(count)),
+                Box::new(Node::Text(" >")),
+            ])),
+        ])
     }
 
     fn handle_input(&self, event: InputEvent) -> Action {
         match event {
             InputEvent::Quit => Action::Quit,
             InputEvent::Enter => Action::StartGame,
+            InputEvent::Left => Action::Left,
+            InputEvent::Right => Action::Right,
             _ => Action::None,
         }
     }
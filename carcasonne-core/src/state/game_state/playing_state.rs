@@ -1,30 +1,85 @@
+pub mod place_meeple_state;
 pub mod place_tile_state;
 pub mod select_tile_state;
 
 use crate::action::Action;
-pub use crate::context::GameContext;
 use crate::input_handler::InputEvent;
+use crate::layout::border_style::BorderStyle;
 use crate::layout::node::Node;
-use crate::model::game::GameTiles;
+use crate::layout::score_board_view::ScoreBoardView;
+use crate::model::game::{Game, GameTiles};
+use crate::model::player::Player;
 use crate::state::game_state::playing_state::PlayingStateResult::Continue;
 use crate::state::game_state::stop_state::StopState;
 use crate::state::StateResult::Skip;
 use crate::state::{State, StateResult};
 
+/// A game being played in the text UI.
+///
+/// The turns are played through `Game::apply`, like any other front end: the
+/// substates only pick the moves of the active player.
 pub struct PlayingPhase {
     pub current_state: Box<dyn PlayingState>,
-    pub context: GameContext,
+    pub game: Game,
+    scores: ScoreBoardView,
 }
 
 impl PlayingPhase {
-    pub fn new(default_state: Box<dyn PlayingState>, tiles: GameTiles) -> Self {
-        Self {
+    pub fn new(
+        default_state: Box<dyn PlayingState>,
+        tiles: GameTiles,
+        players: Vec<Player>,
+    ) -> Self {
+        let mut phase = Self {
             current_state: default_state,
-            context: GameContext {
-                available_tiles: tiles.available_tiles,
-            },
+            game: Game::new(tiles, players),
+            scores: ScoreBoardView::default(),
+        };
+        phase.refresh_scores();
+        phase
+    }
+
+    /// Lays out the scores again, after the game changed.
+    fn refresh_scores(&mut self) {
+        let game = &self.game;
+        self.scores = ScoreBoardView::new(game.score_board(), game.players(), |player| {
+            game.meeples_left(player)
+        });
+    }
+
+    /// Builds the header naming the player whose turn it is.
+    fn draw_header(&self) -> Node<'_> {
+        match self.game.players().get(self.game.active_player()) {
+            Some(player) => Node::HorizontalContainer(vec![
+                Box::new(Node::Text("Turn of ")),
+                Box::new(Node::Text(&player.name)),
+            ]),
+            None => Node::Text("Game Is Running"),
         }
     }
+
+    /// Builds the list of players, marking the one whose turn it is.
+    fn draw_players(&self) -> Node<'_> {
+        Node::VerticalContainer(
+            self.game
+                .players()
+                .iter()
+                .enumerate()
+                .map(|(i, player)| {
+                    let marker = if i == self.game.active_player() {
+                        '>'
+                    } else {
+                        ' '
+                    };
+                    Box::new(Node::HorizontalContainer(vec![
+                        Box::new(Node::Char(marker)),
+                        Box::new(Node::Char(' ')),
+                        Box::new(Node::Text(&player.name)),
+                    ]))
+                })
+                .collect(),
+        )
+    }
 }
 
 pub enum PlayingStateResult {
@@ -33,9 +88,9 @@ pub enum PlayingStateResult {
 }
 
 pub trait PlayingState {
-    fn update_game(&mut self, action: Action, context: &mut GameContext) -> PlayingStateResult;
+    fn update_game(&mut self, action: Action, game: &mut Game) -> PlayingStateResult;
 
-    fn draw(&self) -> Node;
+    fn draw(&self) -> Node<'_>;
     fn handle_input(&self, event: InputEvent) -> Action;
 
     fn need_input(&self) -> bool {
@@ -45,15 +100,16 @@ pub trait PlayingState {
 
 impl State for PlayingPhase {
     fn update(&mut self, action: Action) -> StateResult {
-        if let Continue(new_state) = self.current_state.update_game(action, &mut self.context) {
+        if let Continue(new_state) = self.current_state.update_game(action, &mut self.game) {
             self.current_state = new_state;
+            self.refresh_scores();
             Skip
         } else {
             StateResult::Continue(Box::new(StopState {}))
         }
     }
 
-    fn draw(&self) -> Node {
+    fn draw(&self) -> Node<'_> {
         let draw_result = self.current_state.draw();
 
         if let Node::None = draw_result {
@@ -61,13 +117,23 @@ impl State for PlayingPhase {
         }
 
         Node::VerticalContainer(vec![
-            Box::new(Node::Text("Game Is Running")),
-            Box::new(Node::Framed(Box::new(self.current_state.draw()))),
+            Box::new(self.draw_header()),
             Box::new(Node::HorizontalContainer(vec![
-                Box::new(self.current_state.draw()),
-                Box::new(self.current_state.draw()),
-                Box::new(self.current_state.draw()),
-                Box::new(self.current_state.draw()),
+                Box::new(Node::Framed(
+                    Box::new(Node::Board(self.game.board())),
+                    BorderStyle::Single,
+                )),
+                Box::new(Node::Framed(Box::new(draw_result), BorderStyle::Single)),
+                Box::new(Node::VerticalContainer(vec![
+                    Box::new(Node::Framed(
+                        Box::new(self.draw_players()),
+                        BorderStyle::Single,
+                    )),
+                    Box::new(Node::Framed(
+                        Box::new(self.scores.node()),
+                        BorderStyle::Single,
+                    )),
+                ])),
             ])),
         ])
     }
@@ -78,4 +144,8 @@ impl State for PlayingPhase {
     fn need_input(&self) -> bool {
         self.current_state.need_input()
     }
+
+    fn game(&self) -> Option<&Game> {
+        Some(&self.game)
+    }
 }
@@ -0,0 +1,113 @@
+use crate::action::Action;
+use crate::input_handler::InputEvent;
+use crate::layout::node::Node;
+use crate::model::board::Coord;
+use crate::model::game::Game;
+use crate::model::game::moves::Move;
+use crate::model::meeple::SlotId;
+use crate::model::tile::Tile;
+use crate::state::game_state::playing_state::PlayingStateResult::Continue;
+use crate::state::game_state::playing_state::select_tile_state::SelectTileState;
+use crate::state::game_state::playing_state::{PlayingState, PlayingStateResult};
+
+/// Lets the active player put a meeple on the tile just placed, or keep it.
+///
+/// `Left` and `Right` cycle through the free slots of the tile and the choice of
+/// placing no meeple, which is selected first.
+#[derive(Clone)]
+pub struct PlaceMeepleState {
+    tile: Tile,
+    slots: Vec<SlotId>,
+    selected: Option<usize>,
+    label: String,
+}
+
+impl PlaceMeepleState {
+    /// Creates the state for the tile placed at `position`, offering the slots the
+    /// rules let a meeple go on, unless the active player has no meeple left.
+    pub fn new(position: Coord, game: &Game) -> Self {
+        let placed_tile = game
+            .board()
+            .get(position)
+            .expect("a meeple is placed on a placed tile");
+        let slots = if game.meeples_left(game.active_player()) > 0 {
+            game.legal_meeple_placements(position)
+        } else {
+            Vec::new()
+        };
+        let mut state = Self {
+            tile: placed_tile.tile.clone(),
+            slots,
+            selected: None,
+            label: String::new(),
+        };
+        state.select(None);
+        state
+    }
+
+    /// Selects the slot at `selected` in `slots`, or no meeple with `None`.
+    fn select(&mut self, selected: Option<usize>) {
+        self.selected = selected;
+        let choice = match selected.map(|index| self.slots[index]) {
+            None => "none".to_string(),
+            Some(SlotId::Feature(index)) => format!("{:?}", self.tile.tile_features[index].kind()),
+            Some(SlotId::Extension) => match self.tile.tile_extension {
+                Some(extension) => format!("{extension:?}"),
+                None => "extension".to_string(),
+            },
+        };
+        self.label = format!("Meeple: < {choice} >");
+    }
+
+    /// Moves the selection by `step` among the slots and the choice of no meeple.
+    fn cycle(&mut self, step: isize) {
+        let choices = self.slots.len() as isize + 1;
+        let current = self.selected.map_or(0, |index| index as isize + 1);
+        let next = (current + step).rem_euclid(choices);
+        self.select((next > 0).then(|| next as usize - 1));
+    }
+}
+
+impl PlayingState for PlaceMeepleState {
+    /// Puts a meeple of the active player on the selected slot, or none when the
+    /// player skips, after which the game scores the turn and passes it.
+    fn update_game(&mut self, action: Action, game: &mut Game) -> PlayingStateResult {
+        match action {
+            Action::Left => self.cycle(-1),
+            Action::Right => self.cycle(1),
+            Action::Validate | Action::SkipMeeple => {
+                let slot = match action {
+                    Action::Validate => self.selected.map(|index| self.slots[index]),
+                    _ => None,
+                };
+                let player_move = slot.map_or(Move::SkipMeeple, Move::PlaceMeeple);
+                game.apply(player_move)
+                    .expect("the offered slots are legal");
+                return Continue(Box::new(SelectTileState {}));
+            }
+            _ => {}
+        }
+        Continue(Box::new(self.clone()))
+    }
+
+    fn draw(&self) -> Node<'_> {
+        Node::VerticalContainer(vec![
+            Box::new(Node::Tile(&self.tile)),
+            Box::new(Node::Text(&self.label)),
+        ])
+    }
+
+    fn handle_input(&self, event: InputEvent) -> Action {
+        match event {
+            InputEvent::Left => Action::Left,
+            InputEvent::Right => Action::Right,
+            InputEvent::Enter if self.selected.is_none() => Action::SkipMeeple,
+            InputEvent::Enter => Action::Validate,
+            _ => Action::None,
+        }
+    }
+
+    fn need_input(&self) -> bool {
+        true
+    }
+}
@@ -1,10 +1,12 @@
 use crate::action::Action;
 use crate::input_handler::InputEvent;
 use crate::layout::node::Node;
+use crate::model::game::moves::Move;
+use crate::model::game::Game;
 use crate::model::tile::Tile;
-use crate::state::game_state::playing_state::select_tile_state::SelectTileState;
+use crate::state::game_state::playing_state::place_meeple_state::PlaceMeepleState;
 use crate::state::game_state::playing_state::PlayingStateResult::Continue;
-use crate::state::game_state::playing_state::{GameContext, PlayingState, PlayingStateResult};
+use crate::state::game_state::playing_state::{PlayingState, PlayingStateResult};
 
 pub struct PlaceTileState {
     tile: Tile,
@@ -17,11 +19,22 @@ impl PlaceTileState {
 }
 
 impl PlayingState for PlaceTileState {
-    fn update_game(&mut self, _action: Action, _context: &mut GameContext) -> PlayingStateResult {
-        Continue(Box::new(SelectTileState {}))
+    /// Places the tile, then lets the player put a meeple on it.
+    ///
+    /// Until the player can pick where the tile goes, it is placed at its first
+    /// legal placement. The game only hands out tiles fitting somewhere.
+    fn update_game(&mut self, _action: Action, game: &mut Game) -> PlayingStateResult {
+        let (position, rotation) = game.board().legal_placements(&self.tile)[0];
+        game.apply(Move::PlaceTile {
+            tile: self.tile.id,
+            position,
+            rotation,
+        })
+        .expect("a legal placement should be accepted");
+        Continue(Box::new(PlaceMeepleState::new(position, game)))
     }
 
-    fn draw(&self) -> Node {
+    fn draw(&self) -> Node<'_> {
         Node::Tile(&self.tile)
     }
     fn handle_input(&self, event: InputEvent) -> Action {
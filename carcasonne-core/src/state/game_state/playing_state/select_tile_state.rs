@@ -1,21 +1,27 @@
 use crate::action::Action;
 use crate::input_handler::InputEvent;
 use crate::layout::node::Node;
+use crate::model::game::turn_engine::TurnPhase;
+use crate::model::game::Game;
 use crate::state::game_state::playing_state::place_tile_state::PlaceTileState;
 use crate::state::game_state::playing_state::PlayingStateResult::{Continue, ExitToStop};
-use crate::state::game_state::playing_state::{GameContext, PlayingState, PlayingStateResult};
+use crate::state::game_state::playing_state::{PlayingState, PlayingStateResult};
 
 pub struct SelectTileState {}
 
 impl PlayingState for SelectTileState {
-    fn update_game(&mut self, _action: Action, context: &mut GameContext) -> PlayingStateResult {
-        if let Some(tile) = context.select_random_tile() {
-            Continue(Box::new(PlaceTileState::new(tile)))
-        } else {
-            ExitToStop
+    /// Lets the game draw the next tile, then hands it to the active player, or stops
+    /// once the game is over.
+    fn update_game(&mut self, _action: Action, game: &mut Game) -> PlayingStateResult {
+        game.advance();
+        match (game.phase(), game.drawn_tile()) {
+            (TurnPhase::PlaceTile, Some(tile)) => {
+                Continue(Box::new(PlaceTileState::new(tile.clone())))
+            }
+            _ => ExitToStop,
         }
     }
-    fn draw(&self) -> Node {
+    fn draw(&self) -> Node<'_> {
         Node::None
     }
 
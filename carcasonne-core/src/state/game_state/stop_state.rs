@@ -11,7 +11,7 @@ impl State for StopState {
         ExitToStop
     }
 
-    fn draw(&self) -> Node {
+    fn draw(&self) -> Node<'_> {
         Node::Text("Fin du jeu")
     }
     fn handle_input(&self, _: InputEvent) -> Action {
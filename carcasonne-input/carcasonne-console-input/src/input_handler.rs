@@ -1,28 +1,22 @@
+use crate::keymap::Keymap;
 use carcasonne_core::input_handler::InputEvent;
-use crossterm::event::{read, Event, KeyCode};
+use crossterm::event::{read, Event};
 
 /// Blocks until a valid keyboard input is received and returns a corresponding `InputEvent`.
 ///
-/// This function loops indefinitely until a mapped key is pressed (arrow keys, Enter, or 'q').
+/// This function loops indefinitely until a key bound in `keymap` is pressed.
 /// Other key presses are ignored.
 ///
 /// # Behavior
-/// - Arrow keys map to directional input events.
-/// - Enter maps to `InputEvent::Enter`.
-/// - 'q' maps to `InputEvent::Quit`.
+/// - Keys bound by the keymap's preset map to their `InputEvent`
+///   (arrow keys, Enter and 'q' are bound in every preset).
 /// - All other inputs are ignored.
-/// ```
-pub fn read_input_event() -> InputEvent {
+pub fn read_input_event(keymap: &Keymap) -> InputEvent {
     loop {
         match read() {
-            Ok(Event::Key(key_event)) => match key_event.code {
-                KeyCode::Up => return InputEvent::Up,
-                KeyCode::Down => return InputEvent::Down,
-                KeyCode::Left => return InputEvent::Left,
-                KeyCode::Right => return InputEvent::Right,
-                KeyCode::Enter => return InputEvent::Enter,
-                KeyCode::Char('q') => return InputEvent::Quit,
-                _ => continue,
+            Ok(Event::Key(key_event)) => match keymap.event(key_event.code) {
+                Some(event) => return event,
+                None => continue,
             },
             Ok(_) => continue,
             Err(e) => {
@@ -0,0 +1,215 @@
+use carcasonne_core::input_handler::InputEvent;
+use crossterm::event::KeyCode;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// A predefined set of key bindings.
+///
+/// Every preset keeps the arrow keys, `Enter` and `q` working, and adds its own
+/// navigation keys on top of them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum KeyPreset {
+    /// Arrow keys only.
+    #[default]
+    Arrows,
+    /// Vim-style navigation with `h`, `j`, `k` and `l`.
+    Vim,
+    /// Gamer-style navigation with `w`, `a`, `s` and `d`.
+    Wasd,
+}
+
+impl KeyPreset {
+    /// Returns every available preset.
+    pub fn all() -> [KeyPreset; 3] {
+        [KeyPreset::Arrows, KeyPreset::Vim, KeyPreset::Wasd]
+    }
+
+    /// Returns the human-readable name of the preset.
+    pub fn name(&self) -> &'static str {
+        match self {
+            KeyPreset::Arrows => "arrows",
+            KeyPreset::Vim => "vim",
+            KeyPreset::Wasd => "wasd",
+        }
+    }
+}
+
+impl Display for KeyPreset {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for KeyPreset {
+    type Err = String;
+
+    /// Parses a preset from its name, ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        KeyPreset::all()
+            .into_iter()
+            .find(|preset| preset.name().eq_ignore_ascii_case(s))
+            .ok_or_else(|| format!("Unknown key preset: {s}"))
+    }
+}
+
+/// Maps keyboard keys to `InputEvent`s according to a `KeyPreset`.
+#[derive(Debug, Clone)]
+pub struct Keymap {
+    preset: KeyPreset,
+    bindings: HashMap<KeyCode, InputEvent>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self::new(KeyPreset::default())
+    }
+}
+
+impl Keymap {
+    /// Creates the keymap for the given preset.
+    pub fn new(preset: KeyPreset) -> Self {
+        let mut bindings = HashMap::from([
+            (KeyCode::Up, InputEvent::Up),
+            (KeyCode::Down, InputEvent::Down),
+            (KeyCode::Left, InputEvent::Left),
+            (KeyCode::Right, InputEvent::Right),
+            (KeyCode::Enter, InputEvent::Enter),
+            (KeyCode::Char('q'), InputEvent::Quit),
+        ]);
+        let navigation = match preset {
+            KeyPreset::Arrows => vec![],
+            KeyPreset::Vim => vec![
+                ('k', InputEvent::Up),
+                ('j', InputEvent::Down),
+                ('h', InputEvent::Left),
+                ('l', InputEvent::Right),
+            ],
+            KeyPreset::Wasd => vec![
+                ('w', InputEvent::Up),
+                ('s', InputEvent::Down),
+                ('a', InputEvent::Left),
+                ('d', InputEvent::Right),
+            ],
+        };
+        bindings.extend(
+            navigation
+                .into_iter()
+                .map(|(c, event)| (KeyCode::Char(c), event)),
+        );
+        Self { preset, bindings }
+    }
+
+    /// Returns the preset this keymap was built from.
+    pub fn preset(&self) -> KeyPreset {
+        self.preset
+    }
+
+    /// Returns the event bound to the given key, if any.
+    pub fn event(&self, key: KeyCode) -> Option<InputEvent> {
+        self.bindings.get(&key).copied()
+    }
+
+    /// Returns the keys bound to the given event, sorted by their display label.
+    ///
+    /// Intended for help screens listing the active bindings.
+    pub fn keys_for(&self, event: InputEvent) -> Vec<String> {
+        let mut keys: Vec<String> = self
+            .bindings
+            .iter()
+            .filter(|(_, bound)| **bound == event)
+            .map(|(key, _)| key.to_string())
+            .collect();
+        keys.sort();
+        keys
+    }
+
+    /// Returns a one-line summary of the preset and of the keys bound to each event,
+    /// such as `Keys (vim): up Up/k, down Down/j, ...`.
+    ///
+    /// Intended for the status line shown while playing.
+    pub fn help(&self) -> String {
+        let events = [
+            ("up", InputEvent::Up),
+            ("down", InputEvent::Down),
+            ("left", InputEvent::Left),
+            ("right", InputEvent::Right),
+            ("confirm", InputEvent::Enter),
+            ("quit", InputEvent::Quit),
+        ];
+        let bindings: Vec<String> = events
+            .into_iter()
+            .map(|(name, event)| format!("{name} {}", self.keys_for(event).join("/")))
+            .collect();
+        format!("Keys ({}): {}", self.preset, bindings.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_common_bindings_in_every_preset() {
+        for preset in KeyPreset::all() {
+            let keymap = Keymap::new(preset);
+            assert_eq!(keymap.event(KeyCode::Up), Some(InputEvent::Up));
+            assert_eq!(keymap.event(KeyCode::Enter), Some(InputEvent::Enter));
+            assert_eq!(keymap.event(KeyCode::Char('q')), Some(InputEvent::Quit));
+        }
+    }
+
+    #[test]
+    fn test_vim_preset() {
+        let keymap = Keymap::new(KeyPreset::Vim);
+        assert_eq!(keymap.preset(), KeyPreset::Vim);
+        assert_eq!(keymap.event(KeyCode::Char('h')), Some(InputEvent::Left));
+        assert_eq!(keymap.event(KeyCode::Char('j')), Some(InputEvent::Down));
+        assert_eq!(keymap.event(KeyCode::Char('k')), Some(InputEvent::Up));
+        assert_eq!(keymap.event(KeyCode::Char('l')), Some(InputEvent::Right));
+        assert_eq!(keymap.event(KeyCode::Char('w')), None);
+    }
+
+    #[test]
+    fn test_wasd_preset() {
+        let keymap = Keymap::new(KeyPreset::Wasd);
+        assert_eq!(keymap.event(KeyCode::Char('w')), Some(InputEvent::Up));
+        assert_eq!(keymap.event(KeyCode::Char('a')), Some(InputEvent::Left));
+        assert_eq!(keymap.event(KeyCode::Char('s')), Some(InputEvent::Down));
+        assert_eq!(keymap.event(KeyCode::Char('d')), Some(InputEvent::Right));
+        assert_eq!(keymap.event(KeyCode::Char('h')), None);
+    }
+
+    #[test]
+    fn test_arrows_preset_ignores_letters() {
+        let keymap = Keymap::default();
+        assert_eq!(keymap.preset(), KeyPreset::Arrows);
+        assert_eq!(keymap.event(KeyCode::Char('k')), None);
+        assert_eq!(keymap.event(KeyCode::Char('w')), None);
+    }
+
+    #[test]
+    fn test_keys_for_lists_all_bindings() {
+        let keymap = Keymap::new(KeyPreset::Vim);
+        assert_eq!(keymap.keys_for(InputEvent::Left), vec!["Left", "h"]);
+    }
+
+    #[test]
+    fn test_help_names_the_active_preset() {
+        let help = Keymap::new(KeyPreset::Wasd).help();
+        assert!(help.starts_with("Keys (wasd): up Up/w, down Down/s, left Left/a"));
+        assert!(help.ends_with(", quit q"));
+        assert!(
+            Keymap::default()
+                .help()
+                .starts_with("Keys (arrows): up Up,")
+        );
+    }
+
+    #[test]
+    fn test_parse_preset() {
+        assert_eq!("VIM".parse::<KeyPreset>(), Ok(KeyPreset::Vim));
+        assert_eq!("wasd".parse::<KeyPreset>(), Ok(KeyPreset::Wasd));
+        assert!("emacs".parse::<KeyPreset>().is_err());
+    }
+}
@@ -1 +1,2 @@
 pub mod input_handler;
+pub mod keymap;
@@ -1,39 +1,109 @@
+use carcasonne_core::layout::border_style::BorderStyle;
+
 /// Represents the different characters used to draw box borders.
 ///
-/// These include corners and line segments for horizontal and vertical edges.
+/// These include corners, line segments for horizontal and vertical edges,
+/// T-junctions and crosses. Every piece except `None` carries the `BorderStyle`
+/// it is drawn with.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum CharDrawing {
     /// Represents no character (empty space).
     None,
     /// Top-left corner of a box.
-    CornerTopLeft,
+    CornerTopLeft(BorderStyle),
     /// Top-right corner of a box.
-    CornerTopRight,
+    CornerTopRight(BorderStyle),
     /// Bottom-left corner of a box.
-    CornerBottomLeft,
+    CornerBottomLeft(BorderStyle),
     /// Bottom-right corner of a box.
-    CornerBottomRight,
+    CornerBottomRight(BorderStyle),
     /// Horizontal line segment.
-    Horizontal,
+    Horizontal(BorderStyle),
     /// Vertical line segment.
-    Vertical,
+    Vertical(BorderStyle),
+    /// T-junction opening downwards (horizontal line with a branch below).
+    TeeDown(BorderStyle),
+    /// T-junction opening upwards (horizontal line with a branch above).
+    TeeUp(BorderStyle),
+    /// T-junction opening to the right (vertical line with a branch on the right).
+    TeeRight(BorderStyle),
+    /// T-junction opening to the left (vertical line with a branch on the left).
+    TeeLeft(BorderStyle),
+    /// Crossing of a horizontal and a vertical line.
+    Cross(BorderStyle),
 }
 
 impl From<CharDrawing> for char {
     /// Converts a `CharDrawing` variant into its corresponding Unicode character.
     ///
+    /// Rounded borders only differ from single borders by their corners.
+    ///
     /// # Returns
     ///
     /// * A `char` representing the box drawing element.
     fn from(value: CharDrawing) -> Self {
+        use BorderStyle::{Double, Heavy, Rounded, Single};
         match value {
             CharDrawing::None => ' ',
-            CharDrawing::CornerTopLeft => '┌',
-            CharDrawing::CornerTopRight => '┐',
-            CharDrawing::CornerBottomLeft => '└',
-            CharDrawing::CornerBottomRight => '┘',
-            CharDrawing::Horizontal => '─',
-            CharDrawing::Vertical => '│',
+            CharDrawing::CornerTopLeft(style) => match style {
+                Single => '┌',
+                Double => '╔',
+                Rounded => '╭',
+                Heavy => '┏',
+            },
+            CharDrawing::CornerTopRight(style) => match style {
+                Single => '┐',
+                Double => '╗',
+                Rounded => '╮',
+                Heavy => '┓',
+            },
+            CharDrawing::CornerBottomLeft(style) => match style {
+                Single => '└',
+                Double => '╚',
+                Rounded => '╰',
+                Heavy => '┗',
+            },
+            CharDrawing::CornerBottomRight(style) => match style {
+                Single => '┘',
+                Double => '╝',
+                Rounded => '╯',
+                Heavy => '┛',
+            },
+            CharDrawing::Horizontal(style) => match style {
+                Single | Rounded => '─',
+                Double => '═',
+                Heavy => '━',
+            },
+            CharDrawing::Vertical(style) => match style {
+                Single | Rounded => '│',
+                Double => '║',
+                Heavy => '┃',
+            },
+            CharDrawing::TeeDown(style) => match style {
+                Single | Rounded => '┬',
+                Double => '╦',
+                Heavy => '┳',
+            },
+            CharDrawing::TeeUp(style) => match style {
+                Single | Rounded => '┴',
+                Double => '╩',
+                Heavy => '┻',
+            },
+            CharDrawing::TeeRight(style) => match style {
+                Single | Rounded => '├',
+                Double => '╠',
+                Heavy => '┣',
+            },
+            CharDrawing::TeeLeft(style) => match style {
+                Single | Rounded => '┤',
+                Double => '╣',
+                Heavy => '┫',
+            },
+            CharDrawing::Cross(style) => match style {
+                Single | Rounded => '┼',
+                Double => '╬',
+                Heavy => '╋',
+            },
         }
     }
 }
@@ -41,26 +111,81 @@ impl From<CharDrawing> for char {
 #[cfg(test)]
 mod tests {
     use super::CharDrawing;
+    use carcasonne_core::layout::border_style::BorderStyle::{Double, Heavy, Rounded, Single};
 
-    #[test]
-    fn test_char_drawing_into_char() {
-        let cases = [
-            (CharDrawing::None, ' '),
-            (CharDrawing::CornerTopLeft, '┌'),
-            (CharDrawing::CornerTopRight, '┐'),
-            (CharDrawing::CornerBottomLeft, '└'),
-            (CharDrawing::CornerBottomRight, '┘'),
-            (CharDrawing::Horizontal, '─'),
-            (CharDrawing::Vertical, '│'),
-        ];
-
+    fn assert_conversions(cases: &[(CharDrawing, char)]) {
         for (input, expected) in cases {
-            let c: char = input.into();
+            let c: char = (*input).into();
             assert_eq!(
-                c, expected,
+                c, *expected,
                 "CharDrawing::{:?} should convert to '{}'",
                 input, expected
             );
         }
     }
+
+    #[test]
+    fn test_char_drawing_into_char() {
+        assert_conversions(&[
+            (CharDrawing::None, ' '),
+            (CharDrawing::CornerTopLeft(Single), '┌'),
+            (CharDrawing::CornerTopRight(Single), '┐'),
+            (CharDrawing::CornerBottomLeft(Single), '└'),
+            (CharDrawing::CornerBottomRight(Single), '┘'),
+            (CharDrawing::Horizontal(Single), '─'),
+            (CharDrawing::Vertical(Single), '│'),
+            (CharDrawing::TeeDown(Single), '┬'),
+            (CharDrawing::TeeUp(Single), '┴'),
+            (CharDrawing::TeeRight(Single), '├'),
+            (CharDrawing::TeeLeft(Single), '┤'),
+            (CharDrawing::Cross(Single), '┼'),
+        ]);
+    }
+
+    #[test]
+    fn test_double_char_drawing_into_char() {
+        assert_conversions(&[
+            (CharDrawing::CornerTopLeft(Double), '╔'),
+            (CharDrawing::CornerTopRight(Double), '╗'),
+            (CharDrawing::CornerBottomLeft(Double), '╚'),
+            (CharDrawing::CornerBottomRight(Double), '╝'),
+            (CharDrawing::Horizontal(Double), '═'),
+            (CharDrawing::Vertical(Double), '║'),
+            (CharDrawing::TeeDown(Double), '╦'),
+            (CharDrawing::TeeUp(Double), '╩'),
+            (CharDrawing::TeeRight(Double), '╠'),
+            (CharDrawing::TeeLeft(Double), '╣'),
+            (CharDrawing::Cross(Double), '╬'),
+        ]);
+    }
+
+    #[test]
+    fn test_rounded_char_drawing_into_char() {
+        assert_conversions(&[
+            (CharDrawing::CornerTopLeft(Rounded), '╭'),
+            (CharDrawing::CornerTopRight(Rounded), '╮'),
+            (CharDrawing::CornerBottomLeft(Rounded), '╰'),
+            (CharDrawing::CornerBottomRight(Rounded), '╯'),
+            (CharDrawing::Horizontal(Rounded), '─'),
+            (CharDrawing::Vertical(Rounded), '│'),
+            (CharDrawing::Cross(Rounded), '┼'),
+        ]);
+    }
+
+    #[test]
+    fn test_heavy_char_drawing_into_char() {
+        assert_conversions(&[
+            (CharDrawing::CornerTopLeft(Heavy), '┏'),
+            (CharDrawing::CornerTopRight(Heavy), '┓'),
+            (CharDrawing::CornerBottomLeft(Heavy), '┗'),
+            (CharDrawing::CornerBottomRight(Heavy), '┛'),
+            (CharDrawing::Horizontal(Heavy), '━'),
+            (CharDrawing::Vertical(Heavy), '┃'),
+            (CharDrawing::TeeDown(Heavy), '┳'),
+            (CharDrawing::TeeUp(Heavy), '┻'),
+            (CharDrawing::TeeRight(Heavy), '┣'),
+            (CharDrawing::TeeLeft(Heavy), '┫'),
+            (CharDrawing::Cross(Heavy), '╋'),
+        ]);
+    }
 }
@@ -0,0 +1,109 @@
+use crate::char_drawing::CharDrawing;
+use carcasonne_core::layout::border_style::BorderStyle;
+use std::collections::HashMap;
+
+/// A mapping from `CharDrawing` pieces to the characters actually printed.
+///
+/// Pieces without an explicit mapping fall back to their Unicode box-drawing
+/// character, so a charset only needs to list the pieces it changes.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Charset {
+    overrides: HashMap<CharDrawing, char>,
+}
+
+impl Charset {
+    /// Returns the Unicode charset, printing every piece as its box-drawing character.
+    pub fn unicode() -> Self {
+        Self::default()
+    }
+
+    /// Returns a pure-ASCII charset using `+` for corners and junctions, `-` and `|` for lines.
+    ///
+    /// Useful on terminals or fonts without Unicode box-drawing characters.
+    pub fn ascii() -> Self {
+        let styles = [
+            BorderStyle::Single,
+            BorderStyle::Double,
+            BorderStyle::Rounded,
+            BorderStyle::Heavy,
+        ];
+        let mut charset = Self::default();
+        for style in styles {
+            charset = charset
+                .with(CharDrawing::CornerTopLeft(style), '+')
+                .with(CharDrawing::CornerTopRight(style), '+')
+                .with(CharDrawing::CornerBottomLeft(style), '+')
+                .with(CharDrawing::CornerBottomRight(style), '+')
+                .with(CharDrawing::Horizontal(style), '-')
+                .with(CharDrawing::Vertical(style), '|')
+                .with(CharDrawing::TeeDown(style), '+')
+                .with(CharDrawing::TeeUp(style), '+')
+                .with(CharDrawing::TeeRight(style), '+')
+                .with(CharDrawing::TeeLeft(style), '+')
+                .with(CharDrawing::Cross(style), '+');
+        }
+        charset
+    }
+
+    /// Maps `drawing` to `c`, replacing any previous mapping for that piece.
+    pub fn with(mut self, drawing: CharDrawing, c: char) -> Self {
+        self.overrides.insert(drawing, c);
+        self
+    }
+
+    /// Returns the character printed for the given piece.
+    pub fn char(&self, drawing: CharDrawing) -> char {
+        self.overrides
+            .get(&drawing)
+            .copied()
+            .unwrap_or_else(|| drawing.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unicode_charset_uses_box_drawing() {
+        let charset = Charset::unicode();
+        assert_eq!(
+            charset.char(CharDrawing::CornerTopLeft(BorderStyle::Single)),
+            '┌'
+        );
+        assert_eq!(
+            charset.char(CharDrawing::Horizontal(BorderStyle::Double)),
+            '═'
+        );
+        assert_eq!(charset.char(CharDrawing::None), ' ');
+    }
+
+    #[test]
+    fn test_ascii_charset() {
+        let charset = Charset::ascii();
+        assert_eq!(
+            charset.char(CharDrawing::CornerTopLeft(BorderStyle::Rounded)),
+            '+'
+        );
+        assert_eq!(
+            charset.char(CharDrawing::Horizontal(BorderStyle::Heavy)),
+            '-'
+        );
+        assert_eq!(
+            charset.char(CharDrawing::Vertical(BorderStyle::Double)),
+            '|'
+        );
+        assert_eq!(charset.char(CharDrawing::Cross(BorderStyle::Single)), '+');
+        assert_eq!(charset.char(CharDrawing::None), ' ');
+    }
+
+    #[test]
+    fn test_custom_override_falls_back_for_other_pieces() {
+        let charset = Charset::unicode().with(CharDrawing::Vertical(BorderStyle::Single), '!');
+        assert_eq!(
+            charset.char(CharDrawing::Vertical(BorderStyle::Single)),
+            '!'
+        );
+        assert_eq!(charset.char(CharDrawing::Vertical(BorderStyle::Heavy)), '┃');
+    }
+}
@@ -9,6 +9,14 @@ pub enum Color {
     Red,
     /// Blue color.
     Blue,
+    /// Green color.
+    Green,
+    /// Yellow color.
+    Yellow,
+    /// Magenta color.
+    Magenta,
+    /// Grey color.
+    Grey,
 }
 
 impl From<&Color> for crossterm::style::Color {
@@ -37,6 +45,10 @@ impl From<&Color> for crossterm::style::Color {
             Color::White => Self::White,
             Color::Red => Self::Red,
             Color::Blue => Self::Blue,
+            Color::Green => Self::Green,
+            Color::Yellow => Self::Yellow,
+            Color::Magenta => Self::Magenta,
+            Color::Grey => Self::Grey,
         }
     }
 }
@@ -62,6 +74,10 @@ mod tests {
         Black => test_black,
         White => test_white,
         Red => test_red,
-        Blue => test_blue
+        Blue => test_blue,
+        Green => test_green,
+        Yellow => test_yellow,
+        Magenta => test_magenta,
+        Grey => test_grey
     }
 }
@@ -0,0 +1,376 @@
+use crate::color::Color;
+use carcasonne_core::model::tile_extension::TileExtension;
+use carcasonne_core::model::tile_feature::{FeatureKind, TileFeature};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// The kind of terrain drawn on a tile, used as a key to pick a color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Terrain {
+    /// Any part of the tile not covered by another feature.
+    Field,
+    /// A town feature.
+    Town,
+    /// A road feature.
+    Road,
+    /// An abbey or monastery extension.
+    Abbey,
+    /// A garden extension.
+    Garden,
+}
+
+impl Terrain {
+    /// Returns the terrain matching the kind of the given feature.
+    pub fn of_feature(feature: &TileFeature) -> Option<Terrain> {
+        match feature.kind() {
+            FeatureKind::Town => Some(Terrain::Town),
+            FeatureKind::Road => Some(Terrain::Road),
+            FeatureKind::Field => Some(Terrain::Field),
+        }
+    }
+
+    /// Returns the terrain depicted by a character of `Tile::to_ascii`.
+    ///
+    /// Unknown characters are considered part of the field.
+    pub fn of_symbol(symbol: char) -> Terrain {
+        match symbol {
+            '#' | '*' => Terrain::Town,
+            '|' | '-' | '+' => Terrain::Road,
+            'A' => Terrain::Abbey,
+            'G' => Terrain::Garden,
+            _ => Terrain::Field,
+        }
+    }
+
+    /// Returns the terrain matching the given extension.
+    pub fn of_extension(extension: TileExtension) -> Option<Terrain> {
+        match extension {
+            TileExtension::Abbey | TileExtension::Monastery(_) => Some(Terrain::Abbey),
+            TileExtension::Garden => Some(Terrain::Garden),
+        }
+    }
+}
+
+/// Maps terrain kinds and player identities to the colors used when rendering, and
+/// players to the symbols their meeples are drawn with.
+///
+/// The default mapping follows the board game look (green fields, red-brown towns,
+/// yellow roads), every meeple being drawn with `@` in the color of its owner. Each
+/// entry can be overridden to build alternative themes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ColorMap {
+    field: Color,
+    town: Color,
+    road: Color,
+    abbey: Color,
+    garden: Color,
+    players: Vec<Color>,
+    player_symbols: Vec<char>,
+}
+
+impl Default for ColorMap {
+    fn default() -> Self {
+        Self {
+            field: Color::Green,
+            town: Color::Red,
+            road: Color::Yellow,
+            abbey: Color::White,
+            garden: Color::Green,
+            players: vec![
+                Color::Red,
+                Color::Blue,
+                Color::Green,
+                Color::Yellow,
+                Color::Magenta,
+            ],
+            player_symbols: vec!['@'],
+        }
+    }
+}
+
+impl ColorMap {
+    /// Returns a theme maximizing the contrast between terrains on a dark background.
+    pub fn high_contrast() -> Self {
+        Self {
+            field: Color::Grey,
+            town: Color::White,
+            road: Color::Yellow,
+            abbey: Color::Magenta,
+            garden: Color::Blue,
+            players: vec![
+                Color::White,
+                Color::Yellow,
+                Color::Magenta,
+                Color::Blue,
+                Color::Red,
+            ],
+            player_symbols: vec!['@'],
+        }
+    }
+
+    /// Returns a single-color theme, where terrains are told apart by their symbols only,
+    /// and the meeples of each player are drawn with a symbol of their own.
+    pub fn monochrome() -> Self {
+        Self {
+            field: Color::White,
+            town: Color::White,
+            road: Color::White,
+            abbey: Color::White,
+            garden: Color::White,
+            players: vec![Color::White],
+            player_symbols: vec!['@', '&', '$', '%', '='],
+        }
+    }
+
+    /// Overrides the color used for the given terrain.
+    pub fn with_terrain_color(mut self, terrain: Terrain, color: Color) -> Self {
+        match terrain {
+            Terrain::Field => self.field = color,
+            Terrain::Town => self.town = color,
+            Terrain::Road => self.road = color,
+            Terrain::Abbey => self.abbey = color,
+            Terrain::Garden => self.garden = color,
+        }
+        self
+    }
+
+    /// Overrides the colors assigned to players, in turn order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `colors` is empty.
+    pub fn with_player_colors(mut self, colors: Vec<Color>) -> Self {
+        assert!(!colors.is_empty(), "At least one player color is required");
+        self.players = colors;
+        self
+    }
+
+    /// Overrides the symbols the meeples of players are drawn with, in turn order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `symbols` is empty.
+    pub fn with_player_symbols(mut self, symbols: Vec<char>) -> Self {
+        assert!(
+            !symbols.is_empty(),
+            "At least one player symbol is required"
+        );
+        self.player_symbols = symbols;
+        self
+    }
+
+    /// Returns the color used for the given terrain.
+    pub fn terrain_color(&self, terrain: Terrain) -> Color {
+        match terrain {
+            Terrain::Field => self.field.clone(),
+            Terrain::Town => self.town.clone(),
+            Terrain::Road => self.road.clone(),
+            Terrain::Abbey => self.abbey.clone(),
+            Terrain::Garden => self.garden.clone(),
+        }
+    }
+
+    /// Returns the color of the player at `index` in turn order.
+    ///
+    /// Indices beyond the configured colors wrap around.
+    pub fn player_color(&self, index: usize) -> Color {
+        self.players[index % self.players.len()].clone()
+    }
+
+    /// Returns the symbol the meeples of the player at `index` in turn order are
+    /// drawn with.
+    ///
+    /// Indices beyond the configured symbols wrap around.
+    pub fn player_symbol(&self, index: usize) -> char {
+        self.player_symbols[index % self.player_symbols.len()]
+    }
+}
+
+/// A predefined display theme, selectable for accessibility.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum DisplayMode {
+    /// The board game colors.
+    #[default]
+    Standard,
+    /// Bright, well separated colors for low-vision players.
+    HighContrast,
+    /// No color at all: terrains and players are only distinguished by their symbols.
+    Symbols,
+}
+
+impl DisplayMode {
+    /// Returns every available display mode.
+    pub fn all() -> [DisplayMode; 3] {
+        [
+            DisplayMode::Standard,
+            DisplayMode::HighContrast,
+            DisplayMode::Symbols,
+        ]
+    }
+
+    /// Returns the human-readable name of the display mode.
+    pub fn name(&self) -> &'static str {
+        match self {
+            DisplayMode::Standard => "standard",
+            DisplayMode::HighContrast => "high-contrast",
+            DisplayMode::Symbols => "symbols",
+        }
+    }
+
+    /// Returns the color map used by this display mode.
+    pub fn color_map(&self) -> ColorMap {
+        match self {
+            DisplayMode::Standard => ColorMap::default(),
+            DisplayMode::HighContrast => ColorMap::high_contrast(),
+            DisplayMode::Symbols => ColorMap::monochrome(),
+        }
+    }
+}
+
+impl Display for DisplayMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for DisplayMode {
+    type Err = String;
+
+    /// Parses a display mode from its name, ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        DisplayMode::all()
+            .into_iter()
+            .find(|mode| mode.name().eq_ignore_ascii_case(s))
+            .ok_or_else(|| format!("Unknown display mode: {s}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use carcasonne_core::factory::game_factory::GameTilesFactory;
+    use carcasonne_core::model::player::MAX_PLAYERS;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_default_terrain_colors() {
+        let map = ColorMap::default();
+        assert_eq!(map.terrain_color(Terrain::Field), Color::Green);
+        assert_eq!(map.terrain_color(Terrain::Town), Color::Red);
+        assert_eq!(map.terrain_color(Terrain::Road), Color::Yellow);
+        assert_eq!(map.terrain_color(Terrain::Abbey), Color::White);
+    }
+
+    #[test]
+    fn test_override_terrain_color() {
+        let map = ColorMap::default().with_terrain_color(Terrain::Road, Color::Grey);
+        assert_eq!(map.terrain_color(Terrain::Road), Color::Grey);
+        assert_eq!(map.terrain_color(Terrain::Town), Color::Red);
+    }
+
+    #[test]
+    fn test_player_color_wraps_around() {
+        let map = ColorMap::default().with_player_colors(vec![Color::Red, Color::Blue]);
+        assert_eq!(map.player_color(0), Color::Red);
+        assert_eq!(map.player_color(1), Color::Blue);
+        assert_eq!(map.player_color(2), Color::Red);
+    }
+
+    #[test]
+    #[should_panic(expected = "At least one player color is required")]
+    fn test_empty_player_colors_panics() {
+        ColorMap::default().with_player_colors(vec![]);
+    }
+
+    #[test]
+    fn test_player_symbol_wraps_around() {
+        let map = ColorMap::default().with_player_symbols(vec!['x', 'o']);
+        assert_eq!(map.player_symbol(0), 'x');
+        assert_eq!(map.player_symbol(1), 'o');
+        assert_eq!(map.player_symbol(2), 'x');
+        assert_eq!(ColorMap::default().player_symbol(3), '@');
+    }
+
+    #[test]
+    #[should_panic(expected = "At least one player symbol is required")]
+    fn test_empty_player_symbols_panics() {
+        ColorMap::default().with_player_symbols(vec![]);
+    }
+
+    #[test]
+    fn test_terrain_of_base_game_features() {
+        let tiles = GameTilesFactory::build_base_game().available_tiles;
+        for tile in &tiles {
+            for feature in &tile.tile_features {
+                assert!(Terrain::of_feature(feature).is_some());
+            }
+            if let Some(extension) = tile.tile_extension {
+                assert_eq!(Terrain::of_extension(extension), Some(Terrain::Abbey));
+            }
+        }
+    }
+
+    #[test]
+    fn test_terrain_of_symbol() {
+        assert_eq!(Terrain::of_symbol('*'), Terrain::Town);
+        assert_eq!(Terrain::of_symbol('+'), Terrain::Road);
+        assert_eq!(Terrain::of_symbol('G'), Terrain::Garden);
+        assert_eq!(Terrain::of_symbol('.'), Terrain::Field);
+    }
+
+    #[test]
+    fn test_high_contrast_terrains_are_distinct() {
+        let map = ColorMap::high_contrast();
+        let colors: HashSet<Color> = [
+            Terrain::Field,
+            Terrain::Town,
+            Terrain::Road,
+            Terrain::Abbey,
+            Terrain::Garden,
+        ]
+        .into_iter()
+        .map(|terrain| map.terrain_color(terrain))
+        .collect();
+        assert_eq!(colors.len(), 5);
+        assert!(!colors.contains(&Color::Black));
+    }
+
+    #[test]
+    fn test_monochrome_uses_a_single_color() {
+        let map = ColorMap::monochrome();
+        assert_eq!(map.terrain_color(Terrain::Town), Color::White);
+        assert_eq!(map.terrain_color(Terrain::Road), Color::White);
+        assert_eq!(map.player_color(3), Color::White);
+    }
+
+    #[test]
+    fn test_monochrome_players_have_distinct_symbols() {
+        let map = ColorMap::monochrome();
+        let symbols: HashSet<char> = (0..MAX_PLAYERS).map(|i| map.player_symbol(i)).collect();
+        assert_eq!(symbols.len(), MAX_PLAYERS);
+        for symbol in symbols {
+            assert_eq!(Terrain::of_symbol(symbol), Terrain::Field);
+            assert!(!symbol.is_ascii_alphanumeric());
+        }
+    }
+
+    #[test]
+    fn test_display_mode_color_map() {
+        assert_eq!(DisplayMode::default().color_map(), ColorMap::default());
+        assert_eq!(
+            DisplayMode::HighContrast.color_map(),
+            ColorMap::high_contrast()
+        );
+        assert_eq!(DisplayMode::Symbols.color_map(), ColorMap::monochrome());
+    }
+
+    #[test]
+    fn test_parse_display_mode() {
+        assert_eq!(
+            "High-Contrast".parse::<DisplayMode>(),
+            Ok(DisplayMode::HighContrast)
+        );
+        assert_eq!("symbols".parse::<DisplayMode>(), Ok(DisplayMode::Symbols));
+        assert!("sepia".parse::<DisplayMode>().is_err());
+    }
+}
@@ -1,5 +1,7 @@
 use crate::char_drawing::CharDrawing;
+use crate::charset::Charset;
 use crate::color::Color;
+use crate::color_map::ColorMap;
 use crate::renderable::Renderable;
 use carcasonne_core::layout::node::Node;
 use carcasonne_core::layout::point::Point;
@@ -29,6 +31,10 @@ pub struct Frame {
     pub size: Size,
     /// A 2D vector of cells, indexed as `cells[y][x]`.
     pub cells: Vec<Vec<Cell>>,
+    /// The colors used to draw terrains and players into this frame.
+    pub color_map: ColorMap,
+    /// The characters used to draw box-drawing pieces into this frame.
+    pub charset: Charset,
 }
 
 impl Frame {
@@ -44,6 +50,16 @@ impl Frame {
     ///
     /// A new `Frame` instance with all cells initialized.
     pub fn new(size: Size) -> Self {
+        Self::with_color_map(size, ColorMap::default())
+    }
+
+    /// Creates a new `Frame` with the specified size, drawing with the given color map.
+    ///
+    /// # Parameters
+    ///
+    /// * `size` - The size of the frame as a `Size` struct.
+    /// * `color_map` - The colors used to render terrains and players.
+    pub fn with_color_map(size: Size, color_map: ColorMap) -> Self {
         Self {
             size,
             cells: vec![
@@ -57,9 +73,32 @@ impl Frame {
                 ];
                 size.height
             ],
+            color_map,
+            charset: Charset::default(),
         }
     }
 
+    /// Sets the charset used to draw box-drawing pieces, consuming and returning the frame.
+    ///
+    /// # Parameters
+    ///
+    /// * `charset` - The mapping from `CharDrawing` pieces to printed characters.
+    pub fn with_charset(mut self, charset: Charset) -> Self {
+        self.charset = charset;
+        self
+    }
+
+    /// Draws a box-drawing piece at the given position, using the frame's charset.
+    ///
+    /// # Parameters
+    ///
+    /// * `point` - The position where the piece will be drawn.
+    /// * `drawing` - The piece to draw.
+    pub fn drawing(&mut self, point: Point, drawing: CharDrawing) {
+        let c = self.charset.char(drawing);
+        self.char_simple(point, c);
+    }
+
     /// Sets a specific `Cell` at the given position in the frame.
     ///
     /// # Panics
@@ -137,6 +176,7 @@ impl From<Node<'_>> for Frame {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use carcasonne_core::layout::border_style::BorderStyle;
     use carcasonne_core::layout::node::Node;
     use carcasonne_core::layout::point::Point;
 
@@ -197,6 +237,17 @@ mod tests {
         frame.char_simple(out_of_bounds_point, 'Z');
     }
 
+    #[test]
+    fn frame_drawing_uses_charset() {
+        let mut frame = Frame::new(Size::new(2, 1)).with_charset(Charset::ascii());
+        frame.drawing(Point::new(0, 0), CharDrawing::Cross(BorderStyle::Single));
+        assert_eq!(frame.cells[0][0].symbol, '+');
+
+        let mut frame = Frame::new(Size::new(2, 1));
+        frame.drawing(Point::new(0, 0), CharDrawing::Cross(BorderStyle::Single));
+        assert_eq!(frame.cells[0][0].symbol, '┼');
+    }
+
     #[test]
     fn from_node_creates_frame_and_renders() {
         let node = Node::Char('Q');
@@ -1,5 +1,7 @@
-mod char_drawing;
+pub mod char_drawing;
+pub mod charset;
 pub mod color;
+pub mod color_map;
 pub mod frame;
 mod renderable;
 pub mod renderer;
@@ -9,25 +9,33 @@
 //! # Layout Model
 //! - `Char`: 1x1 symbol
 //! - `Text`: horizontal 1-row string
-//! - `Tile`: square of size `TILE_SIZE` (e.g., 5x5)
-//! - `Framed`: wraps any node in a border with padding
+//! - `Tile`: square of size `TILE_SIZE` (e.g., 5x5), colored through the frame's `ColorMap`
+//! - `Board`: grid of tiles as placed and rotated on the board, with blank cells for gaps
+//!   and the meeples in the symbols and colors of their owners
+//! - `Framed`: wraps any node in a border with padding, drawn in the node's `BorderStyle`
 //! - `VerticalContainer`: stacked child nodes
 //! - `HorizontalContainer`: inline child nodes
 //!
-//! Borders use `CharDrawing` characters for visual clarity.
+//! Borders use `CharDrawing` pieces, printed through the frame's `Charset`.
 use crate::char_drawing::CharDrawing;
+use crate::color::Color;
+use crate::color_map::Terrain;
 use crate::frame::Frame;
 use crate::renderable::Renderable;
+use carcasonne_core::layout::border_style::BorderStyle;
 use carcasonne_core::layout::node::Node;
 use carcasonne_core::layout::point::Point;
 use carcasonne_core::layout::size::Size;
-use carcasonne_core::model::tile::Tile;
+use carcasonne_core::model::board::Board;
+use carcasonne_core::model::meeple::SlotId;
+use carcasonne_core::model::tile::{TILE_ASCII_SIZE, Tile};
+use carcasonne_core::scoring::scorer::AbbeyScorer;
 
 /// The default width and height (in characters) used to render a `Tile` node.
 ///
 /// This constant determines the grid size for all tiles. Currently fixed to `5`,
-/// rendering tiles as 5x5 character matrices.
-pub const TILE_SIZE: usize = 5;
+/// rendering tiles as 5x5 character matrices, as drawn by `Tile::to_ascii`.
+pub const TILE_SIZE: usize = TILE_ASCII_SIZE;
 
 /// Stateless helper for rendering `Node` elements into a `Frame`.
 ///
@@ -61,26 +69,75 @@ impl NodeRenderer {
             .for_each(|(i, c)| frame.char_simple(point + Point::new(i, 0), c));
     }
 
-    /// Renders a tile using a square grid of placeholder characters.
+    /// Renders a tile as a square grid of characters colored by terrain.
     ///
-    /// This is a stub implementation: the tile is filled with `.` characters
-    /// and does not yet reflect actual tile features.
+    /// The characters come from `Tile::to_ascii`; each one is colored with the
+    /// frame's color for the terrain it depicts.
     ///
     /// # Arguments
     /// * `frame` - The drawing buffer.
     /// * `point` - The top-left corner where the tile will be drawn.
     /// * `tile` - The tile to render
-    fn render_tile(frame: &mut Frame, point: Point, _: &Tile) {
-        let chars = vec![vec!['.'; TILE_SIZE]; TILE_SIZE];
+    fn render_tile(frame: &mut Frame, point: Point, tile: &Tile) {
+        NodeRenderer::render_ascii(frame, point, &tile.to_ascii());
+    }
 
-        for (i, row) in chars.iter().enumerate() {
-            for (j, c) in row.iter().enumerate() {
-                frame.char_simple(point + Point::new(i, j), *c)
+    /// Renders the characters of a tile, each colored by the terrain it depicts.
+    fn render_ascii(frame: &mut Frame, point: Point, ascii: &[[char; TILE_SIZE]; TILE_SIZE]) {
+        for (y, row) in ascii.iter().enumerate() {
+            for (x, c) in row.iter().enumerate() {
+                let color = frame.color_map.terrain_color(Terrain::of_symbol(*c));
+                frame.char(point + Point::new(x, y), *c, color, Color::Black);
             }
         }
     }
 
-    /// Renders a framed box around a child node, using `+`, `-`, and `|` characters.
+    /// Renders the placed tiles of a board, each in its cell of the grid.
+    ///
+    /// The north-west corner of the board bounds is drawn at `point`; tiles are
+    /// drawn with their rotation applied, and empty cells are left untouched. Meeples
+    /// are drawn on their slot, see `Tile::slot_cell`, with the symbol and in the color
+    /// of their owner.
+    /// Next to an abbey where a meeple stands is drawn the number of tiles among the
+    /// abbey and the eight around it.
+    ///
+    /// # Arguments
+    /// * `frame` - The drawing buffer.
+    /// * `point` - The top-left corner where the board will be drawn.
+    /// * `board` - The board to render.
+    fn render_board(frame: &mut Frame, point: Point, board: &Board) {
+        let Some(bounds) = board.bounds() else {
+            return;
+        };
+        for (position, placed_tile) in board.iter() {
+            let Some((column, row)) = bounds.cell_of(position) else {
+                continue;
+            };
+            let cell = Point::new(column * TILE_SIZE, row * TILE_SIZE);
+            let tile = placed_tile.oriented_tile();
+            NodeRenderer::render_ascii(frame, point + cell, &tile.to_ascii());
+            let meeple = placed_tile
+                .meeple
+                .and_then(|meeple| Some((meeple.player, tile.slot_cell(meeple.slot)?)));
+            if let Some((player, (x, y))) = meeple {
+                let symbol = frame.color_map.player_symbol(player);
+                let color = frame.color_map.player_color(player);
+                frame.char(point + cell + Point::new(x, y), symbol, color, Color::Black);
+            }
+            let progress = placed_tile
+                .meeple
+                .filter(|meeple| meeple.slot == SlotId::Extension)
+                .and_then(|_| AbbeyScorer::progress(board, position));
+            if let Some(digit) =
+                progress.and_then(|progress| char::from_digit(progress.tiles as u32, 10))
+            {
+                let mid = TILE_SIZE / 2;
+                frame.char_simple(point + cell + Point::new(mid + 1, mid), digit);
+            }
+        }
+    }
+
+    /// Renders a framed box around a child node, using box-drawing characters of the given style.
     ///
     /// Adds 1-character padding around the inner node.
     ///
@@ -88,7 +145,8 @@ impl NodeRenderer {
     /// * `frame` - The drawing buffer.
     /// * `point` - The top-left position of the outer frame.
     /// * `elem` - The inner node to render inside the frame.
-    fn render_framed(frame: &mut Frame, point: Point, elem: &Node) {
+    /// * `style` - The line style of the border.
+    fn render_framed(frame: &mut Frame, point: Point, elem: &Node, style: BorderStyle) {
         let inner_size = elem.size();
         let outer_size = inner_size + Size::new(2, 2);
 
@@ -98,24 +156,24 @@ impl NodeRenderer {
         let y1 = y0 + outer_size.height - 1;
 
         // Top border
-        frame.char_simple(Point::new(x0, y0), CharDrawing::CornerTopLeft.into());
+        frame.drawing(Point::new(x0, y0), CharDrawing::CornerTopLeft(style));
         for x in (x0 + 1)..x1 {
-            frame.char_simple(Point::new(x, y0), CharDrawing::Horizontal.into());
+            frame.drawing(Point::new(x, y0), CharDrawing::Horizontal(style));
         }
-        frame.char_simple(Point::new(x1, y0), CharDrawing::CornerTopRight.into());
+        frame.drawing(Point::new(x1, y0), CharDrawing::CornerTopRight(style));
 
         // Middle rows
         for y in (y0 + 1)..y1 {
-            frame.char_simple(Point::new(x0, y), CharDrawing::Vertical.into());
-            frame.char_simple(Point::new(x1, y), CharDrawing::Vertical.into());
+            frame.drawing(Point::new(x0, y), CharDrawing::Vertical(style));
+            frame.drawing(Point::new(x1, y), CharDrawing::Vertical(style));
         }
 
         // Bottom border
-        frame.char_simple(Point::new(x0, y1), CharDrawing::CornerBottomLeft.into());
+        frame.drawing(Point::new(x0, y1), CharDrawing::CornerBottomLeft(style));
         for x in (x0 + 1)..x1 {
-            frame.char_simple(Point::new(x, y1), CharDrawing::Horizontal.into());
+            frame.drawing(Point::new(x, y1), CharDrawing::Horizontal(style));
         }
-        frame.char_simple(Point::new(x1, y1), CharDrawing::CornerBottomRight.into());
+        frame.drawing(Point::new(x1, y1), CharDrawing::CornerBottomRight(style));
 
         // Render the inner element inside the border
         elem.render(frame, point + Point::new(1, 1));
@@ -165,11 +223,12 @@ impl<'a> Renderable for Node<'a> {
             Node::Char(char) => NodeRenderer::render_char(frame, point, char),
             Node::Text(str) => NodeRenderer::render_text(frame, point, str),
             Node::Tile(tile) => NodeRenderer::render_tile(frame, point, tile),
+            Node::Board(board) => NodeRenderer::render_board(frame, point, board),
             Node::VerticalContainer(elems) => NodeRenderer::vertical_container(frame, point, elems),
             Node::HorizontalContainer(elems) => {
                 NodeRenderer::horizontal_container(frame, point, elems)
             }
-            Node::Framed(elem) => NodeRenderer::render_framed(frame, point, elem),
+            Node::Framed(elem, style) => NodeRenderer::render_framed(frame, point, elem, *style),
         }
     }
 
@@ -183,6 +242,9 @@ impl<'a> Renderable for Node<'a> {
             Node::Char(_) => Size::new(1, 1),
             Node::Text(str) => Size::new(str.len(), 1),
             Node::Tile(_) => Size::new(TILE_SIZE, TILE_SIZE),
+            Node::Board(board) => board.bounds().map_or(Size::new(0, 0), |bounds| {
+                Size::new(bounds.width() * TILE_SIZE, bounds.height() * TILE_SIZE)
+            }),
             Node::VerticalContainer(elems) => elems
                 .iter()
                 .map(|e| e.size())
@@ -196,7 +258,7 @@ impl<'a> Renderable for Node<'a> {
                 .fold(Size::new(0, 0), |acc, s| {
                     Size::new(acc.width + s.width, acc.height.max(s.height))
                 }),
-            Node::Framed(elem) => elem.size() + Size::new(2, 2),
+            Node::Framed(elem, _) => elem.size() + Size::new(2, 2),
         }
     }
 }
@@ -204,9 +266,18 @@ impl<'a> Renderable for Node<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::charset::Charset;
+    use crate::color_map::ColorMap;
+    use carcasonne_core::builder::tile_builder::TileBuilder;
     use carcasonne_core::layout::point::Point;
     use carcasonne_core::layout::size::Size;
-    use carcasonne_core::model::tile::Tile;
+    use carcasonne_core::model::board::{Coord, PlacedTile};
+    use carcasonne_core::model::meeple::Meeple;
+    use carcasonne_core::model::tile::{Rotation, Tile, TileId};
+    use carcasonne_core::model::tile_extension::TileExtension;
+    use carcasonne_core::model::tile_feature::{
+        Edge, EdgeSet, FeatureKind, Road, SegmentSet, TileFeature, TileFeatureEnhancement, Town,
+    };
 
     // Helper Node constructors for tests
     fn char_node(c: char) -> Node<'static> {
@@ -216,8 +287,10 @@ mod tests {
         Node::Text(s)
     }
     static TILE_INSTANCE: Tile = Tile {
+        id: TileId('Z'),
         tile_features: Vec::new(),
         tile_extension: None,
+        meta: None,
     };
     fn tile_node() -> Node<'static> {
         Node::Tile(&TILE_INSTANCE)
@@ -275,7 +348,7 @@ mod tests {
     #[test]
     fn test_size_framed() {
         let inner = text_node("Hi");
-        let framed = Node::Framed(Box::new(inner));
+        let framed = Node::Framed(Box::new(inner), BorderStyle::Single);
         // inner size = (2,1) + (2,2) padding = (4,3)
         assert_eq!(framed.size(), Size::new(4, 3));
     }
@@ -323,23 +396,247 @@ mod tests {
     fn test_render_framed() {
         let mut frame = Frame::new(Size::new(10, 5));
         let inner = text_node("Hi");
-        let framed = Node::Framed(Box::new(inner));
+        let framed = Node::Framed(Box::new(inner), BorderStyle::Single);
         framed.render(&mut frame, Point::new(1, 1));
 
         // Check corners (assuming CharDrawing uses + - | as in the example)
-        assert_eq!(frame.cells[1][1].symbol, CharDrawing::CornerTopLeft.into());
-        assert_eq!(frame.cells[1][4].symbol, CharDrawing::CornerTopRight.into());
+        assert_eq!(
+            frame.cells[1][1].symbol,
+            CharDrawing::CornerTopLeft(BorderStyle::Single).into()
+        );
+        assert_eq!(
+            frame.cells[1][4].symbol,
+            CharDrawing::CornerTopRight(BorderStyle::Single).into()
+        );
         assert_eq!(
             frame.cells[3][1].symbol,
-            CharDrawing::CornerBottomLeft.into()
+            CharDrawing::CornerBottomLeft(BorderStyle::Single).into()
         );
         assert_eq!(
             frame.cells[3][4].symbol,
-            CharDrawing::CornerBottomRight.into()
+            CharDrawing::CornerBottomRight(BorderStyle::Single).into()
         );
 
         // Check inner text position (offset by +1,+1 inside frame)
         assert_eq!(frame.cells[2][2].symbol, 'H');
         assert_eq!(frame.cells[2][3].symbol, 'i');
     }
+
+    #[test]
+    fn test_render_framed_with_double_style() {
+        let mut frame = Frame::new(Size::new(4, 3));
+        let framed = Node::Framed(Box::new(text_node("Hi")), BorderStyle::Double);
+        framed.render(&mut frame, Point::zero());
+
+        assert_eq!(frame.cells[0][0].symbol, '╔');
+        assert_eq!(frame.cells[0][1].symbol, '═');
+        assert_eq!(frame.cells[1][0].symbol, '║');
+        assert_eq!(frame.cells[2][3].symbol, '╝');
+    }
+
+    #[test]
+    fn test_render_framed_with_ascii_charset() {
+        let mut frame = Frame::new(Size::new(4, 3)).with_charset(Charset::ascii());
+        let framed = Node::Framed(Box::new(text_node("Hi")), BorderStyle::Heavy);
+        framed.render(&mut frame, Point::zero());
+
+        assert_eq!(frame.cells[0][0].symbol, '+');
+        assert_eq!(frame.cells[0][1].symbol, '-');
+        assert_eq!(frame.cells[1][0].symbol, '|');
+        assert_eq!(frame.cells[2][3].symbol, '+');
+    }
+
+    #[test]
+    fn test_render_empty_tile_is_field() {
+        let mut frame = Frame::new(Size::new(TILE_SIZE, TILE_SIZE));
+        tile_node().render(&mut frame, Point::zero());
+        for row in &frame.cells {
+            for cell in row {
+                assert_eq!(cell.symbol, '.');
+                assert_eq!(cell.foreground_color, Color::Green);
+            }
+        }
+    }
+
+    #[test]
+    fn test_render_tile_town_and_road() {
+        let tile = Tile {
+            id: TileId('Z'),
+            tile_features: vec![
+                TileFeature {
+                    feature_type: Box::new(Town {}),
+                    edges: EdgeSet::from([Edge::North]),
+                    segments: SegmentSet::from([Edge::North]),
+                    enhancement: Some(TileFeatureEnhancement::Shield),
+                },
+                TileFeature {
+                    feature_type: Box::new(Road {}),
+                    edges: EdgeSet::from([Edge::West, Edge::East]),
+                    segments: FeatureKind::Road
+                        .segments_on(EdgeSet::from([Edge::West, Edge::East])),
+                    enhancement: None,
+                },
+            ],
+            tile_extension: None,
+            meta: None,
+        };
+        let mut frame = Frame::new(Size::new(TILE_SIZE, TILE_SIZE));
+        Node::Tile(&tile).render(&mut frame, Point::zero());
+
+        assert!(frame.cells[0].iter().all(|c| c.symbol == '#'));
+        assert_eq!(frame.cells[0][0].foreground_color, Color::Red);
+        assert_eq!(frame.cells[1][2].symbol, '*');
+        assert_eq!(frame.cells[2][0].symbol, '-');
+        assert_eq!(frame.cells[2][4].symbol, '-');
+        assert_eq!(frame.cells[2][2].symbol, '+');
+        assert_eq!(frame.cells[2][2].foreground_color, Color::Yellow);
+        assert_eq!(frame.cells[4][2].symbol, '.');
+    }
+
+    #[test]
+    fn test_render_garden_tile() {
+        let tile = Tile {
+            id: TileId('Z'),
+            tile_features: vec![],
+            tile_extension: Some(TileExtension::Garden),
+            meta: None,
+        };
+        let mut frame = Frame::new(Size::new(TILE_SIZE, TILE_SIZE));
+        Node::Tile(&tile).render(&mut frame, Point::zero());
+        assert_eq!(frame.cells[2][2].symbol, 'G');
+        assert_eq!(frame.cells[2][2].foreground_color, Color::Green);
+    }
+
+    #[test]
+    fn test_render_tile_uses_frame_color_map() {
+        let tile = Tile {
+            id: TileId('Z'),
+            tile_features: vec![],
+            tile_extension: Some(TileExtension::Abbey),
+            meta: None,
+        };
+        let color_map = ColorMap::default().with_terrain_color(Terrain::Abbey, Color::Blue);
+        let mut frame = Frame::with_color_map(Size::new(TILE_SIZE, TILE_SIZE), color_map);
+        Node::Tile(&tile).render(&mut frame, Point::zero());
+
+        assert_eq!(frame.cells[2][2].symbol, 'A');
+        assert_eq!(frame.cells[2][2].foreground_color, Color::Blue);
+    }
+
+    #[test]
+    fn test_render_board_keeps_positions_and_rotations() {
+        let town = TileBuilder::new(TileId('E'))
+            .add_town(vec![Edge::North])
+            .build();
+        let mut board = Board::new();
+        board.place(PlacedTile::new(town.clone(), Rotation::Deg0, Coord::ORIGIN));
+        board.place(PlacedTile::new(town, Rotation::Deg90, Coord::new(1, 1)));
+
+        let node = Node::Board(&board);
+        assert_eq!(node.size(), Size::new(2 * TILE_SIZE, 2 * TILE_SIZE));
+        let mut frame = Frame::new(node.size());
+        node.render(&mut frame, Point::zero());
+
+        // The start tile has its town to the north.
+        assert!(frame.cells[0][..TILE_SIZE].iter().all(|c| c.symbol == '#'));
+        // The tile south-east of it is turned, its town facing east.
+        assert!(
+            (TILE_SIZE..2 * TILE_SIZE).all(|y| frame.cells[y][2 * TILE_SIZE - 1].symbol == '#')
+        );
+        // No tile lies to the east of the start tile.
+        assert!(frame.cells[0][TILE_SIZE..].iter().all(|c| c.symbol == ' '));
+    }
+
+    #[test]
+    fn test_render_board_shows_abbey_progress() {
+        let abbey = TileBuilder::new(TileId('B')).add_abbey().build();
+        let mut board = Board::new();
+        board.place(PlacedTile::new(
+            abbey.clone(),
+            Rotation::Deg0,
+            Coord::ORIGIN,
+        ));
+        board.place(PlacedTile::new(
+            abbey.clone(),
+            Rotation::Deg0,
+            Coord::new(1, 0),
+        ));
+        board.place(PlacedTile::new(abbey, Rotation::Deg0, Coord::new(1, 1)));
+        board.set_meeple(Coord::ORIGIN, Some(Meeple::new(0, SlotId::Extension)));
+
+        let node = Node::Board(&board);
+        let mut frame = Frame::new(node.size());
+        node.render(&mut frame, Point::zero());
+
+        let mid = TILE_SIZE / 2;
+        assert_eq!(frame.cells[mid][mid].symbol, 'A');
+        assert_eq!(frame.cells[mid][mid + 1].symbol, '3');
+        // No meeple stands on the other abbeys.
+        assert_eq!(frame.cells[mid][TILE_SIZE + mid + 1].symbol, '.');
+    }
+
+    #[test]
+    fn test_render_board_draws_meeples_in_player_colors() {
+        let town = TileBuilder::new(TileId('E'))
+            .add_town(vec![Edge::North])
+            .add_field(vec![Edge::East, Edge::South, Edge::West])
+            .build();
+        let mut board = Board::new();
+        board.place(PlacedTile::new(town.clone(), Rotation::Deg0, Coord::ORIGIN));
+        board.place(PlacedTile::new(
+            town.clone(),
+            Rotation::Deg0,
+            Coord::new(1, 0),
+        ));
+        board.place(PlacedTile::new(town, Rotation::Deg0, Coord::new(2, 0)));
+        board.set_meeple(Coord::ORIGIN, Some(Meeple::new(0, SlotId::Feature(0))));
+        board.set_meeple(Coord::new(1, 0), Some(Meeple::new(1, SlotId::Feature(1))));
+
+        let node = Node::Board(&board);
+        let color_map = ColorMap::default().with_player_colors(vec![Color::Blue, Color::Magenta]);
+        let mut frame = Frame::with_color_map(node.size(), color_map);
+        node.render(&mut frame, Point::zero());
+
+        // The meeple of the first player stands in the town of the start tile.
+        let town_meeple = &frame.cells[0][1];
+        assert_eq!(town_meeple.symbol, '@');
+        assert_eq!(town_meeple.foreground_color, Color::Blue);
+        // The meeple of the second player lies in the field of the next tile, by its
+        // east edge.
+        let field_meeple = &frame.cells[1][TILE_SIZE + 3];
+        assert_eq!(field_meeple.symbol, '@');
+        assert_eq!(field_meeple.foreground_color, Color::Magenta);
+        // No other meeple is drawn.
+        let meeples = frame.cells.iter().flatten().filter(|c| c.symbol == '@');
+        assert_eq!(meeples.count(), 2);
+    }
+
+    #[test]
+    fn test_render_board_tells_meeples_apart_in_monochrome() {
+        let road = TileBuilder::new(TileId('U'))
+            .add_road(vec![Edge::North, Edge::South])
+            .build();
+        let mut board = Board::new();
+        board.place(PlacedTile::new(road.clone(), Rotation::Deg0, Coord::ORIGIN));
+        board.place(PlacedTile::new(road, Rotation::Deg0, Coord::new(1, 0)));
+        board.set_meeple(Coord::ORIGIN, Some(Meeple::new(0, SlotId::Feature(0))));
+        board.set_meeple(Coord::new(1, 0), Some(Meeple::new(1, SlotId::Feature(0))));
+
+        let node = Node::Board(&board);
+        let mut frame = Frame::with_color_map(node.size(), ColorMap::monochrome());
+        node.render(&mut frame, Point::zero());
+
+        let first = &frame.cells[1][2];
+        let second = &frame.cells[1][TILE_SIZE + 2];
+        assert_eq!(first.foreground_color, second.foreground_color);
+        assert_eq!(first.symbol, ColorMap::monochrome().player_symbol(0));
+        assert_eq!(second.symbol, ColorMap::monochrome().player_symbol(1));
+        assert_ne!(first.symbol, second.symbol);
+    }
+
+    #[test]
+    fn test_empty_board_takes_no_space() {
+        let board = Board::new();
+        assert_eq!(Node::Board(&board).size(), Size::new(0, 0));
+    }
 }
@@ -1,5 +1,9 @@
+use crate::charset::Charset;
+use crate::color_map::ColorMap;
 use crate::frame::Frame;
+use crate::renderable::Renderable;
 use carcasonne_core::layout::node::Node;
+use carcasonne_core::layout::point::Point;
 use carcasonne_core::renderer::Renderer;
 use crossterm::style::{Print, ResetColor, SetForegroundColor};
 use crossterm::terminal::{disable_raw_mode, enable_raw_mode};
@@ -16,13 +20,33 @@ use std::io::Write;
 #[derive(Default, Debug)]
 pub struct TextRenderer<W: Write> {
     out: W,
+    color_map: ColorMap,
+    charset: Charset,
 }
 
 impl<W: Write> TextRenderer<W> {
-    /// Creates a new `TextRenderer` and enables raw mode.
+    /// Creates a new `TextRenderer` with the default colors and Unicode charset,
+    /// and enables raw mode.
     pub fn new(out: W) -> Self {
         enable_raw_mode().ok();
-        Self { out }
+        Self {
+            out,
+            color_map: ColorMap::default(),
+            charset: Charset::default(),
+        }
+    }
+
+    /// Sets the color map used to draw terrains and players.
+    pub fn with_color_map(mut self, color_map: ColorMap) -> Self {
+        self.color_map = color_map;
+        self
+    }
+
+    /// Sets the charset used to print box-drawing pieces, e.g. `Charset::ascii()`
+    /// for terminals without Unicode box characters.
+    pub fn with_charset(mut self, charset: Charset) -> Self {
+        self.charset = charset;
+        self
     }
 }
 
@@ -45,7 +69,9 @@ impl<W: Write> Renderer for TextRenderer<W> {
     fn render(&mut self, node: Node) {
         execute!(self.out, Clear(ClearType::All), cursor::MoveTo(0, 0),).ok();
 
-        let frame: Frame = node.into();
+        let mut frame = Frame::with_color_map(node.size(), self.color_map.clone())
+            .with_charset(self.charset.clone());
+        node.render(&mut frame, Point::zero());
 
         for i in 0..frame.size.height {
             for j in 0..frame.size.width {
@@ -67,6 +93,7 @@ impl<W: Write> Renderer for TextRenderer<W> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use carcasonne_core::layout::border_style::BorderStyle;
     use carcasonne_core::layout::node::Node;
     use std::io::Cursor;
 
@@ -85,4 +112,19 @@ mod tests {
 
         assert!(output.contains("\u{1b}["));
     }
+
+    #[test]
+    fn test_render_with_ascii_charset() {
+        let mut buffer = Cursor::new(vec![]);
+        {
+            let mut renderer = TextRenderer::new(&mut buffer).with_charset(Charset::ascii());
+            let node = Node::Framed(Box::new(Node::Char('X')), BorderStyle::Single);
+            renderer.render(node);
+        }
+
+        let output = String::from_utf8(buffer.get_ref().clone()).unwrap();
+
+        assert!(output.contains('+'));
+        assert!(!output.contains('┌'));
+    }
 }